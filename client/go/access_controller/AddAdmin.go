@@ -0,0 +1,136 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// AddAdmin is the `addAdmin` instruction.
+type AddAdmin struct {
+
+	// [0] = [WRITE] state
+	//
+	// [1] = [SIGNER] owner
+	//
+	// [2] = [] admin
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewAddAdminInstructionBuilder creates a new `AddAdmin` instruction builder.
+func NewAddAdminInstructionBuilder() *AddAdmin {
+	nd := &AddAdmin{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *AddAdmin) SetStateAccount(state ag_solanago.PublicKey) *AddAdmin {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state).WRITE()
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *AddAdmin) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *AddAdmin) SetOwnerAccount(owner ag_solanago.PublicKey) *AddAdmin {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *AddAdmin) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetAdminAccount sets the "admin" account.
+func (inst *AddAdmin) SetAdminAccount(admin ag_solanago.PublicKey) *AddAdmin {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(admin)
+	return inst
+}
+
+// GetAdminAccount gets the "admin" account.
+func (inst *AddAdmin) GetAdminAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst AddAdmin) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_AddAdmin,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst AddAdmin) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *AddAdmin) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Admin is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *AddAdmin) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("AddAdmin")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("admin", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj AddAdmin) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *AddAdmin) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewAddAdminInstruction declares a new AddAdmin instruction with the provided parameters and accounts.
+func NewAddAdminInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	admin ag_solanago.PublicKey) *AddAdmin {
+	return NewAddAdminInstructionBuilder().
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetAdminAccount(admin)
+}
@@ -0,0 +1,155 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// AddFeedAccess is the `addFeedAccess` instruction.
+type AddFeedAccess struct {
+
+	// [0] = [] state
+	//
+	// [1] = [SIGNER] owner
+	//
+	// [2] = [WRITE] feedAccessList
+	//
+	// [3] = [] address
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewAddFeedAccessInstructionBuilder creates a new `AddFeedAccess` instruction builder.
+func NewAddFeedAccessInstructionBuilder() *AddFeedAccess {
+	nd := &AddFeedAccess{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *AddFeedAccess) SetStateAccount(state ag_solanago.PublicKey) *AddFeedAccess {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state)
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *AddFeedAccess) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *AddFeedAccess) SetOwnerAccount(owner ag_solanago.PublicKey) *AddFeedAccess {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *AddFeedAccess) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetFeedAccessListAccount sets the "feedAccessList" account.
+func (inst *AddFeedAccess) SetFeedAccessListAccount(feedAccessList ag_solanago.PublicKey) *AddFeedAccess {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(feedAccessList).WRITE()
+	return inst
+}
+
+// GetFeedAccessListAccount gets the "feedAccessList" account.
+func (inst *AddFeedAccess) GetFeedAccessListAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetAddressAccount sets the "address" account.
+func (inst *AddFeedAccess) SetAddressAccount(address ag_solanago.PublicKey) *AddFeedAccess {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(address)
+	return inst
+}
+
+// GetAddressAccount gets the "address" account.
+func (inst *AddFeedAccess) GetAddressAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst AddFeedAccess) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_AddFeedAccess,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst AddFeedAccess) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *AddFeedAccess) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.FeedAccessList is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.Address is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *AddFeedAccess) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("AddFeedAccess")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("         state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("         owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("feedAccessList", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("       address", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj AddFeedAccess) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *AddFeedAccess) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewAddFeedAccessInstruction declares a new AddFeedAccess instruction with the provided parameters and accounts.
+func NewAddFeedAccessInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	feedAccessList ag_solanago.PublicKey,
+	address ag_solanago.PublicKey) *AddFeedAccess {
+	return NewAddFeedAccessInstructionBuilder().
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetFeedAccessListAccount(feedAccessList).
+		SetAddressAccount(address)
+}
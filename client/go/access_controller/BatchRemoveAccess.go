@@ -0,0 +1,147 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Removes `addresses` from the access list in a single pass, ending their subscriptions
+// like `remove_access` does.
+type BatchRemoveAccess struct {
+	Addresses *[]ag_solanago.PublicKey
+
+	// [0] = [WRITE] state
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewBatchRemoveAccessInstructionBuilder creates a new `BatchRemoveAccess` instruction builder.
+func NewBatchRemoveAccessInstructionBuilder() *BatchRemoveAccess {
+	nd := &BatchRemoveAccess{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetAddresses sets the "addresses" parameter.
+func (inst *BatchRemoveAccess) SetAddresses(addresses []ag_solanago.PublicKey) *BatchRemoveAccess {
+	inst.Addresses = &addresses
+	return inst
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *BatchRemoveAccess) SetStateAccount(state ag_solanago.PublicKey) *BatchRemoveAccess {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state).WRITE()
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *BatchRemoveAccess) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *BatchRemoveAccess) SetOwnerAccount(owner ag_solanago.PublicKey) *BatchRemoveAccess {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *BatchRemoveAccess) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst BatchRemoveAccess) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_BatchRemoveAccess,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst BatchRemoveAccess) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *BatchRemoveAccess) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Addresses == nil {
+			return errors.New("Addresses parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *BatchRemoveAccess) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("BatchRemoveAccess")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Addresses", *inst.Addresses))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj BatchRemoveAccess) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Addresses` param:
+	err = encoder.Encode(obj.Addresses)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *BatchRemoveAccess) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Addresses`:
+	err = decoder.Decode(&obj.Addresses)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewBatchRemoveAccessInstruction declares a new BatchRemoveAccess instruction with the provided parameters and accounts.
+func NewBatchRemoveAccessInstruction(
+	// Parameters:
+	addresses []ag_solanago.PublicKey,
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *BatchRemoveAccess {
+	return NewBatchRemoveAccessInstructionBuilder().
+		SetAddresses(addresses).
+		SetStateAccount(state).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,165 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Grants `address` access until `valid_until`. An existing subscription is only ever extended.
+type ExtendSubscription struct {
+	ValidUntil *int64
+
+	// [0] = [WRITE] state
+	//
+	// [1] = [SIGNER] owner
+	//
+	// [2] = [] address
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewExtendSubscriptionInstructionBuilder creates a new `ExtendSubscription` instruction builder.
+func NewExtendSubscriptionInstructionBuilder() *ExtendSubscription {
+	nd := &ExtendSubscription{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetValidUntil sets the "validUntil" parameter.
+func (inst *ExtendSubscription) SetValidUntil(validUntil int64) *ExtendSubscription {
+	inst.ValidUntil = &validUntil
+	return inst
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *ExtendSubscription) SetStateAccount(state ag_solanago.PublicKey) *ExtendSubscription {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state).WRITE()
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *ExtendSubscription) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *ExtendSubscription) SetOwnerAccount(owner ag_solanago.PublicKey) *ExtendSubscription {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *ExtendSubscription) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetAddressAccount sets the "address" account.
+func (inst *ExtendSubscription) SetAddressAccount(address ag_solanago.PublicKey) *ExtendSubscription {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(address)
+	return inst
+}
+
+// GetAddressAccount gets the "address" account.
+func (inst *ExtendSubscription) GetAddressAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst ExtendSubscription) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_ExtendSubscription,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst ExtendSubscription) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *ExtendSubscription) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.ValidUntil == nil {
+			return errors.New("ValidUntil parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Address is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *ExtendSubscription) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("ExtendSubscription")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("ValidUntil", *inst.ValidUntil))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("  state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("  owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("address", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj ExtendSubscription) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `ValidUntil` param:
+	err = encoder.Encode(obj.ValidUntil)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *ExtendSubscription) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `ValidUntil`:
+	err = decoder.Decode(&obj.ValidUntil)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewExtendSubscriptionInstruction declares a new ExtendSubscription instruction with the provided parameters and accounts.
+func NewExtendSubscriptionInstruction(
+	// Parameters:
+	validUntil int64,
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	address ag_solanago.PublicKey) *ExtendSubscription {
+	return NewExtendSubscriptionInstructionBuilder().
+		SetValidUntil(validUntil).
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetAddressAccount(address)
+}
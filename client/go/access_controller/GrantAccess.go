@@ -0,0 +1,174 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// GrantAccess is the `grantAccess` instruction.
+type GrantAccess struct {
+
+	// [0] = [] state
+	//
+	// [1] = [WRITE, SIGNER] owner
+	//
+	// [2] = [] address
+	//
+	// [3] = [WRITE] accessGrant
+	//
+	// [4] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewGrantAccessInstructionBuilder creates a new `GrantAccess` instruction builder.
+func NewGrantAccessInstructionBuilder() *GrantAccess {
+	nd := &GrantAccess{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 5),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *GrantAccess) SetStateAccount(state ag_solanago.PublicKey) *GrantAccess {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state)
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *GrantAccess) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *GrantAccess) SetOwnerAccount(owner ag_solanago.PublicKey) *GrantAccess {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *GrantAccess) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetAddressAccount sets the "address" account.
+func (inst *GrantAccess) SetAddressAccount(address ag_solanago.PublicKey) *GrantAccess {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(address)
+	return inst
+}
+
+// GetAddressAccount gets the "address" account.
+func (inst *GrantAccess) GetAddressAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetAccessGrantAccount sets the "accessGrant" account.
+func (inst *GrantAccess) SetAccessGrantAccount(accessGrant ag_solanago.PublicKey) *GrantAccess {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(accessGrant).WRITE()
+	return inst
+}
+
+// GetAccessGrantAccount gets the "accessGrant" account.
+func (inst *GrantAccess) GetAccessGrantAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *GrantAccess) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *GrantAccess {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *GrantAccess) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+func (inst GrantAccess) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_GrantAccess,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst GrantAccess) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *GrantAccess) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Address is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.AccessGrant is not set")
+		}
+		if inst.AccountMetaSlice[4] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *GrantAccess) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("GrantAccess")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=5]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("        state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("        owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("      address", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("  accessGrant", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(4)))
+					})
+				})
+		})
+}
+
+func (obj GrantAccess) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *GrantAccess) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewGrantAccessInstruction declares a new GrantAccess instruction with the provided parameters and accounts.
+func NewGrantAccessInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	address ag_solanago.PublicKey,
+	accessGrant ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *GrantAccess {
+	return NewGrantAccessInstructionBuilder().
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetAddressAccount(address).
+		SetAccessGrantAccount(accessGrant).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,184 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// InitializeFeedAccessList is the `initializeFeedAccessList` instruction.
+type InitializeFeedAccessList struct {
+	FeedId *[32]uint8
+
+	// [0] = [] state
+	//
+	// [1] = [WRITE, SIGNER] owner
+	//
+	// [2] = [WRITE] feedAccessList
+	//
+	// [3] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeFeedAccessListInstructionBuilder creates a new `InitializeFeedAccessList` instruction builder.
+func NewInitializeFeedAccessListInstructionBuilder() *InitializeFeedAccessList {
+	nd := &InitializeFeedAccessList{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetFeedId sets the "feedId" parameter.
+func (inst *InitializeFeedAccessList) SetFeedId(feedId [32]uint8) *InitializeFeedAccessList {
+	inst.FeedId = &feedId
+	return inst
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *InitializeFeedAccessList) SetStateAccount(state ag_solanago.PublicKey) *InitializeFeedAccessList {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state)
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *InitializeFeedAccessList) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *InitializeFeedAccessList) SetOwnerAccount(owner ag_solanago.PublicKey) *InitializeFeedAccessList {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *InitializeFeedAccessList) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetFeedAccessListAccount sets the "feedAccessList" account.
+func (inst *InitializeFeedAccessList) SetFeedAccessListAccount(feedAccessList ag_solanago.PublicKey) *InitializeFeedAccessList {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(feedAccessList).WRITE()
+	return inst
+}
+
+// GetFeedAccessListAccount gets the "feedAccessList" account.
+func (inst *InitializeFeedAccessList) GetFeedAccessListAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeFeedAccessList) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeFeedAccessList {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeFeedAccessList) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst InitializeFeedAccessList) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeFeedAccessList,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeFeedAccessList) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeFeedAccessList) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.FeedId == nil {
+			return errors.New("FeedId parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.FeedAccessList is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeFeedAccessList) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeFeedAccessList")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("FeedId", *inst.FeedId))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("         state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("         owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("feedAccessList", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta(" systemProgram", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj InitializeFeedAccessList) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `FeedId` param:
+	err = encoder.Encode(obj.FeedId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeFeedAccessList) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `FeedId`:
+	err = decoder.Decode(&obj.FeedId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeFeedAccessListInstruction declares a new InitializeFeedAccessList instruction with the provided parameters and accounts.
+func NewInitializeFeedAccessListInstruction(
+	// Parameters:
+	feedId [32]uint8,
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	feedAccessList ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeFeedAccessList {
+	return NewInitializeFeedAccessListInstructionBuilder().
+		SetFeedId(feedId).
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetFeedAccessListAccount(feedAccessList).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,98 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Permissionless crank removing subscriptions that have expired
+type PurgeExpiredSubscriptions struct {
+
+	// [0] = [WRITE] state
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewPurgeExpiredSubscriptionsInstructionBuilder creates a new `PurgeExpiredSubscriptions` instruction builder.
+func NewPurgeExpiredSubscriptionsInstructionBuilder() *PurgeExpiredSubscriptions {
+	nd := &PurgeExpiredSubscriptions{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 1),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *PurgeExpiredSubscriptions) SetStateAccount(state ag_solanago.PublicKey) *PurgeExpiredSubscriptions {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state).WRITE()
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *PurgeExpiredSubscriptions) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+func (inst PurgeExpiredSubscriptions) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_PurgeExpiredSubscriptions,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst PurgeExpiredSubscriptions) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *PurgeExpiredSubscriptions) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *PurgeExpiredSubscriptions) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("PurgeExpiredSubscriptions")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=1]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("state", inst.AccountMetaSlice.Get(0)))
+					})
+				})
+		})
+}
+
+func (obj PurgeExpiredSubscriptions) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *PurgeExpiredSubscriptions) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewPurgeExpiredSubscriptionsInstruction declares a new PurgeExpiredSubscriptions instruction with the provided parameters and accounts.
+func NewPurgeExpiredSubscriptionsInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey) *PurgeExpiredSubscriptions {
+	return NewPurgeExpiredSubscriptionsInstructionBuilder().
+		SetStateAccount(state)
+}
@@ -0,0 +1,136 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Grows an access controller created before the account layout was extended
+type ReallocAccount struct {
+
+	// [0] = [WRITE] state
+	//
+	// [1] = [WRITE, SIGNER] owner
+	//
+	// [2] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewReallocAccountInstructionBuilder creates a new `ReallocAccount` instruction builder.
+func NewReallocAccountInstructionBuilder() *ReallocAccount {
+	nd := &ReallocAccount{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *ReallocAccount) SetStateAccount(state ag_solanago.PublicKey) *ReallocAccount {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state).WRITE()
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *ReallocAccount) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *ReallocAccount) SetOwnerAccount(owner ag_solanago.PublicKey) *ReallocAccount {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *ReallocAccount) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *ReallocAccount) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *ReallocAccount {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *ReallocAccount) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst ReallocAccount) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_ReallocAccount,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst ReallocAccount) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *ReallocAccount) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *ReallocAccount) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("ReallocAccount")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("        state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("        owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj ReallocAccount) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *ReallocAccount) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewReallocAccountInstruction declares a new ReallocAccount instruction with the provided parameters and accounts.
+func NewReallocAccountInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *ReallocAccount {
+	return NewReallocAccountInstructionBuilder().
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetSystemProgramAccount(systemProgram)
+}
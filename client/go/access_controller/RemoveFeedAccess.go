@@ -0,0 +1,155 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// RemoveFeedAccess is the `removeFeedAccess` instruction.
+type RemoveFeedAccess struct {
+
+	// [0] = [] state
+	//
+	// [1] = [SIGNER] owner
+	//
+	// [2] = [WRITE] feedAccessList
+	//
+	// [3] = [] address
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewRemoveFeedAccessInstructionBuilder creates a new `RemoveFeedAccess` instruction builder.
+func NewRemoveFeedAccessInstructionBuilder() *RemoveFeedAccess {
+	nd := &RemoveFeedAccess{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *RemoveFeedAccess) SetStateAccount(state ag_solanago.PublicKey) *RemoveFeedAccess {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state)
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *RemoveFeedAccess) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *RemoveFeedAccess) SetOwnerAccount(owner ag_solanago.PublicKey) *RemoveFeedAccess {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *RemoveFeedAccess) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetFeedAccessListAccount sets the "feedAccessList" account.
+func (inst *RemoveFeedAccess) SetFeedAccessListAccount(feedAccessList ag_solanago.PublicKey) *RemoveFeedAccess {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(feedAccessList).WRITE()
+	return inst
+}
+
+// GetFeedAccessListAccount gets the "feedAccessList" account.
+func (inst *RemoveFeedAccess) GetFeedAccessListAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetAddressAccount sets the "address" account.
+func (inst *RemoveFeedAccess) SetAddressAccount(address ag_solanago.PublicKey) *RemoveFeedAccess {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(address)
+	return inst
+}
+
+// GetAddressAccount gets the "address" account.
+func (inst *RemoveFeedAccess) GetAddressAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst RemoveFeedAccess) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_RemoveFeedAccess,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst RemoveFeedAccess) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *RemoveFeedAccess) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.FeedAccessList is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.Address is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *RemoveFeedAccess) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("RemoveFeedAccess")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("         state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("         owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("feedAccessList", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("       address", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj RemoveFeedAccess) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *RemoveFeedAccess) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewRemoveFeedAccessInstruction declares a new RemoveFeedAccess instruction with the provided parameters and accounts.
+func NewRemoveFeedAccessInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	feedAccessList ag_solanago.PublicKey,
+	address ag_solanago.PublicKey) *RemoveFeedAccess {
+	return NewRemoveFeedAccessInstructionBuilder().
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetFeedAccessListAccount(feedAccessList).
+		SetAddressAccount(address)
+}
@@ -0,0 +1,136 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// RemoveProgramAccess is the `removeProgramAccess` instruction.
+type RemoveProgramAccess struct {
+
+	// [0] = [WRITE] state
+	//
+	// [1] = [SIGNER] owner
+	//
+	// [2] = [] program
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewRemoveProgramAccessInstructionBuilder creates a new `RemoveProgramAccess` instruction builder.
+func NewRemoveProgramAccessInstructionBuilder() *RemoveProgramAccess {
+	nd := &RemoveProgramAccess{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *RemoveProgramAccess) SetStateAccount(state ag_solanago.PublicKey) *RemoveProgramAccess {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state).WRITE()
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *RemoveProgramAccess) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *RemoveProgramAccess) SetOwnerAccount(owner ag_solanago.PublicKey) *RemoveProgramAccess {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *RemoveProgramAccess) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetProgramAccount sets the "program" account.
+func (inst *RemoveProgramAccess) SetProgramAccount(program ag_solanago.PublicKey) *RemoveProgramAccess {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(program)
+	return inst
+}
+
+// GetProgramAccount gets the "program" account.
+func (inst *RemoveProgramAccess) GetProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst RemoveProgramAccess) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_RemoveProgramAccess,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst RemoveProgramAccess) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *RemoveProgramAccess) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Program is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *RemoveProgramAccess) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("RemoveProgramAccess")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("  state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("  owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("program", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj RemoveProgramAccess) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *RemoveProgramAccess) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewRemoveProgramAccessInstruction declares a new RemoveProgramAccess instruction with the provided parameters and accounts.
+func NewRemoveProgramAccessInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	program ag_solanago.PublicKey) *RemoveProgramAccess {
+	return NewRemoveProgramAccessInstructionBuilder().
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetProgramAccount(program)
+}
@@ -0,0 +1,174 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Closes the access grant, returning its rent to the access controller owner
+type RevokeAccess struct {
+
+	// [0] = [] state
+	//
+	// [1] = [SIGNER] owner
+	//
+	// [2] = [] address
+	//
+	// [3] = [WRITE] accessGrant
+	//
+	// [4] = [WRITE] stateOwner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewRevokeAccessInstructionBuilder creates a new `RevokeAccess` instruction builder.
+func NewRevokeAccessInstructionBuilder() *RevokeAccess {
+	nd := &RevokeAccess{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 5),
+	}
+	return nd
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *RevokeAccess) SetStateAccount(state ag_solanago.PublicKey) *RevokeAccess {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state)
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *RevokeAccess) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *RevokeAccess) SetOwnerAccount(owner ag_solanago.PublicKey) *RevokeAccess {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *RevokeAccess) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetAddressAccount sets the "address" account.
+func (inst *RevokeAccess) SetAddressAccount(address ag_solanago.PublicKey) *RevokeAccess {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(address)
+	return inst
+}
+
+// GetAddressAccount gets the "address" account.
+func (inst *RevokeAccess) GetAddressAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetAccessGrantAccount sets the "accessGrant" account.
+func (inst *RevokeAccess) SetAccessGrantAccount(accessGrant ag_solanago.PublicKey) *RevokeAccess {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(accessGrant).WRITE()
+	return inst
+}
+
+// GetAccessGrantAccount gets the "accessGrant" account.
+func (inst *RevokeAccess) GetAccessGrantAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetStateOwnerAccount sets the "stateOwner" account.
+func (inst *RevokeAccess) SetStateOwnerAccount(stateOwner ag_solanago.PublicKey) *RevokeAccess {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(stateOwner).WRITE()
+	return inst
+}
+
+// GetStateOwnerAccount gets the "stateOwner" account.
+func (inst *RevokeAccess) GetStateOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+func (inst RevokeAccess) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_RevokeAccess,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst RevokeAccess) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *RevokeAccess) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Address is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.AccessGrant is not set")
+		}
+		if inst.AccountMetaSlice[4] == nil {
+			return errors.New("accounts.StateOwner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *RevokeAccess) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("RevokeAccess")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=5]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("      state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("      owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("    address", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("accessGrant", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta(" stateOwner", inst.AccountMetaSlice.Get(4)))
+					})
+				})
+		})
+}
+
+func (obj RevokeAccess) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *RevokeAccess) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewRevokeAccessInstruction declares a new RevokeAccess instruction with the provided parameters and accounts.
+func NewRevokeAccessInstruction(
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	address ag_solanago.PublicKey,
+	accessGrant ag_solanago.PublicKey,
+	stateOwner ag_solanago.PublicKey) *RevokeAccess {
+	return NewRevokeAccessInstructionBuilder().
+		SetStateAccount(state).
+		SetOwnerAccount(owner).
+		SetAddressAccount(address).
+		SetAccessGrantAccount(accessGrant).
+		SetStateOwnerAccount(stateOwner)
+}
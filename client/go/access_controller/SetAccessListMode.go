@@ -0,0 +1,147 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package access_controller
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Switches how the access list is interpreted. The list must be empty to change modes, as
+// its entries would otherwise gain the opposite meaning.
+type SetAccessListMode struct {
+	Mode *AccessListMode
+
+	// [0] = [WRITE] state
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetAccessListModeInstructionBuilder creates a new `SetAccessListMode` instruction builder.
+func NewSetAccessListModeInstructionBuilder() *SetAccessListMode {
+	nd := &SetAccessListMode{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetMode sets the "mode" parameter.
+func (inst *SetAccessListMode) SetMode(mode AccessListMode) *SetAccessListMode {
+	inst.Mode = &mode
+	return inst
+}
+
+// SetStateAccount sets the "state" account.
+func (inst *SetAccessListMode) SetStateAccount(state ag_solanago.PublicKey) *SetAccessListMode {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(state).WRITE()
+	return inst
+}
+
+// GetStateAccount gets the "state" account.
+func (inst *SetAccessListMode) GetStateAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetAccessListMode) SetOwnerAccount(owner ag_solanago.PublicKey) *SetAccessListMode {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetAccessListMode) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst SetAccessListMode) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetAccessListMode,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetAccessListMode) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetAccessListMode) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Mode == nil {
+			return errors.New("Mode parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.State is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetAccessListMode) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetAccessListMode")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Mode", *inst.Mode))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("state", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj SetAccessListMode) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Mode` param:
+	err = encoder.Encode(obj.Mode)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetAccessListMode) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Mode`:
+	err = decoder.Decode(&obj.Mode)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetAccessListModeInstruction declares a new SetAccessListMode instruction with the provided parameters and accounts.
+func NewSetAccessListModeInstruction(
+	// Parameters:
+	mode AccessListMode,
+	// Accounts:
+	state ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetAccessListMode {
+	return NewSetAccessListModeInstructionBuilder().
+		SetMode(mode).
+		SetStateAccount(state).
+		SetOwnerAccount(owner)
+}
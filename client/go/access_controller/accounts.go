@@ -9,9 +9,14 @@ import (
 )
 
 type AccessController struct {
-	Owner         ag_solanago.PublicKey
-	ProposedOwner ag_solanago.PublicKey
-	AccessList    AccessList
+	Owner                 ag_solanago.PublicKey
+	ProposedOwner         ag_solanago.PublicKey
+	AccessList            AccessList
+	Subscriptions         Subscriptions
+	ProgramAccessList     AccessList
+	Admins                Admins
+	AccessListMode        uint8
+	AccessListModePadding [7]uint8
 }
 
 var AccessControllerDiscriminator = [8]byte{143, 45, 12, 204, 220, 20, 114, 87}
@@ -37,6 +42,31 @@ func (obj AccessController) MarshalWithEncoder(encoder *ag_binary.Encoder) (err
 	if err != nil {
 		return err
 	}
+	// Serialize `Subscriptions` param:
+	err = encoder.Encode(obj.Subscriptions)
+	if err != nil {
+		return err
+	}
+	// Serialize `ProgramAccessList` param:
+	err = encoder.Encode(obj.ProgramAccessList)
+	if err != nil {
+		return err
+	}
+	// Serialize `Admins` param:
+	err = encoder.Encode(obj.Admins)
+	if err != nil {
+		return err
+	}
+	// Serialize `AccessListMode` param:
+	err = encoder.Encode(obj.AccessListMode)
+	if err != nil {
+		return err
+	}
+	// Serialize `AccessListModePadding` param:
+	err = encoder.Encode(obj.AccessListModePadding)
+	if err != nil {
+		return err
+	}
 	return nil
 }
 
@@ -69,5 +99,147 @@ func (obj *AccessController) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (e
 	if err != nil {
 		return err
 	}
+	// Deserialize `Subscriptions`:
+	err = decoder.Decode(&obj.Subscriptions)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ProgramAccessList`:
+	err = decoder.Decode(&obj.ProgramAccessList)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Admins`:
+	err = decoder.Decode(&obj.Admins)
+	if err != nil {
+		return err
+	}
+	// Deserialize `AccessListMode`:
+	err = decoder.Decode(&obj.AccessListMode)
+	if err != nil {
+		return err
+	}
+	// Deserialize `AccessListModePadding`:
+	err = decoder.Decode(&obj.AccessListModePadding)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type FeedAccessList struct {
+	AccessController ag_solanago.PublicKey
+	FeedId           [32]uint8
+	AccessList       AccessList
+}
+
+var FeedAccessListDiscriminator = [8]byte{158, 206, 240, 199, 48, 197, 111, 84}
+
+func (obj FeedAccessList) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(FeedAccessListDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `AccessController` param:
+	err = encoder.Encode(obj.AccessController)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeedId` param:
+	err = encoder.Encode(obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Serialize `AccessList` param:
+	err = encoder.Encode(obj.AccessList)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *FeedAccessList) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(FeedAccessListDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[158 206 240 199 48 197 111 84]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `AccessController`:
+	err = decoder.Decode(&obj.AccessController)
+	if err != nil {
+		return err
+	}
+	// Deserialize `FeedId`:
+	err = decoder.Decode(&obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `AccessList`:
+	err = decoder.Decode(&obj.AccessList)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type AccessGrant struct {
+	AccessController ag_solanago.PublicKey
+	Address          ag_solanago.PublicKey
+}
+
+var AccessGrantDiscriminator = [8]byte{167, 55, 184, 237, 74, 242, 0, 109}
+
+func (obj AccessGrant) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(AccessGrantDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `AccessController` param:
+	err = encoder.Encode(obj.AccessController)
+	if err != nil {
+		return err
+	}
+	// Serialize `Address` param:
+	err = encoder.Encode(obj.Address)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *AccessGrant) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(AccessGrantDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[167 55 184 237 74 242 0 109]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `AccessController`:
+	err = decoder.Decode(&obj.AccessController)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Address`:
+	err = decoder.Decode(&obj.Address)
+	if err != nil {
+		return err
+	}
 	return nil
 }
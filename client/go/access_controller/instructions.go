@@ -37,6 +37,53 @@ var (
 	Instruction_AddAccess = ag_binary.TypeID([8]byte{151, 189, 105, 24, 113, 60, 99, 138})
 
 	Instruction_RemoveAccess = ag_binary.TypeID([8]byte{92, 172, 70, 124, 83, 45, 88, 22})
+
+	// Adds `addresses` to the access list in a single merge pass. Once the list is full the
+	// remaining new addresses are rejected instead of failing the whole batch.
+	Instruction_BatchAddAccess = ag_binary.TypeID([8]byte{73, 141, 223, 79, 66, 154, 226, 67})
+
+	// Removes `addresses` from the access list in a single pass, ending their subscriptions
+	// like `remove_access` does.
+	Instruction_BatchRemoveAccess = ag_binary.TypeID([8]byte{38, 253, 222, 201, 155, 27, 109, 172})
+
+	// Switches how the access list is interpreted. The list must be empty to change modes, as
+	// its entries would otherwise gain the opposite meaning.
+	Instruction_SetAccessListMode = ag_binary.TypeID([8]byte{104, 234, 238, 13, 62, 129, 56, 98})
+
+	Instruction_AddAdmin = ag_binary.TypeID([8]byte{177, 236, 33, 205, 124, 152, 55, 186})
+
+	Instruction_RemoveAdmin = ag_binary.TypeID([8]byte{74, 202, 71, 106, 252, 31, 72, 183})
+
+	// Allows every CPI call made by `program`, whichever PDA signs it
+	Instruction_AddProgramAccess = ag_binary.TypeID([8]byte{10, 94, 28, 145, 191, 90, 3, 202})
+
+	Instruction_RemoveProgramAccess = ag_binary.TypeID([8]byte{23, 98, 245, 161, 142, 106, 198, 45})
+
+	// Grants `address` access until `valid_until`. An existing subscription is only ever extended.
+	Instruction_ExtendSubscription = ag_binary.TypeID([8]byte{47, 230, 116, 118, 171, 228, 24, 46})
+
+	// Permissionless crank removing subscriptions that have expired
+	Instruction_PurgeExpiredSubscriptions = ag_binary.TypeID([8]byte{183, 69, 225, 132, 249, 138, 77, 247})
+
+	// Grows an access controller created before the account layout was extended
+	Instruction_ReallocAccount = ag_binary.TypeID([8]byte{51, 237, 126, 233, 52, 244, 186, 244})
+
+	Instruction_InitializeFeedAccessList = ag_binary.TypeID([8]byte{165, 130, 10, 170, 19, 127, 192, 72})
+
+	Instruction_AddFeedAccess = ag_binary.TypeID([8]byte{242, 214, 64, 162, 229, 73, 75, 250})
+
+	Instruction_RemoveFeedAccess = ag_binary.TypeID([8]byte{218, 180, 72, 176, 222, 235, 132, 57})
+
+	Instruction_GrantAccess = ag_binary.TypeID([8]byte{66, 88, 87, 113, 39, 22, 27, 165})
+
+	// Closes the access grant, returning its rent to the access controller owner
+	Instruction_RevokeAccess = ag_binary.TypeID([8]byte{106, 128, 38, 169, 103, 238, 102, 147})
+
+	// Moves the first `remaining_accounts.len()` entries of the access list into access grants.
+	// Each remaining account must be the (writable) access grant address of the matching entry.
+	// Grants that already exist are kept as is. Only allow lists can be migrated, as the entries
+	// of a deny list block access rather than grant it.
+	Instruction_MigrateAccessList = ag_binary.TypeID([8]byte{110, 53, 143, 87, 161, 108, 76, 59})
 )
 
 // InstructionIDToName returns the name of the instruction given its ID.
@@ -52,6 +99,38 @@ func InstructionIDToName(id ag_binary.TypeID) string {
 		return "AddAccess"
 	case Instruction_RemoveAccess:
 		return "RemoveAccess"
+	case Instruction_BatchAddAccess:
+		return "BatchAddAccess"
+	case Instruction_BatchRemoveAccess:
+		return "BatchRemoveAccess"
+	case Instruction_SetAccessListMode:
+		return "SetAccessListMode"
+	case Instruction_AddAdmin:
+		return "AddAdmin"
+	case Instruction_RemoveAdmin:
+		return "RemoveAdmin"
+	case Instruction_AddProgramAccess:
+		return "AddProgramAccess"
+	case Instruction_RemoveProgramAccess:
+		return "RemoveProgramAccess"
+	case Instruction_ExtendSubscription:
+		return "ExtendSubscription"
+	case Instruction_PurgeExpiredSubscriptions:
+		return "PurgeExpiredSubscriptions"
+	case Instruction_ReallocAccount:
+		return "ReallocAccount"
+	case Instruction_InitializeFeedAccessList:
+		return "InitializeFeedAccessList"
+	case Instruction_AddFeedAccess:
+		return "AddFeedAccess"
+	case Instruction_RemoveFeedAccess:
+		return "RemoveFeedAccess"
+	case Instruction_GrantAccess:
+		return "GrantAccess"
+	case Instruction_RevokeAccess:
+		return "RevokeAccess"
+	case Instruction_MigrateAccessList:
+		return "MigrateAccessList"
 	default:
 		return ""
 	}
@@ -87,6 +166,54 @@ var InstructionImplDef = ag_binary.NewVariantDefinition(
 		{
 			"remove_access", (*RemoveAccess)(nil),
 		},
+		{
+			"batch_add_access", (*BatchAddAccess)(nil),
+		},
+		{
+			"batch_remove_access", (*BatchRemoveAccess)(nil),
+		},
+		{
+			"set_access_list_mode", (*SetAccessListMode)(nil),
+		},
+		{
+			"add_admin", (*AddAdmin)(nil),
+		},
+		{
+			"remove_admin", (*RemoveAdmin)(nil),
+		},
+		{
+			"add_program_access", (*AddProgramAccess)(nil),
+		},
+		{
+			"remove_program_access", (*RemoveProgramAccess)(nil),
+		},
+		{
+			"extend_subscription", (*ExtendSubscription)(nil),
+		},
+		{
+			"purge_expired_subscriptions", (*PurgeExpiredSubscriptions)(nil),
+		},
+		{
+			"realloc_account", (*ReallocAccount)(nil),
+		},
+		{
+			"initialize_feed_access_list", (*InitializeFeedAccessList)(nil),
+		},
+		{
+			"add_feed_access", (*AddFeedAccess)(nil),
+		},
+		{
+			"remove_feed_access", (*RemoveFeedAccess)(nil),
+		},
+		{
+			"grant_access", (*GrantAccess)(nil),
+		},
+		{
+			"revoke_access", (*RevokeAccess)(nil),
+		},
+		{
+			"migrate_access_list", (*MigrateAccessList)(nil),
+		},
 	},
 )
 
@@ -39,3 +39,197 @@ func (obj *AccessList) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err err
 	}
 	return nil
 }
+
+type Subscription struct {
+	Address    ag_solanago.PublicKey
+	ValidUntil int64
+}
+
+func (obj Subscription) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Address` param:
+	err = encoder.Encode(obj.Address)
+	if err != nil {
+		return err
+	}
+	// Serialize `ValidUntil` param:
+	err = encoder.Encode(obj.ValidUntil)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *Subscription) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Address`:
+	err = decoder.Decode(&obj.Address)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ValidUntil`:
+	err = decoder.Decode(&obj.ValidUntil)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type Subscriptions struct {
+	Xs  [64]Subscription
+	Len uint64
+}
+
+func (obj Subscriptions) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Xs` param:
+	err = encoder.Encode(obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Serialize `Len` param:
+	err = encoder.Encode(obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *Subscriptions) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Xs`:
+	err = decoder.Decode(&obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Len`:
+	err = decoder.Decode(&obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type AccessListMode ag_binary.BorshEnum
+
+const (
+	AccessListModeAllow AccessListMode = iota
+	AccessListModeDeny
+)
+
+func (value AccessListMode) String() string {
+	switch value {
+	case AccessListModeAllow:
+		return "Allow"
+	case AccessListModeDeny:
+		return "Deny"
+	default:
+		return ""
+	}
+}
+
+type Admins struct {
+	Xs  [8]ag_solanago.PublicKey
+	Len uint64
+}
+
+func (obj Admins) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Xs` param:
+	err = encoder.Encode(obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Serialize `Len` param:
+	err = encoder.Encode(obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *Admins) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Xs`:
+	err = decoder.Decode(&obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Len`:
+	err = decoder.Decode(&obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type BatchAddAccessResult struct {
+	Added    uint32
+	Skipped  uint32
+	Rejected uint32
+}
+
+func (obj BatchAddAccessResult) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Added` param:
+	err = encoder.Encode(obj.Added)
+	if err != nil {
+		return err
+	}
+	// Serialize `Skipped` param:
+	err = encoder.Encode(obj.Skipped)
+	if err != nil {
+		return err
+	}
+	// Serialize `Rejected` param:
+	err = encoder.Encode(obj.Rejected)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *BatchAddAccessResult) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Added`:
+	err = decoder.Decode(&obj.Added)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Skipped`:
+	err = decoder.Decode(&obj.Skipped)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Rejected`:
+	err = decoder.Decode(&obj.Rejected)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type BatchRemoveAccessResult struct {
+	Removed uint32
+	Skipped uint32
+}
+
+func (obj BatchRemoveAccessResult) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Removed` param:
+	err = encoder.Encode(obj.Removed)
+	if err != nil {
+		return err
+	}
+	// Serialize `Skipped` param:
+	err = encoder.Encode(obj.Skipped)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *BatchRemoveAccessResult) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Removed`:
+	err = decoder.Decode(&obj.Removed)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Skipped`:
+	err = decoder.Decode(&obj.Skipped)
+	if err != nil {
+		return err
+	}
+	return nil
+}
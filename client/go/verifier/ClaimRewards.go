@@ -0,0 +1,248 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Pays the recipients at `recipient_indices` their share of fees in `asset` collected since
+// their last claim, so pools with many recipients can be claimed over several transactions.
+// Anyone may submit it.
+type ClaimRewards struct {
+	Asset            *FeeAsset
+	RecipientIndices *[]byte
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] rewardPool
+	//
+	// [2] = [] feeTokenMint (optional)
+	//
+	// [3] = [WRITE] feeTokenVault (optional)
+	//
+	// [4] = [] tokenProgram (optional)
+	//
+	// [5] = [WRITE] nativeFeeVault (optional)
+	//
+	// [6] = [] systemProgram (optional)
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewClaimRewardsInstructionBuilder creates a new `ClaimRewards` instruction builder.
+func NewClaimRewardsInstructionBuilder() *ClaimRewards {
+	nd := &ClaimRewards{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 7),
+	}
+	// Optional accounts are passed as the program ID until set
+	nd.AccountMetaSlice[2] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[3] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[4] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[5] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[6] = ag_solanago.Meta(ProgramID)
+	return nd
+}
+
+// SetAsset sets the "asset" parameter.
+func (inst *ClaimRewards) SetAsset(asset FeeAsset) *ClaimRewards {
+	inst.Asset = &asset
+	return inst
+}
+
+// SetRecipientIndices sets the "recipientIndices" parameter.
+func (inst *ClaimRewards) SetRecipientIndices(recipientIndices []byte) *ClaimRewards {
+	inst.RecipientIndices = &recipientIndices
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *ClaimRewards) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *ClaimRewards {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *ClaimRewards) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRewardPoolAccount sets the "rewardPool" account.
+func (inst *ClaimRewards) SetRewardPoolAccount(rewardPool ag_solanago.PublicKey) *ClaimRewards {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(rewardPool).WRITE()
+	return inst
+}
+
+// GetRewardPoolAccount gets the "rewardPool" account.
+func (inst *ClaimRewards) GetRewardPoolAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetFeeTokenMintAccount sets the "feeTokenMint" account.
+func (inst *ClaimRewards) SetFeeTokenMintAccount(feeTokenMint ag_solanago.PublicKey) *ClaimRewards {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(feeTokenMint)
+	return inst
+}
+
+// GetFeeTokenMintAccount gets the "feeTokenMint" account.
+func (inst *ClaimRewards) GetFeeTokenMintAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetFeeTokenVaultAccount sets the "feeTokenVault" account.
+func (inst *ClaimRewards) SetFeeTokenVaultAccount(feeTokenVault ag_solanago.PublicKey) *ClaimRewards {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(feeTokenVault).WRITE()
+	return inst
+}
+
+// GetFeeTokenVaultAccount gets the "feeTokenVault" account.
+func (inst *ClaimRewards) GetFeeTokenVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetTokenProgramAccount sets the "tokenProgram" account.
+func (inst *ClaimRewards) SetTokenProgramAccount(tokenProgram ag_solanago.PublicKey) *ClaimRewards {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(tokenProgram)
+	return inst
+}
+
+// GetTokenProgramAccount gets the "tokenProgram" account.
+func (inst *ClaimRewards) GetTokenProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+// SetNativeFeeVaultAccount sets the "nativeFeeVault" account.
+func (inst *ClaimRewards) SetNativeFeeVaultAccount(nativeFeeVault ag_solanago.PublicKey) *ClaimRewards {
+	inst.AccountMetaSlice[5] = ag_solanago.Meta(nativeFeeVault).WRITE()
+	return inst
+}
+
+// GetNativeFeeVaultAccount gets the "nativeFeeVault" account.
+func (inst *ClaimRewards) GetNativeFeeVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(5)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *ClaimRewards) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *ClaimRewards {
+	inst.AccountMetaSlice[6] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *ClaimRewards) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(6)
+}
+
+func (inst ClaimRewards) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_ClaimRewards,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst ClaimRewards) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *ClaimRewards) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Asset == nil {
+			return errors.New("Asset parameter is not set")
+		}
+		if inst.RecipientIndices == nil {
+			return errors.New("RecipientIndices parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.RewardPool is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *ClaimRewards) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("ClaimRewards")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=2]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("           Asset", *inst.Asset))
+						paramsBranch.Child(ag_format.Param("RecipientIndices", *inst.RecipientIndices))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=7]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("      verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("    rewardPool", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("  feeTokenMint", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta(" feeTokenVault", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta("  tokenProgram", inst.AccountMetaSlice.Get(4)))
+						accountsBranch.Child(ag_format.Meta("nativeFeeVault", inst.AccountMetaSlice.Get(5)))
+						accountsBranch.Child(ag_format.Meta(" systemProgram", inst.AccountMetaSlice.Get(6)))
+					})
+				})
+		})
+}
+
+func (obj ClaimRewards) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Asset` param:
+	err = encoder.Encode(obj.Asset)
+	if err != nil {
+		return err
+	}
+	// Serialize `RecipientIndices` param:
+	err = encoder.Encode(obj.RecipientIndices)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *ClaimRewards) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Asset`:
+	err = decoder.Decode(&obj.Asset)
+	if err != nil {
+		return err
+	}
+	// Deserialize `RecipientIndices`:
+	err = decoder.Decode(&obj.RecipientIndices)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewClaimRewardsInstruction declares a new ClaimRewards instruction with the provided parameters and accounts.
+// Optional accounts are set with the builder.
+func NewClaimRewardsInstruction(
+	// Parameters:
+	asset FeeAsset,
+	recipientIndices []byte,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	rewardPool ag_solanago.PublicKey) *ClaimRewards {
+	return NewClaimRewardsInstructionBuilder().
+		SetAsset(asset).
+		SetRecipientIndices(recipientIndices).
+		SetVerifierAccountAccount(verifierAccount).
+		SetRewardPoolAccount(rewardPool)
+}
@@ -0,0 +1,117 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Disables the access controller, letting any user verify reports
+type ClearAccessController struct {
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewClearAccessControllerInstructionBuilder creates a new `ClearAccessController` instruction builder.
+func NewClearAccessControllerInstructionBuilder() *ClearAccessController {
+	nd := &ClearAccessController{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *ClearAccessController) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *ClearAccessController {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *ClearAccessController) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *ClearAccessController) SetOwnerAccount(owner ag_solanago.PublicKey) *ClearAccessController {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *ClearAccessController) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst ClearAccessController) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_ClearAccessController,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst ClearAccessController) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *ClearAccessController) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *ClearAccessController) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("ClearAccessController")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj ClearAccessController) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *ClearAccessController) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewClearAccessControllerInstruction declares a new ClearAccessController instruction with the provided parameters and accounts.
+func NewClearAccessControllerInstruction(
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *ClearAccessController {
+	return NewClearAccessControllerInstructionBuilder().
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner)
+}
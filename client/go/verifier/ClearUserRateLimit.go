@@ -0,0 +1,155 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Puts a user back on the default rate limit
+type ClearUserRateLimit struct {
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] rateLimit
+	//
+	// [2] = [] user
+	//
+	// [3] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewClearUserRateLimitInstructionBuilder creates a new `ClearUserRateLimit` instruction builder.
+func NewClearUserRateLimitInstructionBuilder() *ClearUserRateLimit {
+	nd := &ClearUserRateLimit{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *ClearUserRateLimit) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *ClearUserRateLimit {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *ClearUserRateLimit) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRateLimitAccount sets the "rateLimit" account.
+func (inst *ClearUserRateLimit) SetRateLimitAccount(rateLimit ag_solanago.PublicKey) *ClearUserRateLimit {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(rateLimit).WRITE()
+	return inst
+}
+
+// GetRateLimitAccount gets the "rateLimit" account.
+func (inst *ClearUserRateLimit) GetRateLimitAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetUserAccount sets the "user" account.
+func (inst *ClearUserRateLimit) SetUserAccount(user ag_solanago.PublicKey) *ClearUserRateLimit {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(user)
+	return inst
+}
+
+// GetUserAccount gets the "user" account.
+func (inst *ClearUserRateLimit) GetUserAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *ClearUserRateLimit) SetOwnerAccount(owner ag_solanago.PublicKey) *ClearUserRateLimit {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *ClearUserRateLimit) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst ClearUserRateLimit) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_ClearUserRateLimit,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst ClearUserRateLimit) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *ClearUserRateLimit) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.RateLimit is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.User is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *ClearUserRateLimit) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("ClearUserRateLimit")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta(" verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("rateLimit", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("     user", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("    owner", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj ClearUserRateLimit) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *ClearUserRateLimit) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewClearUserRateLimitInstruction declares a new ClearUserRateLimit instruction with the provided parameters and accounts.
+func NewClearUserRateLimitInstruction(
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	rateLimit ag_solanago.PublicKey,
+	user ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *ClearUserRateLimit {
+	return NewClearUserRateLimitInstructionBuilder().
+		SetVerifierAccountAccount(verifierAccount).
+		SetRateLimitAccount(rateLimit).
+		SetUserAccount(user).
+		SetOwnerAccount(owner)
+}
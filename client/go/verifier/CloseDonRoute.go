@@ -0,0 +1,137 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Removes a route, returning its rent to the owner. Its reports are verified against the
+// verifier account's configs again.
+type CloseDonRoute struct {
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [WRITE] donRoute
+	//
+	// [2] = [WRITE, SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewCloseDonRouteInstructionBuilder creates a new `CloseDonRoute` instruction builder.
+func NewCloseDonRouteInstructionBuilder() *CloseDonRoute {
+	nd := &CloseDonRoute{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *CloseDonRoute) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *CloseDonRoute {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *CloseDonRoute) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetDonRouteAccount sets the "donRoute" account.
+func (inst *CloseDonRoute) SetDonRouteAccount(donRoute ag_solanago.PublicKey) *CloseDonRoute {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(donRoute).WRITE()
+	return inst
+}
+
+// GetDonRouteAccount gets the "donRoute" account.
+func (inst *CloseDonRoute) GetDonRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *CloseDonRoute) SetOwnerAccount(owner ag_solanago.PublicKey) *CloseDonRoute {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *CloseDonRoute) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst CloseDonRoute) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_CloseDonRoute,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst CloseDonRoute) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *CloseDonRoute) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.DonRoute is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *CloseDonRoute) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("CloseDonRoute")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("donRoute", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("   owner", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj CloseDonRoute) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *CloseDonRoute) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewCloseDonRouteInstruction declares a new CloseDonRoute instruction with the provided parameters and accounts.
+func NewCloseDonRouteInstruction(
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	donRoute ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *CloseDonRoute {
+	return NewCloseDonRouteInstructionBuilder().
+		SetVerifierAccountAccount(verifierAccount).
+		SetDonRouteAccount(donRoute).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,137 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Closes a verification receipt at the request of its requester, returning its rent to the
+// account that paid it
+type CloseReceipt struct {
+
+	// [0] = [WRITE] receipt
+	//
+	// [1] = [SIGNER] requester
+	//
+	// [2] = [WRITE] payer
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewCloseReceiptInstructionBuilder creates a new `CloseReceipt` instruction builder.
+func NewCloseReceiptInstructionBuilder() *CloseReceipt {
+	nd := &CloseReceipt{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetReceiptAccount sets the "receipt" account.
+func (inst *CloseReceipt) SetReceiptAccount(receipt ag_solanago.PublicKey) *CloseReceipt {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(receipt).WRITE()
+	return inst
+}
+
+// GetReceiptAccount gets the "receipt" account.
+func (inst *CloseReceipt) GetReceiptAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRequesterAccount sets the "requester" account.
+func (inst *CloseReceipt) SetRequesterAccount(requester ag_solanago.PublicKey) *CloseReceipt {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(requester).SIGNER()
+	return inst
+}
+
+// GetRequesterAccount gets the "requester" account.
+func (inst *CloseReceipt) GetRequesterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *CloseReceipt) SetPayerAccount(payer ag_solanago.PublicKey) *CloseReceipt {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(payer).WRITE()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *CloseReceipt) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst CloseReceipt) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_CloseReceipt,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst CloseReceipt) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *CloseReceipt) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.Receipt is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Requester is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Payer is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *CloseReceipt) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("CloseReceipt")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("  receipt", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("requester", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("    payer", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj CloseReceipt) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *CloseReceipt) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewCloseReceiptInstruction declares a new CloseReceipt instruction with the provided parameters and accounts.
+func NewCloseReceiptInstruction(
+	// Accounts:
+	receipt ag_solanago.PublicKey,
+	requester ag_solanago.PublicKey,
+	payer ag_solanago.PublicKey) *CloseReceipt {
+	return NewCloseReceiptInstructionBuilder().
+		SetReceiptAccount(receipt).
+		SetRequesterAccount(requester).
+		SetPayerAccount(payer)
+}
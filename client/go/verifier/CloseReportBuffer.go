@@ -0,0 +1,117 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Closes a report buffer, returning its rent to the authority
+type CloseReportBuffer struct {
+
+	// [0] = [WRITE] reportBuffer
+	//
+	// [1] = [WRITE, SIGNER] authority
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewCloseReportBufferInstructionBuilder creates a new `CloseReportBuffer` instruction builder.
+func NewCloseReportBufferInstructionBuilder() *CloseReportBuffer {
+	nd := &CloseReportBuffer{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetReportBufferAccount sets the "reportBuffer" account.
+func (inst *CloseReportBuffer) SetReportBufferAccount(reportBuffer ag_solanago.PublicKey) *CloseReportBuffer {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(reportBuffer).WRITE()
+	return inst
+}
+
+// GetReportBufferAccount gets the "reportBuffer" account.
+func (inst *CloseReportBuffer) GetReportBufferAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetAuthorityAccount sets the "authority" account.
+func (inst *CloseReportBuffer) SetAuthorityAccount(authority ag_solanago.PublicKey) *CloseReportBuffer {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(authority).WRITE().SIGNER()
+	return inst
+}
+
+// GetAuthorityAccount gets the "authority" account.
+func (inst *CloseReportBuffer) GetAuthorityAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst CloseReportBuffer) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_CloseReportBuffer,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst CloseReportBuffer) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *CloseReportBuffer) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.ReportBuffer is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Authority is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *CloseReportBuffer) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("CloseReportBuffer")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("reportBuffer", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   authority", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj CloseReportBuffer) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *CloseReportBuffer) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewCloseReportBufferInstruction declares a new CloseReportBuffer instruction with the provided parameters and accounts.
+func NewCloseReportBufferInstruction(
+	// Accounts:
+	reportBuffer ag_solanago.PublicKey,
+	authority ag_solanago.PublicKey) *CloseReportBuffer {
+	return NewCloseReportBufferInstructionBuilder().
+		SetReportBufferAccount(reportBuffer).
+		SetAuthorityAccount(authority)
+}
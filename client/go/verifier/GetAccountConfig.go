@@ -0,0 +1,98 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Returns the owner, proposed owner and access controller of the verifier
+type GetAccountConfig struct {
+
+	// [0] = [] verifierAccount
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewGetAccountConfigInstructionBuilder creates a new `GetAccountConfig` instruction builder.
+func NewGetAccountConfigInstructionBuilder() *GetAccountConfig {
+	nd := &GetAccountConfig{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 1),
+	}
+	return nd
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *GetAccountConfig) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *GetAccountConfig {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *GetAccountConfig) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+func (inst GetAccountConfig) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_GetAccountConfig,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst GetAccountConfig) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *GetAccountConfig) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *GetAccountConfig) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("GetAccountConfig")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=1]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+					})
+				})
+		})
+}
+
+func (obj GetAccountConfig) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *GetAccountConfig) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewGetAccountConfigInstruction declares a new GetAccountConfig instruction with the provided parameters and accounts.
+func NewGetAccountConfigInstruction(
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey) *GetAccountConfig {
+	return NewGetAccountConfigInstructionBuilder().
+		SetVerifierAccountAccount(verifierAccount)
+}
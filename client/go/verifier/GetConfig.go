@@ -0,0 +1,127 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Returns the DON config stored at `don_config_index`
+type GetConfig struct {
+	DonConfigIndex *uint64
+
+	// [0] = [] verifierAccount
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewGetConfigInstructionBuilder creates a new `GetConfig` instruction builder.
+func NewGetConfigInstructionBuilder() *GetConfig {
+	nd := &GetConfig{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 1),
+	}
+	return nd
+}
+
+// SetDonConfigIndex sets the "donConfigIndex" parameter.
+func (inst *GetConfig) SetDonConfigIndex(donConfigIndex uint64) *GetConfig {
+	inst.DonConfigIndex = &donConfigIndex
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *GetConfig) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *GetConfig {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *GetConfig) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+func (inst GetConfig) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_GetConfig,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst GetConfig) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *GetConfig) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.DonConfigIndex == nil {
+			return errors.New("DonConfigIndex parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *GetConfig) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("GetConfig")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("DonConfigIndex", *inst.DonConfigIndex))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=1]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+					})
+				})
+		})
+}
+
+func (obj GetConfig) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `DonConfigIndex` param:
+	err = encoder.Encode(obj.DonConfigIndex)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *GetConfig) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `DonConfigIndex`:
+	err = decoder.Decode(&obj.DonConfigIndex)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewGetConfigInstruction declares a new GetConfig instruction with the provided parameters and accounts.
+func NewGetConfigInstruction(
+	// Parameters:
+	donConfigIndex uint64,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey) *GetConfig {
+	return NewGetConfigInstructionBuilder().
+		SetDonConfigIndex(donConfigIndex).
+		SetVerifierAccountAccount(verifierAccount)
+}
@@ -0,0 +1,98 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Returns the number of DON configs that have been set
+type GetConfigCount struct {
+
+	// [0] = [] verifierAccount
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewGetConfigCountInstructionBuilder creates a new `GetConfigCount` instruction builder.
+func NewGetConfigCountInstructionBuilder() *GetConfigCount {
+	nd := &GetConfigCount{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 1),
+	}
+	return nd
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *GetConfigCount) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *GetConfigCount {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *GetConfigCount) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+func (inst GetConfigCount) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_GetConfigCount,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst GetConfigCount) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *GetConfigCount) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *GetConfigCount) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("GetConfigCount")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=1]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+					})
+				})
+		})
+}
+
+func (obj GetConfigCount) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *GetConfigCount) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewGetConfigCountInstruction declares a new GetConfigCount instruction with the provided parameters and accounts.
+func NewGetConfigCountInstruction(
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey) *GetConfigCount {
+	return NewGetConfigCountInstructionBuilder().
+		SetVerifierAccountAccount(verifierAccount)
+}
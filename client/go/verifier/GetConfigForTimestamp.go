@@ -0,0 +1,128 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Returns the DON config that `verify` would select for a report with the given timestamp.
+// The config is returned even if it has been deactivated.
+type GetConfigForTimestamp struct {
+	Timestamp *uint32
+
+	// [0] = [] verifierAccount
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewGetConfigForTimestampInstructionBuilder creates a new `GetConfigForTimestamp` instruction builder.
+func NewGetConfigForTimestampInstructionBuilder() *GetConfigForTimestamp {
+	nd := &GetConfigForTimestamp{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 1),
+	}
+	return nd
+}
+
+// SetTimestamp sets the "timestamp" parameter.
+func (inst *GetConfigForTimestamp) SetTimestamp(timestamp uint32) *GetConfigForTimestamp {
+	inst.Timestamp = &timestamp
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *GetConfigForTimestamp) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *GetConfigForTimestamp {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *GetConfigForTimestamp) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+func (inst GetConfigForTimestamp) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_GetConfigForTimestamp,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst GetConfigForTimestamp) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *GetConfigForTimestamp) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Timestamp == nil {
+			return errors.New("Timestamp parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *GetConfigForTimestamp) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("GetConfigForTimestamp")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Timestamp", *inst.Timestamp))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=1]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+					})
+				})
+		})
+}
+
+func (obj GetConfigForTimestamp) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Timestamp` param:
+	err = encoder.Encode(obj.Timestamp)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *GetConfigForTimestamp) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Timestamp`:
+	err = decoder.Decode(&obj.Timestamp)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewGetConfigForTimestampInstruction declares a new GetConfigForTimestamp instruction with the provided parameters and accounts.
+func NewGetConfigForTimestampInstruction(
+	// Parameters:
+	timestamp uint32,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey) *GetConfigForTimestamp {
+	return NewGetConfigForTimestampInstructionBuilder().
+		SetTimestamp(timestamp).
+		SetVerifierAccountAccount(verifierAccount)
+}
@@ -0,0 +1,195 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Returns the fee `verify` would charge the requester for the signed report, or `None` while
+// fees are disabled or waived for the requester. The report is parsed and its schema version
+// checked as in `verify` but its signatures are not checked.
+type GetFeeQuote struct {
+	SignedReport *[]byte
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [] requester
+	//
+	// [2] = [] feePreference (optional)
+	//
+	// [3] = [] feeTokenMint (optional)
+	//
+	// [4] = [] subscriberDiscount (optional)
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewGetFeeQuoteInstructionBuilder creates a new `GetFeeQuote` instruction builder.
+func NewGetFeeQuoteInstructionBuilder() *GetFeeQuote {
+	nd := &GetFeeQuote{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 5),
+	}
+	// Optional accounts are passed as the program ID until set
+	nd.AccountMetaSlice[2] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[3] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[4] = ag_solanago.Meta(ProgramID)
+	return nd
+}
+
+// SetSignedReport sets the "signedReport" parameter.
+func (inst *GetFeeQuote) SetSignedReport(signedReport []byte) *GetFeeQuote {
+	inst.SignedReport = &signedReport
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *GetFeeQuote) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *GetFeeQuote {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *GetFeeQuote) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRequesterAccount sets the "requester" account.
+func (inst *GetFeeQuote) SetRequesterAccount(requester ag_solanago.PublicKey) *GetFeeQuote {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(requester)
+	return inst
+}
+
+// GetRequesterAccount gets the "requester" account.
+func (inst *GetFeeQuote) GetRequesterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetFeePreferenceAccount sets the "feePreference" account.
+func (inst *GetFeeQuote) SetFeePreferenceAccount(feePreference ag_solanago.PublicKey) *GetFeeQuote {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(feePreference)
+	return inst
+}
+
+// GetFeePreferenceAccount gets the "feePreference" account.
+func (inst *GetFeeQuote) GetFeePreferenceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetFeeTokenMintAccount sets the "feeTokenMint" account.
+func (inst *GetFeeQuote) SetFeeTokenMintAccount(feeTokenMint ag_solanago.PublicKey) *GetFeeQuote {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(feeTokenMint)
+	return inst
+}
+
+// GetFeeTokenMintAccount gets the "feeTokenMint" account.
+func (inst *GetFeeQuote) GetFeeTokenMintAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetSubscriberDiscountAccount sets the "subscriberDiscount" account.
+func (inst *GetFeeQuote) SetSubscriberDiscountAccount(subscriberDiscount ag_solanago.PublicKey) *GetFeeQuote {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(subscriberDiscount)
+	return inst
+}
+
+// GetSubscriberDiscountAccount gets the "subscriberDiscount" account.
+func (inst *GetFeeQuote) GetSubscriberDiscountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+func (inst GetFeeQuote) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_GetFeeQuote,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst GetFeeQuote) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *GetFeeQuote) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.SignedReport == nil {
+			return errors.New("SignedReport parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Requester is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *GetFeeQuote) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("GetFeeQuote")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("SignedReport", *inst.SignedReport))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=5]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("          verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("         requester", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("     feePreference", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("      feeTokenMint", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta("subscriberDiscount", inst.AccountMetaSlice.Get(4)))
+					})
+				})
+		})
+}
+
+func (obj GetFeeQuote) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `SignedReport` param:
+	err = encoder.Encode(obj.SignedReport)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *GetFeeQuote) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `SignedReport`:
+	err = decoder.Decode(&obj.SignedReport)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewGetFeeQuoteInstruction declares a new GetFeeQuote instruction with the provided parameters and accounts.
+// Optional accounts are set with the builder.
+func NewGetFeeQuoteInstruction(
+	// Parameters:
+	signedReport []byte,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	requester ag_solanago.PublicKey) *GetFeeQuote {
+	return NewGetFeeQuoteInstructionBuilder().
+		SetSignedReport(signedReport).
+		SetVerifierAccountAccount(verifierAccount).
+		SetRequesterAccount(requester)
+}
@@ -11,8 +11,7 @@ import (
 )
 
 // Initializes the verifier (admin) account data. Call after initialize + realloc
-// We use an optional access controller.
-// See https://github.com/coral-xyz/anchor/pull/2101 on how option works in Anchor
+// Pass the verifier program ID as the access controller to leave it unset.
 type InitializeAccountData struct {
 
 	// [0] = [WRITE] verifierAccount
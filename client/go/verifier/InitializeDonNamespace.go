@@ -0,0 +1,184 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Creates an empty DON namespace, whose config history verifies the reports routed to it
+type InitializeDonNamespace struct {
+	NamespaceId *uint64
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] donNamespace
+	//
+	// [2] = [WRITE, SIGNER] owner
+	//
+	// [3] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeDonNamespaceInstructionBuilder creates a new `InitializeDonNamespace` instruction builder.
+func NewInitializeDonNamespaceInstructionBuilder() *InitializeDonNamespace {
+	nd := &InitializeDonNamespace{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetNamespaceId sets the "namespaceId" parameter.
+func (inst *InitializeDonNamespace) SetNamespaceId(namespaceId uint64) *InitializeDonNamespace {
+	inst.NamespaceId = &namespaceId
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *InitializeDonNamespace) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *InitializeDonNamespace {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *InitializeDonNamespace) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetDonNamespaceAccount sets the "donNamespace" account.
+func (inst *InitializeDonNamespace) SetDonNamespaceAccount(donNamespace ag_solanago.PublicKey) *InitializeDonNamespace {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(donNamespace).WRITE()
+	return inst
+}
+
+// GetDonNamespaceAccount gets the "donNamespace" account.
+func (inst *InitializeDonNamespace) GetDonNamespaceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *InitializeDonNamespace) SetOwnerAccount(owner ag_solanago.PublicKey) *InitializeDonNamespace {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *InitializeDonNamespace) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeDonNamespace) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeDonNamespace {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeDonNamespace) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst InitializeDonNamespace) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeDonNamespace,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeDonNamespace) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeDonNamespace) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.NamespaceId == nil {
+			return errors.New("NamespaceId parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.DonNamespace is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeDonNamespace) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeDonNamespace")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("NamespaceId", *inst.NamespaceId))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("     verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta(" donNamespace", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("        owner", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj InitializeDonNamespace) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `NamespaceId` param:
+	err = encoder.Encode(obj.NamespaceId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeDonNamespace) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `NamespaceId`:
+	err = decoder.Decode(&obj.NamespaceId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeDonNamespaceInstruction declares a new InitializeDonNamespace instruction with the provided parameters and accounts.
+func NewInitializeDonNamespaceInstruction(
+	// Parameters:
+	namespaceId uint64,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	donNamespace ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeDonNamespace {
+	return NewInitializeDonNamespaceInstructionBuilder().
+		SetNamespaceId(namespaceId).
+		SetVerifierAccountAccount(verifierAccount).
+		SetDonNamespaceAccount(donNamespace).
+		SetOwnerAccount(owner).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,204 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Routes reports whose config digest or feed id is `key` to the DON namespace. A route of the
+// config digest takes precedence over one of the feed id.
+type InitializeDonRoute struct {
+	Key *[32]uint8
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [WRITE] donRoute
+	//
+	// [2] = [] donNamespace
+	//
+	// [3] = [WRITE, SIGNER] owner
+	//
+	// [4] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeDonRouteInstructionBuilder creates a new `InitializeDonRoute` instruction builder.
+func NewInitializeDonRouteInstructionBuilder() *InitializeDonRoute {
+	nd := &InitializeDonRoute{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 5),
+	}
+	return nd
+}
+
+// SetKey sets the "key" parameter.
+func (inst *InitializeDonRoute) SetKey(key [32]uint8) *InitializeDonRoute {
+	inst.Key = &key
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *InitializeDonRoute) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *InitializeDonRoute {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *InitializeDonRoute) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetDonRouteAccount sets the "donRoute" account.
+func (inst *InitializeDonRoute) SetDonRouteAccount(donRoute ag_solanago.PublicKey) *InitializeDonRoute {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(donRoute).WRITE()
+	return inst
+}
+
+// GetDonRouteAccount gets the "donRoute" account.
+func (inst *InitializeDonRoute) GetDonRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetDonNamespaceAccount sets the "donNamespace" account.
+func (inst *InitializeDonRoute) SetDonNamespaceAccount(donNamespace ag_solanago.PublicKey) *InitializeDonRoute {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(donNamespace)
+	return inst
+}
+
+// GetDonNamespaceAccount gets the "donNamespace" account.
+func (inst *InitializeDonRoute) GetDonNamespaceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *InitializeDonRoute) SetOwnerAccount(owner ag_solanago.PublicKey) *InitializeDonRoute {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *InitializeDonRoute) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeDonRoute) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeDonRoute {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeDonRoute) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+func (inst InitializeDonRoute) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeDonRoute,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeDonRoute) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeDonRoute) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Key == nil {
+			return errors.New("Key parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.DonRoute is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.DonNamespace is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[4] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeDonRoute) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeDonRoute")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Key", *inst.Key))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=5]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("     verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("     donRoute", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta(" donNamespace", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("        owner", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(4)))
+					})
+				})
+		})
+}
+
+func (obj InitializeDonRoute) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Key` param:
+	err = encoder.Encode(obj.Key)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeDonRoute) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Key`:
+	err = decoder.Decode(&obj.Key)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeDonRouteInstruction declares a new InitializeDonRoute instruction with the provided parameters and accounts.
+func NewInitializeDonRouteInstruction(
+	// Parameters:
+	key [32]uint8,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	donRoute ag_solanago.PublicKey,
+	donNamespace ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeDonRoute {
+	return NewInitializeDonRouteInstructionBuilder().
+		SetKey(key).
+		SetVerifierAccountAccount(verifierAccount).
+		SetDonRouteAccount(donRoute).
+		SetDonNamespaceAccount(donNamespace).
+		SetOwnerAccount(owner).
+		SetSystemProgramAccount(systemProgram)
+}
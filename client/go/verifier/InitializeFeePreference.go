@@ -0,0 +1,165 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Creates the requester's fee preference with their choice of fee asset
+type InitializeFeePreference struct {
+	Asset *FeeAsset
+
+	// [0] = [WRITE] feePreference
+	//
+	// [1] = [WRITE, SIGNER] requester
+	//
+	// [2] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeFeePreferenceInstructionBuilder creates a new `InitializeFeePreference` instruction builder.
+func NewInitializeFeePreferenceInstructionBuilder() *InitializeFeePreference {
+	nd := &InitializeFeePreference{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetAsset sets the "asset" parameter.
+func (inst *InitializeFeePreference) SetAsset(asset FeeAsset) *InitializeFeePreference {
+	inst.Asset = &asset
+	return inst
+}
+
+// SetFeePreferenceAccount sets the "feePreference" account.
+func (inst *InitializeFeePreference) SetFeePreferenceAccount(feePreference ag_solanago.PublicKey) *InitializeFeePreference {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(feePreference).WRITE()
+	return inst
+}
+
+// GetFeePreferenceAccount gets the "feePreference" account.
+func (inst *InitializeFeePreference) GetFeePreferenceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRequesterAccount sets the "requester" account.
+func (inst *InitializeFeePreference) SetRequesterAccount(requester ag_solanago.PublicKey) *InitializeFeePreference {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(requester).WRITE().SIGNER()
+	return inst
+}
+
+// GetRequesterAccount gets the "requester" account.
+func (inst *InitializeFeePreference) GetRequesterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeFeePreference) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeFeePreference {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeFeePreference) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst InitializeFeePreference) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeFeePreference,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeFeePreference) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeFeePreference) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Asset == nil {
+			return errors.New("Asset parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.FeePreference is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Requester is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeFeePreference) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeFeePreference")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Asset", *inst.Asset))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("feePreference", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("    requester", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj InitializeFeePreference) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Asset` param:
+	err = encoder.Encode(obj.Asset)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeFeePreference) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Asset`:
+	err = decoder.Decode(&obj.Asset)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeFeePreferenceInstruction declares a new InitializeFeePreference instruction with the provided parameters and accounts.
+func NewInitializeFeePreferenceInstruction(
+	// Parameters:
+	asset FeeAsset,
+	// Accounts:
+	feePreference ag_solanago.PublicKey,
+	requester ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeFeePreference {
+	return NewInitializeFeePreferenceInstructionBuilder().
+		SetAsset(asset).
+		SetFeePreferenceAccount(feePreference).
+		SetRequesterAccount(requester).
+		SetSystemProgramAccount(systemProgram)
+}
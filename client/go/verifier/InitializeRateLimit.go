@@ -0,0 +1,155 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Creates the rate limit account of a user. Anyone may pay for it.
+type InitializeRateLimit struct {
+
+	// [0] = [WRITE] rateLimit
+	//
+	// [1] = [] user
+	//
+	// [2] = [WRITE, SIGNER] payer
+	//
+	// [3] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeRateLimitInstructionBuilder creates a new `InitializeRateLimit` instruction builder.
+func NewInitializeRateLimitInstructionBuilder() *InitializeRateLimit {
+	nd := &InitializeRateLimit{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetRateLimitAccount sets the "rateLimit" account.
+func (inst *InitializeRateLimit) SetRateLimitAccount(rateLimit ag_solanago.PublicKey) *InitializeRateLimit {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(rateLimit).WRITE()
+	return inst
+}
+
+// GetRateLimitAccount gets the "rateLimit" account.
+func (inst *InitializeRateLimit) GetRateLimitAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetUserAccount sets the "user" account.
+func (inst *InitializeRateLimit) SetUserAccount(user ag_solanago.PublicKey) *InitializeRateLimit {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(user)
+	return inst
+}
+
+// GetUserAccount gets the "user" account.
+func (inst *InitializeRateLimit) GetUserAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *InitializeRateLimit) SetPayerAccount(payer ag_solanago.PublicKey) *InitializeRateLimit {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(payer).WRITE().SIGNER()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *InitializeRateLimit) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeRateLimit) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeRateLimit {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeRateLimit) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst InitializeRateLimit) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeRateLimit,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeRateLimit) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeRateLimit) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.RateLimit is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.User is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Payer is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeRateLimit) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeRateLimit")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("    rateLimit", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("         user", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("        payer", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj InitializeRateLimit) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *InitializeRateLimit) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewInitializeRateLimitInstruction declares a new InitializeRateLimit instruction with the provided parameters and accounts.
+func NewInitializeRateLimitInstruction(
+	// Accounts:
+	rateLimit ag_solanago.PublicKey,
+	user ag_solanago.PublicKey,
+	payer ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeRateLimit {
+	return NewInitializeRateLimitInstructionBuilder().
+		SetRateLimitAccount(rateLimit).
+		SetUserAccount(user).
+		SetPayerAccount(payer).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,188 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Creates a buffer of `capacity` bytes to stage a signed report in across transactions
+type InitializeReportBuffer struct {
+	BufferId *uint64
+	Capacity *uint32
+
+	// [0] = [WRITE] reportBuffer
+	//
+	// [1] = [WRITE, SIGNER] authority
+	//
+	// [2] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeReportBufferInstructionBuilder creates a new `InitializeReportBuffer` instruction builder.
+func NewInitializeReportBufferInstructionBuilder() *InitializeReportBuffer {
+	nd := &InitializeReportBuffer{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetBufferId sets the "bufferId" parameter.
+func (inst *InitializeReportBuffer) SetBufferId(bufferId uint64) *InitializeReportBuffer {
+	inst.BufferId = &bufferId
+	return inst
+}
+
+// SetCapacity sets the "capacity" parameter.
+func (inst *InitializeReportBuffer) SetCapacity(capacity uint32) *InitializeReportBuffer {
+	inst.Capacity = &capacity
+	return inst
+}
+
+// SetReportBufferAccount sets the "reportBuffer" account.
+func (inst *InitializeReportBuffer) SetReportBufferAccount(reportBuffer ag_solanago.PublicKey) *InitializeReportBuffer {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(reportBuffer).WRITE()
+	return inst
+}
+
+// GetReportBufferAccount gets the "reportBuffer" account.
+func (inst *InitializeReportBuffer) GetReportBufferAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetAuthorityAccount sets the "authority" account.
+func (inst *InitializeReportBuffer) SetAuthorityAccount(authority ag_solanago.PublicKey) *InitializeReportBuffer {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(authority).WRITE().SIGNER()
+	return inst
+}
+
+// GetAuthorityAccount gets the "authority" account.
+func (inst *InitializeReportBuffer) GetAuthorityAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeReportBuffer) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeReportBuffer {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeReportBuffer) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst InitializeReportBuffer) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeReportBuffer,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeReportBuffer) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeReportBuffer) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.BufferId == nil {
+			return errors.New("BufferId parameter is not set")
+		}
+		if inst.Capacity == nil {
+			return errors.New("Capacity parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.ReportBuffer is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Authority is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeReportBuffer) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeReportBuffer")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=2]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("BufferId", *inst.BufferId))
+						paramsBranch.Child(ag_format.Param("Capacity", *inst.Capacity))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta(" reportBuffer", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("    authority", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj InitializeReportBuffer) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `BufferId` param:
+	err = encoder.Encode(obj.BufferId)
+	if err != nil {
+		return err
+	}
+	// Serialize `Capacity` param:
+	err = encoder.Encode(obj.Capacity)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeReportBuffer) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `BufferId`:
+	err = decoder.Decode(&obj.BufferId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Capacity`:
+	err = decoder.Decode(&obj.Capacity)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeReportBufferInstruction declares a new InitializeReportBuffer instruction with the provided parameters and accounts.
+func NewInitializeReportBufferInstruction(
+	// Parameters:
+	bufferId uint64,
+	capacity uint32,
+	// Accounts:
+	reportBuffer ag_solanago.PublicKey,
+	authority ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeReportBuffer {
+	return NewInitializeReportBufferInstructionBuilder().
+		SetBufferId(bufferId).
+		SetCapacity(capacity).
+		SetReportBufferAccount(reportBuffer).
+		SetAuthorityAccount(authority).
+		SetSystemProgramAccount(systemProgram)
+}
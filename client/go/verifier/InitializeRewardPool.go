@@ -0,0 +1,165 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Creates the reward pool collecting fees of reports with `config_digest`. Anyone may pay for it.
+type InitializeRewardPool struct {
+	ConfigDigest *[32]uint8
+
+	// [0] = [WRITE] rewardPool
+	//
+	// [1] = [WRITE, SIGNER] payer
+	//
+	// [2] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeRewardPoolInstructionBuilder creates a new `InitializeRewardPool` instruction builder.
+func NewInitializeRewardPoolInstructionBuilder() *InitializeRewardPool {
+	nd := &InitializeRewardPool{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetConfigDigest sets the "configDigest" parameter.
+func (inst *InitializeRewardPool) SetConfigDigest(configDigest [32]uint8) *InitializeRewardPool {
+	inst.ConfigDigest = &configDigest
+	return inst
+}
+
+// SetRewardPoolAccount sets the "rewardPool" account.
+func (inst *InitializeRewardPool) SetRewardPoolAccount(rewardPool ag_solanago.PublicKey) *InitializeRewardPool {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(rewardPool).WRITE()
+	return inst
+}
+
+// GetRewardPoolAccount gets the "rewardPool" account.
+func (inst *InitializeRewardPool) GetRewardPoolAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *InitializeRewardPool) SetPayerAccount(payer ag_solanago.PublicKey) *InitializeRewardPool {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(payer).WRITE().SIGNER()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *InitializeRewardPool) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeRewardPool) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeRewardPool {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeRewardPool) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst InitializeRewardPool) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeRewardPool,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeRewardPool) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeRewardPool) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.ConfigDigest == nil {
+			return errors.New("ConfigDigest parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.RewardPool is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Payer is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeRewardPool) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeRewardPool")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("ConfigDigest", *inst.ConfigDigest))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("   rewardPool", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("        payer", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj InitializeRewardPool) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `ConfigDigest` param:
+	err = encoder.Encode(obj.ConfigDigest)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeRewardPool) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `ConfigDigest`:
+	err = decoder.Decode(&obj.ConfigDigest)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeRewardPoolInstruction declares a new InitializeRewardPool instruction with the provided parameters and accounts.
+func NewInitializeRewardPoolInstruction(
+	// Parameters:
+	configDigest [32]uint8,
+	// Accounts:
+	rewardPool ag_solanago.PublicKey,
+	payer ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeRewardPool {
+	return NewInitializeRewardPoolInstructionBuilder().
+		SetConfigDigest(configDigest).
+		SetRewardPoolAccount(rewardPool).
+		SetPayerAccount(payer).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,185 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Creates a subscriber's discount record for a feed, or for all feeds with
+// `GLOBAL_DISCOUNT_FEED_ID`. Anyone may pay for it, only the owner can grant a discount.
+type InitializeSubscriberDiscount struct {
+	FeedId *[32]uint8
+
+	// [0] = [WRITE] subscriberDiscount
+	//
+	// [1] = [] subscriber
+	//
+	// [2] = [WRITE, SIGNER] payer
+	//
+	// [3] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeSubscriberDiscountInstructionBuilder creates a new `InitializeSubscriberDiscount` instruction builder.
+func NewInitializeSubscriberDiscountInstructionBuilder() *InitializeSubscriberDiscount {
+	nd := &InitializeSubscriberDiscount{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetFeedId sets the "feedId" parameter.
+func (inst *InitializeSubscriberDiscount) SetFeedId(feedId [32]uint8) *InitializeSubscriberDiscount {
+	inst.FeedId = &feedId
+	return inst
+}
+
+// SetSubscriberDiscountAccount sets the "subscriberDiscount" account.
+func (inst *InitializeSubscriberDiscount) SetSubscriberDiscountAccount(subscriberDiscount ag_solanago.PublicKey) *InitializeSubscriberDiscount {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(subscriberDiscount).WRITE()
+	return inst
+}
+
+// GetSubscriberDiscountAccount gets the "subscriberDiscount" account.
+func (inst *InitializeSubscriberDiscount) GetSubscriberDiscountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetSubscriberAccount sets the "subscriber" account.
+func (inst *InitializeSubscriberDiscount) SetSubscriberAccount(subscriber ag_solanago.PublicKey) *InitializeSubscriberDiscount {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(subscriber)
+	return inst
+}
+
+// GetSubscriberAccount gets the "subscriber" account.
+func (inst *InitializeSubscriberDiscount) GetSubscriberAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *InitializeSubscriberDiscount) SetPayerAccount(payer ag_solanago.PublicKey) *InitializeSubscriberDiscount {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(payer).WRITE().SIGNER()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *InitializeSubscriberDiscount) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeSubscriberDiscount) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeSubscriberDiscount {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeSubscriberDiscount) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst InitializeSubscriberDiscount) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeSubscriberDiscount,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeSubscriberDiscount) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeSubscriberDiscount) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.FeedId == nil {
+			return errors.New("FeedId parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.SubscriberDiscount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Subscriber is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Payer is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeSubscriberDiscount) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeSubscriberDiscount")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("FeedId", *inst.FeedId))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("subscriberDiscount", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("        subscriber", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("             payer", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("     systemProgram", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj InitializeSubscriberDiscount) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `FeedId` param:
+	err = encoder.Encode(obj.FeedId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeSubscriberDiscount) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `FeedId`:
+	err = decoder.Decode(&obj.FeedId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeSubscriberDiscountInstruction declares a new InitializeSubscriberDiscount instruction with the provided parameters and accounts.
+func NewInitializeSubscriberDiscountInstruction(
+	// Parameters:
+	feedId [32]uint8,
+	// Accounts:
+	subscriberDiscount ag_solanago.PublicKey,
+	subscriber ag_solanago.PublicKey,
+	payer ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeSubscriberDiscount {
+	return NewInitializeSubscriberDiscountInstructionBuilder().
+		SetFeedId(feedId).
+		SetSubscriberDiscountAccount(subscriberDiscount).
+		SetSubscriberAccount(subscriber).
+		SetPayerAccount(payer).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,184 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Creates the usage counter of a requester for a feed. Anyone may pay for it.
+type InitializeUsageCounter struct {
+	FeedId *[32]uint8
+
+	// [0] = [WRITE] usageCounter
+	//
+	// [1] = [] requester
+	//
+	// [2] = [WRITE, SIGNER] payer
+	//
+	// [3] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewInitializeUsageCounterInstructionBuilder creates a new `InitializeUsageCounter` instruction builder.
+func NewInitializeUsageCounterInstructionBuilder() *InitializeUsageCounter {
+	nd := &InitializeUsageCounter{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetFeedId sets the "feedId" parameter.
+func (inst *InitializeUsageCounter) SetFeedId(feedId [32]uint8) *InitializeUsageCounter {
+	inst.FeedId = &feedId
+	return inst
+}
+
+// SetUsageCounterAccount sets the "usageCounter" account.
+func (inst *InitializeUsageCounter) SetUsageCounterAccount(usageCounter ag_solanago.PublicKey) *InitializeUsageCounter {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(usageCounter).WRITE()
+	return inst
+}
+
+// GetUsageCounterAccount gets the "usageCounter" account.
+func (inst *InitializeUsageCounter) GetUsageCounterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRequesterAccount sets the "requester" account.
+func (inst *InitializeUsageCounter) SetRequesterAccount(requester ag_solanago.PublicKey) *InitializeUsageCounter {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(requester)
+	return inst
+}
+
+// GetRequesterAccount gets the "requester" account.
+func (inst *InitializeUsageCounter) GetRequesterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *InitializeUsageCounter) SetPayerAccount(payer ag_solanago.PublicKey) *InitializeUsageCounter {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(payer).WRITE().SIGNER()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *InitializeUsageCounter) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *InitializeUsageCounter) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *InitializeUsageCounter {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *InitializeUsageCounter) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst InitializeUsageCounter) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_InitializeUsageCounter,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst InitializeUsageCounter) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *InitializeUsageCounter) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.FeedId == nil {
+			return errors.New("FeedId parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.UsageCounter is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Requester is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Payer is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *InitializeUsageCounter) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("InitializeUsageCounter")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("FeedId", *inst.FeedId))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta(" usageCounter", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("    requester", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("        payer", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj InitializeUsageCounter) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `FeedId` param:
+	err = encoder.Encode(obj.FeedId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *InitializeUsageCounter) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `FeedId`:
+	err = decoder.Decode(&obj.FeedId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewInitializeUsageCounterInstruction declares a new InitializeUsageCounter instruction with the provided parameters and accounts.
+func NewInitializeUsageCounterInstruction(
+	// Parameters:
+	feedId [32]uint8,
+	// Accounts:
+	usageCounter ag_solanago.PublicKey,
+	requester ag_solanago.PublicKey,
+	payer ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *InitializeUsageCounter {
+	return NewInitializeUsageCounterInstructionBuilder().
+		SetFeedId(feedId).
+		SetUsageCounterAccount(usageCounter).
+		SetRequesterAccount(requester).
+		SetPayerAccount(payer).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,222 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Sets a new DON config without the owner. The rotation must be signed by more than `f`
+// signers of the currently active config over `Encoder::compute_config_rotation_hash`, which
+// includes the verifier account's rotation nonce. Signatures are packed the same way as in a
+// signed report.
+type RotateConfig struct {
+	Signers *[][20]uint8
+	F       *uint8
+	Rs      *[][32]uint8
+	Ss      *[][32]uint8
+	RawVs   *[32]uint8
+
+	// [0] = [WRITE] verifierAccount
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewRotateConfigInstructionBuilder creates a new `RotateConfig` instruction builder.
+func NewRotateConfigInstructionBuilder() *RotateConfig {
+	nd := &RotateConfig{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 1),
+	}
+	return nd
+}
+
+// SetSigners sets the "signers" parameter.
+func (inst *RotateConfig) SetSigners(signers [][20]uint8) *RotateConfig {
+	inst.Signers = &signers
+	return inst
+}
+
+// SetF sets the "f" parameter.
+func (inst *RotateConfig) SetF(f uint8) *RotateConfig {
+	inst.F = &f
+	return inst
+}
+
+// SetRs sets the "rs" parameter.
+func (inst *RotateConfig) SetRs(rs [][32]uint8) *RotateConfig {
+	inst.Rs = &rs
+	return inst
+}
+
+// SetSs sets the "ss" parameter.
+func (inst *RotateConfig) SetSs(ss [][32]uint8) *RotateConfig {
+	inst.Ss = &ss
+	return inst
+}
+
+// SetRawVs sets the "rawVs" parameter.
+func (inst *RotateConfig) SetRawVs(rawVs [32]uint8) *RotateConfig {
+	inst.RawVs = &rawVs
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *RotateConfig) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *RotateConfig {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *RotateConfig) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+func (inst RotateConfig) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_RotateConfig,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst RotateConfig) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *RotateConfig) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Signers == nil {
+			return errors.New("Signers parameter is not set")
+		}
+		if inst.F == nil {
+			return errors.New("F parameter is not set")
+		}
+		if inst.Rs == nil {
+			return errors.New("Rs parameter is not set")
+		}
+		if inst.Ss == nil {
+			return errors.New("Ss parameter is not set")
+		}
+		if inst.RawVs == nil {
+			return errors.New("RawVs parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *RotateConfig) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("RotateConfig")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=5]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Signers", *inst.Signers))
+						paramsBranch.Child(ag_format.Param("      F", *inst.F))
+						paramsBranch.Child(ag_format.Param("     Rs", *inst.Rs))
+						paramsBranch.Child(ag_format.Param("     Ss", *inst.Ss))
+						paramsBranch.Child(ag_format.Param("  RawVs", *inst.RawVs))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=1]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+					})
+				})
+		})
+}
+
+func (obj RotateConfig) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Signers` param:
+	err = encoder.Encode(obj.Signers)
+	if err != nil {
+		return err
+	}
+	// Serialize `F` param:
+	err = encoder.Encode(obj.F)
+	if err != nil {
+		return err
+	}
+	// Serialize `Rs` param:
+	err = encoder.Encode(obj.Rs)
+	if err != nil {
+		return err
+	}
+	// Serialize `Ss` param:
+	err = encoder.Encode(obj.Ss)
+	if err != nil {
+		return err
+	}
+	// Serialize `RawVs` param:
+	err = encoder.Encode(obj.RawVs)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *RotateConfig) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Signers`:
+	err = decoder.Decode(&obj.Signers)
+	if err != nil {
+		return err
+	}
+	// Deserialize `F`:
+	err = decoder.Decode(&obj.F)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Rs`:
+	err = decoder.Decode(&obj.Rs)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Ss`:
+	err = decoder.Decode(&obj.Ss)
+	if err != nil {
+		return err
+	}
+	// Deserialize `RawVs`:
+	err = decoder.Decode(&obj.RawVs)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewRotateConfigInstruction declares a new RotateConfig instruction with the provided parameters and accounts.
+func NewRotateConfigInstruction(
+	// Parameters:
+	signers [][20]uint8,
+	f uint8,
+	rs [][32]uint8,
+	ss [][32]uint8,
+	rawVs [32]uint8,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey) *RotateConfig {
+	return NewRotateConfigInstructionBuilder().
+		SetSigners(signers).
+		SetF(f).
+		SetRs(rs).
+		SetSs(ss).
+		SetRawVs(rawVs).
+		SetVerifierAccountAccount(verifierAccount)
+}
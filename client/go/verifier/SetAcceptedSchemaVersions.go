@@ -0,0 +1,147 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Sets the report schema versions `verify` accepts, replacing the previous list. The version
+// is taken from the first two bytes of the feed id. An empty list accepts every version.
+type SetAcceptedSchemaVersions struct {
+	SchemaVersions *[]uint16
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetAcceptedSchemaVersionsInstructionBuilder creates a new `SetAcceptedSchemaVersions` instruction builder.
+func NewSetAcceptedSchemaVersionsInstructionBuilder() *SetAcceptedSchemaVersions {
+	nd := &SetAcceptedSchemaVersions{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetSchemaVersions sets the "schemaVersions" parameter.
+func (inst *SetAcceptedSchemaVersions) SetSchemaVersions(schemaVersions []uint16) *SetAcceptedSchemaVersions {
+	inst.SchemaVersions = &schemaVersions
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetAcceptedSchemaVersions) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetAcceptedSchemaVersions {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetAcceptedSchemaVersions) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetAcceptedSchemaVersions) SetOwnerAccount(owner ag_solanago.PublicKey) *SetAcceptedSchemaVersions {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetAcceptedSchemaVersions) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst SetAcceptedSchemaVersions) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetAcceptedSchemaVersions,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetAcceptedSchemaVersions) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetAcceptedSchemaVersions) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.SchemaVersions == nil {
+			return errors.New("SchemaVersions parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetAcceptedSchemaVersions) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetAcceptedSchemaVersions")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("SchemaVersions", *inst.SchemaVersions))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj SetAcceptedSchemaVersions) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `SchemaVersions` param:
+	err = encoder.Encode(obj.SchemaVersions)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetAcceptedSchemaVersions) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `SchemaVersions`:
+	err = decoder.Decode(&obj.SchemaVersions)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetAcceptedSchemaVersionsInstruction declares a new SetAcceptedSchemaVersions instruction with the provided parameters and accounts.
+func NewSetAcceptedSchemaVersionsInstruction(
+	// Parameters:
+	schemaVersions []uint16,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetAcceptedSchemaVersions {
+	return NewSetAcceptedSchemaVersionsInstructionBuilder().
+		SetSchemaVersions(schemaVersions).
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner)
+}
@@ -11,8 +11,7 @@ import (
 )
 
 // Used to Set the access controller
-// We use an optional access controller.
-// See https://github.com/coral-xyz/anchor/pull/2101 on how option works in Anchor
+// Pass the verifier program ID as the access controller to disable it.
 type SetAccessController struct {
 
 	// [0] = [WRITE] verifierAccount
@@ -0,0 +1,193 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Like `set_config`, but fails unless `expected_don_config_id` matches the id computed from
+// the sorted signers and `f`
+type SetConfigChecked struct {
+	Signers             *[][20]uint8
+	F                   *uint8
+	ExpectedDonConfigId *[24]uint8
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetConfigCheckedInstructionBuilder creates a new `SetConfigChecked` instruction builder.
+func NewSetConfigCheckedInstructionBuilder() *SetConfigChecked {
+	nd := &SetConfigChecked{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetSigners sets the "signers" parameter.
+func (inst *SetConfigChecked) SetSigners(signers [][20]uint8) *SetConfigChecked {
+	inst.Signers = &signers
+	return inst
+}
+
+// SetF sets the "f" parameter.
+func (inst *SetConfigChecked) SetF(f uint8) *SetConfigChecked {
+	inst.F = &f
+	return inst
+}
+
+// SetExpectedDonConfigId sets the "expectedDonConfigId" parameter.
+func (inst *SetConfigChecked) SetExpectedDonConfigId(expectedDonConfigId [24]uint8) *SetConfigChecked {
+	inst.ExpectedDonConfigId = &expectedDonConfigId
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetConfigChecked) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetConfigChecked {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetConfigChecked) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetConfigChecked) SetOwnerAccount(owner ag_solanago.PublicKey) *SetConfigChecked {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetConfigChecked) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst SetConfigChecked) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetConfigChecked,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetConfigChecked) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetConfigChecked) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Signers == nil {
+			return errors.New("Signers parameter is not set")
+		}
+		if inst.F == nil {
+			return errors.New("F parameter is not set")
+		}
+		if inst.ExpectedDonConfigId == nil {
+			return errors.New("ExpectedDonConfigId parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetConfigChecked) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetConfigChecked")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=3]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("            Signers", *inst.Signers))
+						paramsBranch.Child(ag_format.Param("                  F", *inst.F))
+						paramsBranch.Child(ag_format.Param("ExpectedDonConfigId", *inst.ExpectedDonConfigId))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj SetConfigChecked) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Signers` param:
+	err = encoder.Encode(obj.Signers)
+	if err != nil {
+		return err
+	}
+	// Serialize `F` param:
+	err = encoder.Encode(obj.F)
+	if err != nil {
+		return err
+	}
+	// Serialize `ExpectedDonConfigId` param:
+	err = encoder.Encode(obj.ExpectedDonConfigId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetConfigChecked) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Signers`:
+	err = decoder.Decode(&obj.Signers)
+	if err != nil {
+		return err
+	}
+	// Deserialize `F`:
+	err = decoder.Decode(&obj.F)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ExpectedDonConfigId`:
+	err = decoder.Decode(&obj.ExpectedDonConfigId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetConfigCheckedInstruction declares a new SetConfigChecked instruction with the provided parameters and accounts.
+func NewSetConfigCheckedInstruction(
+	// Parameters:
+	signers [][20]uint8,
+	f uint8,
+	expectedDonConfigId [24]uint8,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetConfigChecked {
+	return NewSetConfigCheckedInstructionBuilder().
+		SetSigners(signers).
+		SetF(f).
+		SetExpectedDonConfigId(expectedDonConfigId).
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,146 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Allows or disallows the active DON to set its successor config via `rotate_config`
+type SetConfigRotationEnabled struct {
+	Enabled *bool
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetConfigRotationEnabledInstructionBuilder creates a new `SetConfigRotationEnabled` instruction builder.
+func NewSetConfigRotationEnabledInstructionBuilder() *SetConfigRotationEnabled {
+	nd := &SetConfigRotationEnabled{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetEnabled sets the "enabled" parameter.
+func (inst *SetConfigRotationEnabled) SetEnabled(enabled bool) *SetConfigRotationEnabled {
+	inst.Enabled = &enabled
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetConfigRotationEnabled) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetConfigRotationEnabled {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetConfigRotationEnabled) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetConfigRotationEnabled) SetOwnerAccount(owner ag_solanago.PublicKey) *SetConfigRotationEnabled {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetConfigRotationEnabled) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst SetConfigRotationEnabled) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetConfigRotationEnabled,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetConfigRotationEnabled) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetConfigRotationEnabled) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Enabled == nil {
+			return errors.New("Enabled parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetConfigRotationEnabled) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetConfigRotationEnabled")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Enabled", *inst.Enabled))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj SetConfigRotationEnabled) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Enabled` param:
+	err = encoder.Encode(obj.Enabled)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetConfigRotationEnabled) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Enabled`:
+	err = decoder.Decode(&obj.Enabled)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetConfigRotationEnabledInstruction declares a new SetConfigRotationEnabled instruction with the provided parameters and accounts.
+func NewSetConfigRotationEnabledInstruction(
+	// Parameters:
+	enabled bool,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetConfigRotationEnabled {
+	return NewSetConfigRotationEnabledInstructionBuilder().
+		SetEnabled(enabled).
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,195 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Enables or disables rate limiting. While enabled every `verify` must pass the user's rate
+// limit account, which is checked against `max_verifications` per `window_slots` unless the
+// user has their own limit. A `max_verifications` of 0 means no limit. `window_slots` must be
+// non-zero when enabling.
+type SetDefaultRateLimit struct {
+	Enabled          *bool
+	MaxVerifications *uint64
+	WindowSlots      *uint64
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetDefaultRateLimitInstructionBuilder creates a new `SetDefaultRateLimit` instruction builder.
+func NewSetDefaultRateLimitInstructionBuilder() *SetDefaultRateLimit {
+	nd := &SetDefaultRateLimit{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetEnabled sets the "enabled" parameter.
+func (inst *SetDefaultRateLimit) SetEnabled(enabled bool) *SetDefaultRateLimit {
+	inst.Enabled = &enabled
+	return inst
+}
+
+// SetMaxVerifications sets the "maxVerifications" parameter.
+func (inst *SetDefaultRateLimit) SetMaxVerifications(maxVerifications uint64) *SetDefaultRateLimit {
+	inst.MaxVerifications = &maxVerifications
+	return inst
+}
+
+// SetWindowSlots sets the "windowSlots" parameter.
+func (inst *SetDefaultRateLimit) SetWindowSlots(windowSlots uint64) *SetDefaultRateLimit {
+	inst.WindowSlots = &windowSlots
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetDefaultRateLimit) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetDefaultRateLimit {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetDefaultRateLimit) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetDefaultRateLimit) SetOwnerAccount(owner ag_solanago.PublicKey) *SetDefaultRateLimit {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetDefaultRateLimit) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst SetDefaultRateLimit) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetDefaultRateLimit,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetDefaultRateLimit) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetDefaultRateLimit) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Enabled == nil {
+			return errors.New("Enabled parameter is not set")
+		}
+		if inst.MaxVerifications == nil {
+			return errors.New("MaxVerifications parameter is not set")
+		}
+		if inst.WindowSlots == nil {
+			return errors.New("WindowSlots parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetDefaultRateLimit) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetDefaultRateLimit")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=3]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("         Enabled", *inst.Enabled))
+						paramsBranch.Child(ag_format.Param("MaxVerifications", *inst.MaxVerifications))
+						paramsBranch.Child(ag_format.Param("     WindowSlots", *inst.WindowSlots))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj SetDefaultRateLimit) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Enabled` param:
+	err = encoder.Encode(obj.Enabled)
+	if err != nil {
+		return err
+	}
+	// Serialize `MaxVerifications` param:
+	err = encoder.Encode(obj.MaxVerifications)
+	if err != nil {
+		return err
+	}
+	// Serialize `WindowSlots` param:
+	err = encoder.Encode(obj.WindowSlots)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetDefaultRateLimit) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Enabled`:
+	err = decoder.Decode(&obj.Enabled)
+	if err != nil {
+		return err
+	}
+	// Deserialize `MaxVerifications`:
+	err = decoder.Decode(&obj.MaxVerifications)
+	if err != nil {
+		return err
+	}
+	// Deserialize `WindowSlots`:
+	err = decoder.Decode(&obj.WindowSlots)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetDefaultRateLimitInstruction declares a new SetDefaultRateLimit instruction with the provided parameters and accounts.
+func NewSetDefaultRateLimitInstruction(
+	// Parameters:
+	enabled bool,
+	maxVerifications uint64,
+	windowSlots uint64,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetDefaultRateLimit {
+	return NewSetDefaultRateLimitInstructionBuilder().
+		SetEnabled(enabled).
+		SetMaxVerifications(maxVerifications).
+		SetWindowSlots(windowSlots).
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,224 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Enables or disables fees in an SPL token and sets the token. While enabled `verify` transfers
+// the report's `link_fee` in this token from requesters paying in it to the fee vault.
+// The token cannot be changed once set, as reward pools track unclaimed fees in it.
+type SetFeeConfig struct {
+	Enabled *bool
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [WRITE, SIGNER] owner
+	//
+	// [2] = [] feeTokenMint
+	//
+	// [3] = [WRITE] feeTokenVault
+	//
+	// [4] = [] tokenProgram
+	//
+	// [5] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetFeeConfigInstructionBuilder creates a new `SetFeeConfig` instruction builder.
+func NewSetFeeConfigInstructionBuilder() *SetFeeConfig {
+	nd := &SetFeeConfig{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 6),
+	}
+	return nd
+}
+
+// SetEnabled sets the "enabled" parameter.
+func (inst *SetFeeConfig) SetEnabled(enabled bool) *SetFeeConfig {
+	inst.Enabled = &enabled
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetFeeConfig) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetFeeConfig {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetFeeConfig) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetFeeConfig) SetOwnerAccount(owner ag_solanago.PublicKey) *SetFeeConfig {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetFeeConfig) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetFeeTokenMintAccount sets the "feeTokenMint" account.
+func (inst *SetFeeConfig) SetFeeTokenMintAccount(feeTokenMint ag_solanago.PublicKey) *SetFeeConfig {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(feeTokenMint)
+	return inst
+}
+
+// GetFeeTokenMintAccount gets the "feeTokenMint" account.
+func (inst *SetFeeConfig) GetFeeTokenMintAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetFeeTokenVaultAccount sets the "feeTokenVault" account.
+func (inst *SetFeeConfig) SetFeeTokenVaultAccount(feeTokenVault ag_solanago.PublicKey) *SetFeeConfig {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(feeTokenVault).WRITE()
+	return inst
+}
+
+// GetFeeTokenVaultAccount gets the "feeTokenVault" account.
+func (inst *SetFeeConfig) GetFeeTokenVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetTokenProgramAccount sets the "tokenProgram" account.
+func (inst *SetFeeConfig) SetTokenProgramAccount(tokenProgram ag_solanago.PublicKey) *SetFeeConfig {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(tokenProgram)
+	return inst
+}
+
+// GetTokenProgramAccount gets the "tokenProgram" account.
+func (inst *SetFeeConfig) GetTokenProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *SetFeeConfig) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *SetFeeConfig {
+	inst.AccountMetaSlice[5] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *SetFeeConfig) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(5)
+}
+
+func (inst SetFeeConfig) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetFeeConfig,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetFeeConfig) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetFeeConfig) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Enabled == nil {
+			return errors.New("Enabled parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.FeeTokenMint is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.FeeTokenVault is not set")
+		}
+		if inst.AccountMetaSlice[4] == nil {
+			return errors.New("accounts.TokenProgram is not set")
+		}
+		if inst.AccountMetaSlice[5] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetFeeConfig) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetFeeConfig")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Enabled", *inst.Enabled))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=6]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("     verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("        owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta(" feeTokenMint", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("feeTokenVault", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta(" tokenProgram", inst.AccountMetaSlice.Get(4)))
+						accountsBranch.Child(ag_format.Meta("systemProgram", inst.AccountMetaSlice.Get(5)))
+					})
+				})
+		})
+}
+
+func (obj SetFeeConfig) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Enabled` param:
+	err = encoder.Encode(obj.Enabled)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetFeeConfig) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Enabled`:
+	err = decoder.Decode(&obj.Enabled)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetFeeConfigInstruction declares a new SetFeeConfig instruction with the provided parameters and accounts.
+func NewSetFeeConfigInstruction(
+	// Parameters:
+	enabled bool,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	feeTokenMint ag_solanago.PublicKey,
+	feeTokenVault ag_solanago.PublicKey,
+	tokenProgram ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *SetFeeConfig {
+	return NewSetFeeConfigInstructionBuilder().
+		SetEnabled(enabled).
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner).
+		SetFeeTokenMintAccount(feeTokenMint).
+		SetFeeTokenVaultAccount(feeTokenVault).
+		SetTokenProgramAccount(tokenProgram).
+		SetSystemProgramAccount(systemProgram)
+}
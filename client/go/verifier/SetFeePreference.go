@@ -0,0 +1,146 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Changes the asset the requester pays fees in
+type SetFeePreference struct {
+	Asset *FeeAsset
+
+	// [0] = [WRITE] feePreference
+	//
+	// [1] = [SIGNER] requester
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetFeePreferenceInstructionBuilder creates a new `SetFeePreference` instruction builder.
+func NewSetFeePreferenceInstructionBuilder() *SetFeePreference {
+	nd := &SetFeePreference{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetAsset sets the "asset" parameter.
+func (inst *SetFeePreference) SetAsset(asset FeeAsset) *SetFeePreference {
+	inst.Asset = &asset
+	return inst
+}
+
+// SetFeePreferenceAccount sets the "feePreference" account.
+func (inst *SetFeePreference) SetFeePreferenceAccount(feePreference ag_solanago.PublicKey) *SetFeePreference {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(feePreference).WRITE()
+	return inst
+}
+
+// GetFeePreferenceAccount gets the "feePreference" account.
+func (inst *SetFeePreference) GetFeePreferenceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRequesterAccount sets the "requester" account.
+func (inst *SetFeePreference) SetRequesterAccount(requester ag_solanago.PublicKey) *SetFeePreference {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(requester).SIGNER()
+	return inst
+}
+
+// GetRequesterAccount gets the "requester" account.
+func (inst *SetFeePreference) GetRequesterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst SetFeePreference) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetFeePreference,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetFeePreference) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetFeePreference) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Asset == nil {
+			return errors.New("Asset parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.FeePreference is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Requester is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetFeePreference) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetFeePreference")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Asset", *inst.Asset))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("feePreference", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("    requester", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj SetFeePreference) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Asset` param:
+	err = encoder.Encode(obj.Asset)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetFeePreference) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Asset`:
+	err = decoder.Decode(&obj.Asset)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetFeePreferenceInstruction declares a new SetFeePreference instruction with the provided parameters and accounts.
+func NewSetFeePreferenceInstruction(
+	// Parameters:
+	asset FeeAsset,
+	// Accounts:
+	feePreference ag_solanago.PublicKey,
+	requester ag_solanago.PublicKey) *SetFeePreference {
+	return NewSetFeePreferenceInstructionBuilder().
+		SetAsset(asset).
+		SetFeePreferenceAccount(feePreference).
+		SetRequesterAccount(requester)
+}
@@ -0,0 +1,166 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Waives all fees of a subscriber, e.g. for internal service accounts. Waived subscribers
+// need none of the fee accounts in `verify`.
+type SetFeeWaiver struct {
+	Waived *bool
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] subscriberDiscount
+	//
+	// [2] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetFeeWaiverInstructionBuilder creates a new `SetFeeWaiver` instruction builder.
+func NewSetFeeWaiverInstructionBuilder() *SetFeeWaiver {
+	nd := &SetFeeWaiver{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetWaived sets the "waived" parameter.
+func (inst *SetFeeWaiver) SetWaived(waived bool) *SetFeeWaiver {
+	inst.Waived = &waived
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetFeeWaiver) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetFeeWaiver {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetFeeWaiver) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetSubscriberDiscountAccount sets the "subscriberDiscount" account.
+func (inst *SetFeeWaiver) SetSubscriberDiscountAccount(subscriberDiscount ag_solanago.PublicKey) *SetFeeWaiver {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(subscriberDiscount).WRITE()
+	return inst
+}
+
+// GetSubscriberDiscountAccount gets the "subscriberDiscount" account.
+func (inst *SetFeeWaiver) GetSubscriberDiscountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetFeeWaiver) SetOwnerAccount(owner ag_solanago.PublicKey) *SetFeeWaiver {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetFeeWaiver) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst SetFeeWaiver) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetFeeWaiver,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetFeeWaiver) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetFeeWaiver) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Waived == nil {
+			return errors.New("Waived parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.SubscriberDiscount is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetFeeWaiver) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetFeeWaiver")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Waived", *inst.Waived))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("          verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("subscriberDiscount", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("             owner", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj SetFeeWaiver) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Waived` param:
+	err = encoder.Encode(obj.Waived)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetFeeWaiver) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Waived`:
+	err = decoder.Decode(&obj.Waived)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetFeeWaiverInstruction declares a new SetFeeWaiver instruction with the provided parameters and accounts.
+func NewSetFeeWaiverInstruction(
+	// Parameters:
+	waived bool,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	subscriberDiscount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetFeeWaiver {
+	return NewSetFeeWaiverInstructionBuilder().
+		SetWaived(waived).
+		SetVerifierAccountAccount(verifierAccount).
+		SetSubscriberDiscountAccount(subscriberDiscount).
+		SetOwnerAccount(owner)
+}
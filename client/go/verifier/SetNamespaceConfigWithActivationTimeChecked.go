@@ -0,0 +1,235 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Like `set_namespace_config_with_activation_time`, but fails unless `expected_don_config_id`
+// matches the id computed from the sorted signers and `f`
+type SetNamespaceConfigWithActivationTimeChecked struct {
+	Signers             *[][20]uint8
+	F                   *uint8
+	ActivationTime      *uint32
+	ExpectedDonConfigId *[24]uint8
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] donNamespace
+	//
+	// [2] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetNamespaceConfigWithActivationTimeCheckedInstructionBuilder creates a new `SetNamespaceConfigWithActivationTimeChecked` instruction builder.
+func NewSetNamespaceConfigWithActivationTimeCheckedInstructionBuilder() *SetNamespaceConfigWithActivationTimeChecked {
+	nd := &SetNamespaceConfigWithActivationTimeChecked{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetSigners sets the "signers" parameter.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) SetSigners(signers [][20]uint8) *SetNamespaceConfigWithActivationTimeChecked {
+	inst.Signers = &signers
+	return inst
+}
+
+// SetF sets the "f" parameter.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) SetF(f uint8) *SetNamespaceConfigWithActivationTimeChecked {
+	inst.F = &f
+	return inst
+}
+
+// SetActivationTime sets the "activationTime" parameter.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) SetActivationTime(activationTime uint32) *SetNamespaceConfigWithActivationTimeChecked {
+	inst.ActivationTime = &activationTime
+	return inst
+}
+
+// SetExpectedDonConfigId sets the "expectedDonConfigId" parameter.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) SetExpectedDonConfigId(expectedDonConfigId [24]uint8) *SetNamespaceConfigWithActivationTimeChecked {
+	inst.ExpectedDonConfigId = &expectedDonConfigId
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetNamespaceConfigWithActivationTimeChecked {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetDonNamespaceAccount sets the "donNamespace" account.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) SetDonNamespaceAccount(donNamespace ag_solanago.PublicKey) *SetNamespaceConfigWithActivationTimeChecked {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(donNamespace).WRITE()
+	return inst
+}
+
+// GetDonNamespaceAccount gets the "donNamespace" account.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) GetDonNamespaceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) SetOwnerAccount(owner ag_solanago.PublicKey) *SetNamespaceConfigWithActivationTimeChecked {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetNamespaceConfigWithActivationTimeChecked) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst SetNamespaceConfigWithActivationTimeChecked) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetNamespaceConfigWithActivationTimeChecked,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetNamespaceConfigWithActivationTimeChecked) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetNamespaceConfigWithActivationTimeChecked) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Signers == nil {
+			return errors.New("Signers parameter is not set")
+		}
+		if inst.F == nil {
+			return errors.New("F parameter is not set")
+		}
+		if inst.ActivationTime == nil {
+			return errors.New("ActivationTime parameter is not set")
+		}
+		if inst.ExpectedDonConfigId == nil {
+			return errors.New("ExpectedDonConfigId parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.DonNamespace is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetNamespaceConfigWithActivationTimeChecked) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetNamespaceConfigWithActivationTimeChecked")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=4]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("            Signers", *inst.Signers))
+						paramsBranch.Child(ag_format.Param("                  F", *inst.F))
+						paramsBranch.Child(ag_format.Param("     ActivationTime", *inst.ActivationTime))
+						paramsBranch.Child(ag_format.Param("ExpectedDonConfigId", *inst.ExpectedDonConfigId))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("    verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("donNamespace", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("       owner", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj SetNamespaceConfigWithActivationTimeChecked) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Signers` param:
+	err = encoder.Encode(obj.Signers)
+	if err != nil {
+		return err
+	}
+	// Serialize `F` param:
+	err = encoder.Encode(obj.F)
+	if err != nil {
+		return err
+	}
+	// Serialize `ActivationTime` param:
+	err = encoder.Encode(obj.ActivationTime)
+	if err != nil {
+		return err
+	}
+	// Serialize `ExpectedDonConfigId` param:
+	err = encoder.Encode(obj.ExpectedDonConfigId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetNamespaceConfigWithActivationTimeChecked) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Signers`:
+	err = decoder.Decode(&obj.Signers)
+	if err != nil {
+		return err
+	}
+	// Deserialize `F`:
+	err = decoder.Decode(&obj.F)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ActivationTime`:
+	err = decoder.Decode(&obj.ActivationTime)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ExpectedDonConfigId`:
+	err = decoder.Decode(&obj.ExpectedDonConfigId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetNamespaceConfigWithActivationTimeCheckedInstruction declares a new SetNamespaceConfigWithActivationTimeChecked instruction with the provided parameters and accounts.
+func NewSetNamespaceConfigWithActivationTimeCheckedInstruction(
+	// Parameters:
+	signers [][20]uint8,
+	f uint8,
+	activationTime uint32,
+	expectedDonConfigId [24]uint8,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	donNamespace ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetNamespaceConfigWithActivationTimeChecked {
+	return NewSetNamespaceConfigWithActivationTimeCheckedInstructionBuilder().
+		SetSigners(signers).
+		SetF(f).
+		SetActivationTime(activationTime).
+		SetExpectedDonConfigId(expectedDonConfigId).
+		SetVerifierAccountAccount(verifierAccount).
+		SetDonNamespaceAccount(donNamespace).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,208 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Allows or disallows paying fees in native SOL, charged the report's `native_fee` plus
+// `surcharge_bps` basis points. Funds the native fee vault to be rent exempt if needed.
+type SetNativeFeeConfig struct {
+	Enabled      *bool
+	SurchargeBps *uint16
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [WRITE, SIGNER] owner
+	//
+	// [2] = [WRITE] nativeFeeVault
+	//
+	// [3] = [] systemProgram
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetNativeFeeConfigInstructionBuilder creates a new `SetNativeFeeConfig` instruction builder.
+func NewSetNativeFeeConfigInstructionBuilder() *SetNativeFeeConfig {
+	nd := &SetNativeFeeConfig{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetEnabled sets the "enabled" parameter.
+func (inst *SetNativeFeeConfig) SetEnabled(enabled bool) *SetNativeFeeConfig {
+	inst.Enabled = &enabled
+	return inst
+}
+
+// SetSurchargeBps sets the "surchargeBps" parameter.
+func (inst *SetNativeFeeConfig) SetSurchargeBps(surchargeBps uint16) *SetNativeFeeConfig {
+	inst.SurchargeBps = &surchargeBps
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetNativeFeeConfig) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetNativeFeeConfig {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetNativeFeeConfig) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetNativeFeeConfig) SetOwnerAccount(owner ag_solanago.PublicKey) *SetNativeFeeConfig {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).WRITE().SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetNativeFeeConfig) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetNativeFeeVaultAccount sets the "nativeFeeVault" account.
+func (inst *SetNativeFeeConfig) SetNativeFeeVaultAccount(nativeFeeVault ag_solanago.PublicKey) *SetNativeFeeConfig {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(nativeFeeVault).WRITE()
+	return inst
+}
+
+// GetNativeFeeVaultAccount gets the "nativeFeeVault" account.
+func (inst *SetNativeFeeConfig) GetNativeFeeVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *SetNativeFeeConfig) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *SetNativeFeeConfig {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *SetNativeFeeConfig) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst SetNativeFeeConfig) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetNativeFeeConfig,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetNativeFeeConfig) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetNativeFeeConfig) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Enabled == nil {
+			return errors.New("Enabled parameter is not set")
+		}
+		if inst.SurchargeBps == nil {
+			return errors.New("SurchargeBps parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.NativeFeeVault is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.SystemProgram is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetNativeFeeConfig) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetNativeFeeConfig")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=2]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("     Enabled", *inst.Enabled))
+						paramsBranch.Child(ag_format.Param("SurchargeBps", *inst.SurchargeBps))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("      verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("         owner", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("nativeFeeVault", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta(" systemProgram", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj SetNativeFeeConfig) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Enabled` param:
+	err = encoder.Encode(obj.Enabled)
+	if err != nil {
+		return err
+	}
+	// Serialize `SurchargeBps` param:
+	err = encoder.Encode(obj.SurchargeBps)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetNativeFeeConfig) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Enabled`:
+	err = decoder.Decode(&obj.Enabled)
+	if err != nil {
+		return err
+	}
+	// Deserialize `SurchargeBps`:
+	err = decoder.Decode(&obj.SurchargeBps)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetNativeFeeConfigInstruction declares a new SetNativeFeeConfig instruction with the provided parameters and accounts.
+func NewSetNativeFeeConfigInstruction(
+	// Parameters:
+	enabled bool,
+	surchargeBps uint16,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey,
+	nativeFeeVault ag_solanago.PublicKey,
+	systemProgram ag_solanago.PublicKey) *SetNativeFeeConfig {
+	return NewSetNativeFeeConfigInstructionBuilder().
+		SetEnabled(enabled).
+		SetSurchargeBps(surchargeBps).
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner).
+		SetNativeFeeVaultAccount(nativeFeeVault).
+		SetSystemProgramAccount(systemProgram)
+}
@@ -0,0 +1,210 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Sets who the pool's fees are paid to. Each recipient is paid for a signer of the DON config
+// at `don_config_index`, of the DON namespace when given, and their weights must add up to
+// `TOTAL_REWARD_WEIGHT`. Fees collected before the first recipients are set are shared among
+// them. As with the EVM RewardManager's `updateRewardRecipients`, the current recipients must
+// have claimed all their rewards before they are replaced, so `claim_rewards` for both assets
+// should precede this in the same transaction.
+type SetRewardRecipients struct {
+	DonConfigIndex *uint64
+	Recipients     *[]RewardRecipientConfig
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] rewardPool
+	//
+	// [2] = [SIGNER] owner
+	//
+	// [3] = [] donNamespace (optional)
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetRewardRecipientsInstructionBuilder creates a new `SetRewardRecipients` instruction builder.
+func NewSetRewardRecipientsInstructionBuilder() *SetRewardRecipients {
+	nd := &SetRewardRecipients{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	// Optional accounts are passed as the program ID until set
+	nd.AccountMetaSlice[3] = ag_solanago.Meta(ProgramID)
+	return nd
+}
+
+// SetDonConfigIndex sets the "donConfigIndex" parameter.
+func (inst *SetRewardRecipients) SetDonConfigIndex(donConfigIndex uint64) *SetRewardRecipients {
+	inst.DonConfigIndex = &donConfigIndex
+	return inst
+}
+
+// SetRecipients sets the "recipients" parameter.
+func (inst *SetRewardRecipients) SetRecipients(recipients []RewardRecipientConfig) *SetRewardRecipients {
+	inst.Recipients = &recipients
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetRewardRecipients) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetRewardRecipients {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetRewardRecipients) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRewardPoolAccount sets the "rewardPool" account.
+func (inst *SetRewardRecipients) SetRewardPoolAccount(rewardPool ag_solanago.PublicKey) *SetRewardRecipients {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(rewardPool).WRITE()
+	return inst
+}
+
+// GetRewardPoolAccount gets the "rewardPool" account.
+func (inst *SetRewardRecipients) GetRewardPoolAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetRewardRecipients) SetOwnerAccount(owner ag_solanago.PublicKey) *SetRewardRecipients {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetRewardRecipients) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetDonNamespaceAccount sets the "donNamespace" account.
+func (inst *SetRewardRecipients) SetDonNamespaceAccount(donNamespace ag_solanago.PublicKey) *SetRewardRecipients {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(donNamespace)
+	return inst
+}
+
+// GetDonNamespaceAccount gets the "donNamespace" account.
+func (inst *SetRewardRecipients) GetDonNamespaceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst SetRewardRecipients) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetRewardRecipients,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetRewardRecipients) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetRewardRecipients) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.DonConfigIndex == nil {
+			return errors.New("DonConfigIndex parameter is not set")
+		}
+		if inst.Recipients == nil {
+			return errors.New("Recipients parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.RewardPool is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetRewardRecipients) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetRewardRecipients")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=2]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("DonConfigIndex", *inst.DonConfigIndex))
+						paramsBranch.Child(ag_format.Param("    Recipients", *inst.Recipients))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("    verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("  rewardPool", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("       owner", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("donNamespace", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj SetRewardRecipients) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `DonConfigIndex` param:
+	err = encoder.Encode(obj.DonConfigIndex)
+	if err != nil {
+		return err
+	}
+	// Serialize `Recipients` param:
+	err = encoder.Encode(obj.Recipients)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetRewardRecipients) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `DonConfigIndex`:
+	err = decoder.Decode(&obj.DonConfigIndex)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Recipients`:
+	err = decoder.Decode(&obj.Recipients)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetRewardRecipientsInstruction declares a new SetRewardRecipients instruction with the provided parameters and accounts.
+// Optional accounts are set with the builder.
+func NewSetRewardRecipientsInstruction(
+	// Parameters:
+	donConfigIndex uint64,
+	recipients []RewardRecipientConfig,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	rewardPool ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetRewardRecipients {
+	return NewSetRewardRecipientsInstructionBuilder().
+		SetDonConfigIndex(donConfigIndex).
+		SetRecipients(recipients).
+		SetVerifierAccountAccount(verifierAccount).
+		SetRewardPoolAccount(rewardPool).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,165 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Sets a subscriber's discount on fees in basis points
+type SetSubscriberDiscount struct {
+	DiscountBps *uint16
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] subscriberDiscount
+	//
+	// [2] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetSubscriberDiscountInstructionBuilder creates a new `SetSubscriberDiscount` instruction builder.
+func NewSetSubscriberDiscountInstructionBuilder() *SetSubscriberDiscount {
+	nd := &SetSubscriberDiscount{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 3),
+	}
+	return nd
+}
+
+// SetDiscountBps sets the "discountBps" parameter.
+func (inst *SetSubscriberDiscount) SetDiscountBps(discountBps uint16) *SetSubscriberDiscount {
+	inst.DiscountBps = &discountBps
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetSubscriberDiscount) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetSubscriberDiscount {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetSubscriberDiscount) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetSubscriberDiscountAccount sets the "subscriberDiscount" account.
+func (inst *SetSubscriberDiscount) SetSubscriberDiscountAccount(subscriberDiscount ag_solanago.PublicKey) *SetSubscriberDiscount {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(subscriberDiscount).WRITE()
+	return inst
+}
+
+// GetSubscriberDiscountAccount gets the "subscriberDiscount" account.
+func (inst *SetSubscriberDiscount) GetSubscriberDiscountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetSubscriberDiscount) SetOwnerAccount(owner ag_solanago.PublicKey) *SetSubscriberDiscount {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetSubscriberDiscount) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+func (inst SetSubscriberDiscount) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetSubscriberDiscount,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetSubscriberDiscount) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetSubscriberDiscount) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.DiscountBps == nil {
+			return errors.New("DiscountBps parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.SubscriberDiscount is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetSubscriberDiscount) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetSubscriberDiscount")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("DiscountBps", *inst.DiscountBps))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=3]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("          verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("subscriberDiscount", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("             owner", inst.AccountMetaSlice.Get(2)))
+					})
+				})
+		})
+}
+
+func (obj SetSubscriberDiscount) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `DiscountBps` param:
+	err = encoder.Encode(obj.DiscountBps)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetSubscriberDiscount) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `DiscountBps`:
+	err = decoder.Decode(&obj.DiscountBps)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetSubscriberDiscountInstruction declares a new SetSubscriberDiscount instruction with the provided parameters and accounts.
+func NewSetSubscriberDiscountInstruction(
+	// Parameters:
+	discountBps uint16,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	subscriberDiscount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetSubscriberDiscount {
+	return NewSetSubscriberDiscountInstructionBuilder().
+		SetDiscountBps(discountBps).
+		SetVerifierAccountAccount(verifierAccount).
+		SetSubscriberDiscountAccount(subscriberDiscount).
+		SetOwnerAccount(owner)
+}
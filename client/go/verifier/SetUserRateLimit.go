@@ -0,0 +1,207 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Gives a user their own rate limit instead of the default one
+type SetUserRateLimit struct {
+	MaxVerifications *uint64
+	WindowSlots      *uint64
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [WRITE] rateLimit
+	//
+	// [2] = [] user
+	//
+	// [3] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSetUserRateLimitInstructionBuilder creates a new `SetUserRateLimit` instruction builder.
+func NewSetUserRateLimitInstructionBuilder() *SetUserRateLimit {
+	nd := &SetUserRateLimit{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+	}
+	return nd
+}
+
+// SetMaxVerifications sets the "maxVerifications" parameter.
+func (inst *SetUserRateLimit) SetMaxVerifications(maxVerifications uint64) *SetUserRateLimit {
+	inst.MaxVerifications = &maxVerifications
+	return inst
+}
+
+// SetWindowSlots sets the "windowSlots" parameter.
+func (inst *SetUserRateLimit) SetWindowSlots(windowSlots uint64) *SetUserRateLimit {
+	inst.WindowSlots = &windowSlots
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SetUserRateLimit) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SetUserRateLimit {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SetUserRateLimit) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetRateLimitAccount sets the "rateLimit" account.
+func (inst *SetUserRateLimit) SetRateLimitAccount(rateLimit ag_solanago.PublicKey) *SetUserRateLimit {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(rateLimit).WRITE()
+	return inst
+}
+
+// GetRateLimitAccount gets the "rateLimit" account.
+func (inst *SetUserRateLimit) GetRateLimitAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetUserAccount sets the "user" account.
+func (inst *SetUserRateLimit) SetUserAccount(user ag_solanago.PublicKey) *SetUserRateLimit {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(user)
+	return inst
+}
+
+// GetUserAccount gets the "user" account.
+func (inst *SetUserRateLimit) GetUserAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SetUserRateLimit) SetOwnerAccount(owner ag_solanago.PublicKey) *SetUserRateLimit {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SetUserRateLimit) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+func (inst SetUserRateLimit) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SetUserRateLimit,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SetUserRateLimit) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SetUserRateLimit) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.MaxVerifications == nil {
+			return errors.New("MaxVerifications parameter is not set")
+		}
+		if inst.WindowSlots == nil {
+			return errors.New("WindowSlots parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.RateLimit is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.User is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SetUserRateLimit) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SetUserRateLimit")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=2]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("MaxVerifications", *inst.MaxVerifications))
+						paramsBranch.Child(ag_format.Param("     WindowSlots", *inst.WindowSlots))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta(" verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("rateLimit", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("     user", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("    owner", inst.AccountMetaSlice.Get(3)))
+					})
+				})
+		})
+}
+
+func (obj SetUserRateLimit) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `MaxVerifications` param:
+	err = encoder.Encode(obj.MaxVerifications)
+	if err != nil {
+		return err
+	}
+	// Serialize `WindowSlots` param:
+	err = encoder.Encode(obj.WindowSlots)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SetUserRateLimit) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `MaxVerifications`:
+	err = decoder.Decode(&obj.MaxVerifications)
+	if err != nil {
+		return err
+	}
+	// Deserialize `WindowSlots`:
+	err = decoder.Decode(&obj.WindowSlots)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSetUserRateLimitInstruction declares a new SetUserRateLimit instruction with the provided parameters and accounts.
+func NewSetUserRateLimitInstruction(
+	// Parameters:
+	maxVerifications uint64,
+	windowSlots uint64,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	rateLimit ag_solanago.PublicKey,
+	user ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SetUserRateLimit {
+	return NewSetUserRateLimitInstructionBuilder().
+		SetMaxVerifications(maxVerifications).
+		SetWindowSlots(windowSlots).
+		SetVerifierAccountAccount(verifierAccount).
+		SetRateLimitAccount(rateLimit).
+		SetUserAccount(user).
+		SetOwnerAccount(owner)
+}
@@ -0,0 +1,147 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Closes `billing_period` for the usage counters passed as remaining accounts, keeping their
+// count as the last period's usage and resetting them.
+type SnapshotUsage struct {
+	BillingPeriod *uint64
+
+	// [0] = [WRITE] verifierAccount
+	//
+	// [1] = [SIGNER] owner
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewSnapshotUsageInstructionBuilder creates a new `SnapshotUsage` instruction builder.
+func NewSnapshotUsageInstructionBuilder() *SnapshotUsage {
+	nd := &SnapshotUsage{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetBillingPeriod sets the "billingPeriod" parameter.
+func (inst *SnapshotUsage) SetBillingPeriod(billingPeriod uint64) *SnapshotUsage {
+	inst.BillingPeriod = &billingPeriod
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *SnapshotUsage) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *SnapshotUsage {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount).WRITE()
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *SnapshotUsage) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetOwnerAccount sets the "owner" account.
+func (inst *SnapshotUsage) SetOwnerAccount(owner ag_solanago.PublicKey) *SnapshotUsage {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(owner).SIGNER()
+	return inst
+}
+
+// GetOwnerAccount gets the "owner" account.
+func (inst *SnapshotUsage) GetOwnerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst SnapshotUsage) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_SnapshotUsage,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst SnapshotUsage) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *SnapshotUsage) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.BillingPeriod == nil {
+			return errors.New("BillingPeriod parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Owner is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *SnapshotUsage) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("SnapshotUsage")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=1]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("BillingPeriod", *inst.BillingPeriod))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   owner", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj SnapshotUsage) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `BillingPeriod` param:
+	err = encoder.Encode(obj.BillingPeriod)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *SnapshotUsage) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `BillingPeriod`:
+	err = decoder.Decode(&obj.BillingPeriod)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewSnapshotUsageInstruction declares a new SnapshotUsage instruction with the provided parameters and accounts.
+func NewSnapshotUsageInstruction(
+	// Parameters:
+	billingPeriod uint64,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	owner ag_solanago.PublicKey) *SnapshotUsage {
+	return NewSnapshotUsageInstructionBuilder().
+		SetBillingPeriod(billingPeriod).
+		SetVerifierAccountAccount(verifierAccount).
+		SetOwnerAccount(owner)
+}
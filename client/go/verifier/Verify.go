@@ -21,14 +21,72 @@ type Verify struct {
 	// [2] = [SIGNER] user
 	//
 	// [3] = [] configAccount
+	//
+	// [4] = [] feedAccessList (optional)
+	//
+	// [5] = [] accessGrant (optional)
+	//
+	// [6] = [] instructionsSysvar (optional)
+	//
+	// [7] = [WRITE] rateLimit (optional)
+	//
+	// [8] = [WRITE] usageCounter (optional)
+	//
+	// [9] = [] feeTokenMint (optional)
+	//
+	// [10] = [WRITE] requesterTokenAccount (optional)
+	//
+	// [11] = [WRITE] feeTokenVault (optional)
+	//
+	// [12] = [] tokenProgram (optional)
+	//
+	// [13] = [] feePreference (optional)
+	//
+	// [14] = [WRITE] nativeFeeVault (optional)
+	//
+	// [15] = [] systemProgram (optional)
+	//
+	// [16] = [WRITE] rewardPool (optional)
+	//
+	// [17] = [] subscriberDiscount (optional)
+	//
+	// [18] = [WRITE] receipt (optional)
+	//
+	// [19] = [] donNamespace (optional)
+	//
+	// [20] = [] configDigestRoute (optional)
+	//
+	// [21] = [] feedRoute (optional)
+	//
+	// [22] = [WRITE, SIGNER] payer (optional)
 	ag_solanago.AccountMetaSlice `bin:"-"`
 }
 
 // NewVerifyInstructionBuilder creates a new `Verify` instruction builder.
 func NewVerifyInstructionBuilder() *Verify {
 	nd := &Verify{
-		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 4),
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 23),
 	}
+	// Optional accounts are passed as the program ID until set
+	nd.AccountMetaSlice[4] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[5] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[6] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[7] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[8] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[9] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[10] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[11] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[12] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[13] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[14] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[15] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[16] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[17] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[18] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[19] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[20] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[21] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[22] = ag_solanago.Meta(ProgramID)
 	return nd
 }
 
@@ -82,6 +140,215 @@ func (inst *Verify) GetConfigAccountAccount() *ag_solanago.AccountMeta {
 	return inst.AccountMetaSlice.Get(3)
 }
 
+// SetFeedAccessListAccount sets the "feedAccessList" account.
+func (inst *Verify) SetFeedAccessListAccount(feedAccessList ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(feedAccessList)
+	return inst
+}
+
+// GetFeedAccessListAccount gets the "feedAccessList" account.
+func (inst *Verify) GetFeedAccessListAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+// SetAccessGrantAccount sets the "accessGrant" account.
+func (inst *Verify) SetAccessGrantAccount(accessGrant ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[5] = ag_solanago.Meta(accessGrant)
+	return inst
+}
+
+// GetAccessGrantAccount gets the "accessGrant" account.
+func (inst *Verify) GetAccessGrantAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(5)
+}
+
+// SetInstructionsSysvarAccount sets the "instructionsSysvar" account.
+func (inst *Verify) SetInstructionsSysvarAccount(instructionsSysvar ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[6] = ag_solanago.Meta(instructionsSysvar)
+	return inst
+}
+
+// GetInstructionsSysvarAccount gets the "instructionsSysvar" account.
+func (inst *Verify) GetInstructionsSysvarAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(6)
+}
+
+// SetRateLimitAccount sets the "rateLimit" account.
+func (inst *Verify) SetRateLimitAccount(rateLimit ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[7] = ag_solanago.Meta(rateLimit).WRITE()
+	return inst
+}
+
+// GetRateLimitAccount gets the "rateLimit" account.
+func (inst *Verify) GetRateLimitAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(7)
+}
+
+// SetUsageCounterAccount sets the "usageCounter" account.
+func (inst *Verify) SetUsageCounterAccount(usageCounter ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[8] = ag_solanago.Meta(usageCounter).WRITE()
+	return inst
+}
+
+// GetUsageCounterAccount gets the "usageCounter" account.
+func (inst *Verify) GetUsageCounterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(8)
+}
+
+// SetFeeTokenMintAccount sets the "feeTokenMint" account.
+func (inst *Verify) SetFeeTokenMintAccount(feeTokenMint ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[9] = ag_solanago.Meta(feeTokenMint)
+	return inst
+}
+
+// GetFeeTokenMintAccount gets the "feeTokenMint" account.
+func (inst *Verify) GetFeeTokenMintAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(9)
+}
+
+// SetRequesterTokenAccountAccount sets the "requesterTokenAccount" account.
+func (inst *Verify) SetRequesterTokenAccountAccount(requesterTokenAccount ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[10] = ag_solanago.Meta(requesterTokenAccount).WRITE()
+	return inst
+}
+
+// GetRequesterTokenAccountAccount gets the "requesterTokenAccount" account.
+func (inst *Verify) GetRequesterTokenAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(10)
+}
+
+// SetFeeTokenVaultAccount sets the "feeTokenVault" account.
+func (inst *Verify) SetFeeTokenVaultAccount(feeTokenVault ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[11] = ag_solanago.Meta(feeTokenVault).WRITE()
+	return inst
+}
+
+// GetFeeTokenVaultAccount gets the "feeTokenVault" account.
+func (inst *Verify) GetFeeTokenVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(11)
+}
+
+// SetTokenProgramAccount sets the "tokenProgram" account.
+func (inst *Verify) SetTokenProgramAccount(tokenProgram ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[12] = ag_solanago.Meta(tokenProgram)
+	return inst
+}
+
+// GetTokenProgramAccount gets the "tokenProgram" account.
+func (inst *Verify) GetTokenProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(12)
+}
+
+// SetFeePreferenceAccount sets the "feePreference" account.
+func (inst *Verify) SetFeePreferenceAccount(feePreference ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[13] = ag_solanago.Meta(feePreference)
+	return inst
+}
+
+// GetFeePreferenceAccount gets the "feePreference" account.
+func (inst *Verify) GetFeePreferenceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(13)
+}
+
+// SetNativeFeeVaultAccount sets the "nativeFeeVault" account.
+func (inst *Verify) SetNativeFeeVaultAccount(nativeFeeVault ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[14] = ag_solanago.Meta(nativeFeeVault).WRITE()
+	return inst
+}
+
+// GetNativeFeeVaultAccount gets the "nativeFeeVault" account.
+func (inst *Verify) GetNativeFeeVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(14)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *Verify) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[15] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *Verify) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(15)
+}
+
+// SetRewardPoolAccount sets the "rewardPool" account.
+func (inst *Verify) SetRewardPoolAccount(rewardPool ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[16] = ag_solanago.Meta(rewardPool).WRITE()
+	return inst
+}
+
+// GetRewardPoolAccount gets the "rewardPool" account.
+func (inst *Verify) GetRewardPoolAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(16)
+}
+
+// SetSubscriberDiscountAccount sets the "subscriberDiscount" account.
+func (inst *Verify) SetSubscriberDiscountAccount(subscriberDiscount ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[17] = ag_solanago.Meta(subscriberDiscount)
+	return inst
+}
+
+// GetSubscriberDiscountAccount gets the "subscriberDiscount" account.
+func (inst *Verify) GetSubscriberDiscountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(17)
+}
+
+// SetReceiptAccount sets the "receipt" account.
+func (inst *Verify) SetReceiptAccount(receipt ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[18] = ag_solanago.Meta(receipt).WRITE()
+	return inst
+}
+
+// GetReceiptAccount gets the "receipt" account.
+func (inst *Verify) GetReceiptAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(18)
+}
+
+// SetDonNamespaceAccount sets the "donNamespace" account.
+func (inst *Verify) SetDonNamespaceAccount(donNamespace ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[19] = ag_solanago.Meta(donNamespace)
+	return inst
+}
+
+// GetDonNamespaceAccount gets the "donNamespace" account.
+func (inst *Verify) GetDonNamespaceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(19)
+}
+
+// SetConfigDigestRouteAccount sets the "configDigestRoute" account.
+func (inst *Verify) SetConfigDigestRouteAccount(configDigestRoute ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[20] = ag_solanago.Meta(configDigestRoute)
+	return inst
+}
+
+// GetConfigDigestRouteAccount gets the "configDigestRoute" account.
+func (inst *Verify) GetConfigDigestRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(20)
+}
+
+// SetFeedRouteAccount sets the "feedRoute" account.
+func (inst *Verify) SetFeedRouteAccount(feedRoute ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[21] = ag_solanago.Meta(feedRoute)
+	return inst
+}
+
+// GetFeedRouteAccount gets the "feedRoute" account.
+func (inst *Verify) GetFeedRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(21)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *Verify) SetPayerAccount(payer ag_solanago.PublicKey) *Verify {
+	inst.AccountMetaSlice[22] = ag_solanago.Meta(payer).WRITE().SIGNER()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *Verify) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(22)
+}
+
 func (inst Verify) Build() *Instruction {
 	return &Instruction{BaseVariant: ag_binary.BaseVariant{
 		Impl:   inst,
@@ -139,11 +406,30 @@ func (inst *Verify) EncodeToTree(parent ag_treeout.Branches) {
 					})
 
 					// Accounts of the instruction:
-					instructionBranch.Child("Accounts[len=4]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
-						accountsBranch.Child(ag_format.Meta("        verifier", inst.AccountMetaSlice.Get(0)))
-						accountsBranch.Child(ag_format.Meta("accessController", inst.AccountMetaSlice.Get(1)))
-						accountsBranch.Child(ag_format.Meta("            user", inst.AccountMetaSlice.Get(2)))
-						accountsBranch.Child(ag_format.Meta("          config", inst.AccountMetaSlice.Get(3)))
+					instructionBranch.Child("Accounts[len=23]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("          verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("  accessController", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("              user", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("            config", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta("    feedAccessList", inst.AccountMetaSlice.Get(4)))
+						accountsBranch.Child(ag_format.Meta("       accessGrant", inst.AccountMetaSlice.Get(5)))
+						accountsBranch.Child(ag_format.Meta("instructionsSysvar", inst.AccountMetaSlice.Get(6)))
+						accountsBranch.Child(ag_format.Meta("         rateLimit", inst.AccountMetaSlice.Get(7)))
+						accountsBranch.Child(ag_format.Meta("      usageCounter", inst.AccountMetaSlice.Get(8)))
+						accountsBranch.Child(ag_format.Meta("      feeTokenMint", inst.AccountMetaSlice.Get(9)))
+						accountsBranch.Child(ag_format.Meta("    requesterToken", inst.AccountMetaSlice.Get(10)))
+						accountsBranch.Child(ag_format.Meta("     feeTokenVault", inst.AccountMetaSlice.Get(11)))
+						accountsBranch.Child(ag_format.Meta("      tokenProgram", inst.AccountMetaSlice.Get(12)))
+						accountsBranch.Child(ag_format.Meta("     feePreference", inst.AccountMetaSlice.Get(13)))
+						accountsBranch.Child(ag_format.Meta("    nativeFeeVault", inst.AccountMetaSlice.Get(14)))
+						accountsBranch.Child(ag_format.Meta("     systemProgram", inst.AccountMetaSlice.Get(15)))
+						accountsBranch.Child(ag_format.Meta("        rewardPool", inst.AccountMetaSlice.Get(16)))
+						accountsBranch.Child(ag_format.Meta("subscriberDiscount", inst.AccountMetaSlice.Get(17)))
+						accountsBranch.Child(ag_format.Meta("           receipt", inst.AccountMetaSlice.Get(18)))
+						accountsBranch.Child(ag_format.Meta("      donNamespace", inst.AccountMetaSlice.Get(19)))
+						accountsBranch.Child(ag_format.Meta(" configDigestRoute", inst.AccountMetaSlice.Get(20)))
+						accountsBranch.Child(ag_format.Meta("         feedRoute", inst.AccountMetaSlice.Get(21)))
+						accountsBranch.Child(ag_format.Meta("             payer", inst.AccountMetaSlice.Get(22)))
 					})
 				})
 		})
@@ -167,6 +453,7 @@ func (obj *Verify) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error)
 }
 
 // NewVerifyInstruction declares a new Verify instruction with the provided parameters and accounts.
+// Optional accounts are set with the builder.
 func NewVerifyInstruction(
 	// Parameters:
 	signedReport []byte,
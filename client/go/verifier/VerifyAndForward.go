@@ -0,0 +1,536 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Verifies the report like `verify`, then calls the consumer program with
+// `consumer_discriminator` immediately followed by the Borsh serialized `DecodedReport`, so
+// the data of the call holds nothing else the caller chose. The consumer receives the
+// forwarder PDA as a signer, followed by the remaining accounts with their signer and
+// writable flags.
+type VerifyAndForward struct {
+	SignedReport          *[]byte
+	ConsumerDiscriminator *[8]uint8
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [] accessController
+	//
+	// [2] = [SIGNER] user
+	//
+	// [3] = [] configAccount
+	//
+	// [4] = [] feedAccessList (optional)
+	//
+	// [5] = [] accessGrant (optional)
+	//
+	// [6] = [] instructionsSysvar (optional)
+	//
+	// [7] = [WRITE] rateLimit (optional)
+	//
+	// [8] = [WRITE] usageCounter (optional)
+	//
+	// [9] = [] feeTokenMint (optional)
+	//
+	// [10] = [WRITE] requesterTokenAccount (optional)
+	//
+	// [11] = [WRITE] feeTokenVault (optional)
+	//
+	// [12] = [] tokenProgram (optional)
+	//
+	// [13] = [] feePreference (optional)
+	//
+	// [14] = [WRITE] nativeFeeVault (optional)
+	//
+	// [15] = [] systemProgram (optional)
+	//
+	// [16] = [WRITE] rewardPool (optional)
+	//
+	// [17] = [] subscriberDiscount (optional)
+	//
+	// [18] = [WRITE] receipt (optional)
+	//
+	// [19] = [] donNamespace (optional)
+	//
+	// [20] = [] configDigestRoute (optional)
+	//
+	// [21] = [] feedRoute (optional)
+	//
+	// [22] = [WRITE, SIGNER] payer (optional)
+	//
+	// [23] = [] consumerProgram
+	//
+	// [24] = [] forwarder
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewVerifyAndForwardInstructionBuilder creates a new `VerifyAndForward` instruction builder.
+func NewVerifyAndForwardInstructionBuilder() *VerifyAndForward {
+	nd := &VerifyAndForward{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 25),
+	}
+	// Optional accounts are passed as the program ID until set
+	nd.AccountMetaSlice[4] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[5] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[6] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[7] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[8] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[9] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[10] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[11] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[12] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[13] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[14] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[15] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[16] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[17] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[18] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[19] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[20] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[21] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[22] = ag_solanago.Meta(ProgramID)
+	return nd
+}
+
+// SetSignedReport sets the "signedReport" parameter.
+func (inst *VerifyAndForward) SetSignedReport(signedReport []byte) *VerifyAndForward {
+	inst.SignedReport = &signedReport
+	return inst
+}
+
+// SetConsumerDiscriminator sets the "consumerDiscriminator" parameter.
+func (inst *VerifyAndForward) SetConsumerDiscriminator(consumerDiscriminator [8]uint8) *VerifyAndForward {
+	inst.ConsumerDiscriminator = &consumerDiscriminator
+	return inst
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *VerifyAndForward) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *VerifyAndForward) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetAccessControllerAccount sets the "accessController" account.
+func (inst *VerifyAndForward) SetAccessControllerAccount(accessController ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(accessController)
+	return inst
+}
+
+// GetAccessControllerAccount gets the "accessController" account.
+func (inst *VerifyAndForward) GetAccessControllerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetUserAccount sets the "user" account.
+func (inst *VerifyAndForward) SetUserAccount(user ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(user).SIGNER()
+	return inst
+}
+
+// GetUserAccount gets the "user" account.
+func (inst *VerifyAndForward) GetUserAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetConfigAccountAccount sets the "configAccount" account.
+func (inst *VerifyAndForward) SetConfigAccountAccount(configAccount ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(configAccount)
+	return inst
+}
+
+// GetConfigAccountAccount gets the "configAccount" account.
+func (inst *VerifyAndForward) GetConfigAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetFeedAccessListAccount sets the "feedAccessList" account.
+func (inst *VerifyAndForward) SetFeedAccessListAccount(feedAccessList ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(feedAccessList)
+	return inst
+}
+
+// GetFeedAccessListAccount gets the "feedAccessList" account.
+func (inst *VerifyAndForward) GetFeedAccessListAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+// SetAccessGrantAccount sets the "accessGrant" account.
+func (inst *VerifyAndForward) SetAccessGrantAccount(accessGrant ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[5] = ag_solanago.Meta(accessGrant)
+	return inst
+}
+
+// GetAccessGrantAccount gets the "accessGrant" account.
+func (inst *VerifyAndForward) GetAccessGrantAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(5)
+}
+
+// SetInstructionsSysvarAccount sets the "instructionsSysvar" account.
+func (inst *VerifyAndForward) SetInstructionsSysvarAccount(instructionsSysvar ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[6] = ag_solanago.Meta(instructionsSysvar)
+	return inst
+}
+
+// GetInstructionsSysvarAccount gets the "instructionsSysvar" account.
+func (inst *VerifyAndForward) GetInstructionsSysvarAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(6)
+}
+
+// SetRateLimitAccount sets the "rateLimit" account.
+func (inst *VerifyAndForward) SetRateLimitAccount(rateLimit ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[7] = ag_solanago.Meta(rateLimit).WRITE()
+	return inst
+}
+
+// GetRateLimitAccount gets the "rateLimit" account.
+func (inst *VerifyAndForward) GetRateLimitAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(7)
+}
+
+// SetUsageCounterAccount sets the "usageCounter" account.
+func (inst *VerifyAndForward) SetUsageCounterAccount(usageCounter ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[8] = ag_solanago.Meta(usageCounter).WRITE()
+	return inst
+}
+
+// GetUsageCounterAccount gets the "usageCounter" account.
+func (inst *VerifyAndForward) GetUsageCounterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(8)
+}
+
+// SetFeeTokenMintAccount sets the "feeTokenMint" account.
+func (inst *VerifyAndForward) SetFeeTokenMintAccount(feeTokenMint ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[9] = ag_solanago.Meta(feeTokenMint)
+	return inst
+}
+
+// GetFeeTokenMintAccount gets the "feeTokenMint" account.
+func (inst *VerifyAndForward) GetFeeTokenMintAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(9)
+}
+
+// SetRequesterTokenAccountAccount sets the "requesterTokenAccount" account.
+func (inst *VerifyAndForward) SetRequesterTokenAccountAccount(requesterTokenAccount ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[10] = ag_solanago.Meta(requesterTokenAccount).WRITE()
+	return inst
+}
+
+// GetRequesterTokenAccountAccount gets the "requesterTokenAccount" account.
+func (inst *VerifyAndForward) GetRequesterTokenAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(10)
+}
+
+// SetFeeTokenVaultAccount sets the "feeTokenVault" account.
+func (inst *VerifyAndForward) SetFeeTokenVaultAccount(feeTokenVault ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[11] = ag_solanago.Meta(feeTokenVault).WRITE()
+	return inst
+}
+
+// GetFeeTokenVaultAccount gets the "feeTokenVault" account.
+func (inst *VerifyAndForward) GetFeeTokenVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(11)
+}
+
+// SetTokenProgramAccount sets the "tokenProgram" account.
+func (inst *VerifyAndForward) SetTokenProgramAccount(tokenProgram ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[12] = ag_solanago.Meta(tokenProgram)
+	return inst
+}
+
+// GetTokenProgramAccount gets the "tokenProgram" account.
+func (inst *VerifyAndForward) GetTokenProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(12)
+}
+
+// SetFeePreferenceAccount sets the "feePreference" account.
+func (inst *VerifyAndForward) SetFeePreferenceAccount(feePreference ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[13] = ag_solanago.Meta(feePreference)
+	return inst
+}
+
+// GetFeePreferenceAccount gets the "feePreference" account.
+func (inst *VerifyAndForward) GetFeePreferenceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(13)
+}
+
+// SetNativeFeeVaultAccount sets the "nativeFeeVault" account.
+func (inst *VerifyAndForward) SetNativeFeeVaultAccount(nativeFeeVault ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[14] = ag_solanago.Meta(nativeFeeVault).WRITE()
+	return inst
+}
+
+// GetNativeFeeVaultAccount gets the "nativeFeeVault" account.
+func (inst *VerifyAndForward) GetNativeFeeVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(14)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *VerifyAndForward) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[15] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *VerifyAndForward) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(15)
+}
+
+// SetRewardPoolAccount sets the "rewardPool" account.
+func (inst *VerifyAndForward) SetRewardPoolAccount(rewardPool ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[16] = ag_solanago.Meta(rewardPool).WRITE()
+	return inst
+}
+
+// GetRewardPoolAccount gets the "rewardPool" account.
+func (inst *VerifyAndForward) GetRewardPoolAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(16)
+}
+
+// SetSubscriberDiscountAccount sets the "subscriberDiscount" account.
+func (inst *VerifyAndForward) SetSubscriberDiscountAccount(subscriberDiscount ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[17] = ag_solanago.Meta(subscriberDiscount)
+	return inst
+}
+
+// GetSubscriberDiscountAccount gets the "subscriberDiscount" account.
+func (inst *VerifyAndForward) GetSubscriberDiscountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(17)
+}
+
+// SetReceiptAccount sets the "receipt" account.
+func (inst *VerifyAndForward) SetReceiptAccount(receipt ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[18] = ag_solanago.Meta(receipt).WRITE()
+	return inst
+}
+
+// GetReceiptAccount gets the "receipt" account.
+func (inst *VerifyAndForward) GetReceiptAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(18)
+}
+
+// SetDonNamespaceAccount sets the "donNamespace" account.
+func (inst *VerifyAndForward) SetDonNamespaceAccount(donNamespace ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[19] = ag_solanago.Meta(donNamespace)
+	return inst
+}
+
+// GetDonNamespaceAccount gets the "donNamespace" account.
+func (inst *VerifyAndForward) GetDonNamespaceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(19)
+}
+
+// SetConfigDigestRouteAccount sets the "configDigestRoute" account.
+func (inst *VerifyAndForward) SetConfigDigestRouteAccount(configDigestRoute ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[20] = ag_solanago.Meta(configDigestRoute)
+	return inst
+}
+
+// GetConfigDigestRouteAccount gets the "configDigestRoute" account.
+func (inst *VerifyAndForward) GetConfigDigestRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(20)
+}
+
+// SetFeedRouteAccount sets the "feedRoute" account.
+func (inst *VerifyAndForward) SetFeedRouteAccount(feedRoute ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[21] = ag_solanago.Meta(feedRoute)
+	return inst
+}
+
+// GetFeedRouteAccount gets the "feedRoute" account.
+func (inst *VerifyAndForward) GetFeedRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(21)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *VerifyAndForward) SetPayerAccount(payer ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[22] = ag_solanago.Meta(payer).WRITE().SIGNER()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *VerifyAndForward) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(22)
+}
+
+// SetConsumerProgramAccount sets the "consumerProgram" account.
+func (inst *VerifyAndForward) SetConsumerProgramAccount(consumerProgram ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[23] = ag_solanago.Meta(consumerProgram)
+	return inst
+}
+
+// GetConsumerProgramAccount gets the "consumerProgram" account.
+func (inst *VerifyAndForward) GetConsumerProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(23)
+}
+
+// SetForwarderAccount sets the "forwarder" account.
+func (inst *VerifyAndForward) SetForwarderAccount(forwarder ag_solanago.PublicKey) *VerifyAndForward {
+	inst.AccountMetaSlice[24] = ag_solanago.Meta(forwarder)
+	return inst
+}
+
+// GetForwarderAccount gets the "forwarder" account.
+func (inst *VerifyAndForward) GetForwarderAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(24)
+}
+
+func (inst VerifyAndForward) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_VerifyAndForward,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst VerifyAndForward) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *VerifyAndForward) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.SignedReport == nil {
+			return errors.New("SignedReport parameter is not set")
+		}
+		if inst.ConsumerDiscriminator == nil {
+			return errors.New("ConsumerDiscriminator parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.AccessController is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.User is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.ConfigAccount is not set")
+		}
+		if inst.AccountMetaSlice[23] == nil {
+			return errors.New("accounts.ConsumerProgram is not set")
+		}
+		if inst.AccountMetaSlice[24] == nil {
+			return errors.New("accounts.Forwarder is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *VerifyAndForward) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("VerifyAndForward")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=2]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("         SignedReport", *inst.SignedReport))
+						paramsBranch.Child(ag_format.Param("ConsumerDiscriminator", *inst.ConsumerDiscriminator))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=25]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("          verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("  accessController", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("              user", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("            config", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta("    feedAccessList", inst.AccountMetaSlice.Get(4)))
+						accountsBranch.Child(ag_format.Meta("       accessGrant", inst.AccountMetaSlice.Get(5)))
+						accountsBranch.Child(ag_format.Meta("instructionsSysvar", inst.AccountMetaSlice.Get(6)))
+						accountsBranch.Child(ag_format.Meta("         rateLimit", inst.AccountMetaSlice.Get(7)))
+						accountsBranch.Child(ag_format.Meta("      usageCounter", inst.AccountMetaSlice.Get(8)))
+						accountsBranch.Child(ag_format.Meta("      feeTokenMint", inst.AccountMetaSlice.Get(9)))
+						accountsBranch.Child(ag_format.Meta("    requesterToken", inst.AccountMetaSlice.Get(10)))
+						accountsBranch.Child(ag_format.Meta("     feeTokenVault", inst.AccountMetaSlice.Get(11)))
+						accountsBranch.Child(ag_format.Meta("      tokenProgram", inst.AccountMetaSlice.Get(12)))
+						accountsBranch.Child(ag_format.Meta("     feePreference", inst.AccountMetaSlice.Get(13)))
+						accountsBranch.Child(ag_format.Meta("    nativeFeeVault", inst.AccountMetaSlice.Get(14)))
+						accountsBranch.Child(ag_format.Meta("     systemProgram", inst.AccountMetaSlice.Get(15)))
+						accountsBranch.Child(ag_format.Meta("        rewardPool", inst.AccountMetaSlice.Get(16)))
+						accountsBranch.Child(ag_format.Meta("subscriberDiscount", inst.AccountMetaSlice.Get(17)))
+						accountsBranch.Child(ag_format.Meta("           receipt", inst.AccountMetaSlice.Get(18)))
+						accountsBranch.Child(ag_format.Meta("      donNamespace", inst.AccountMetaSlice.Get(19)))
+						accountsBranch.Child(ag_format.Meta(" configDigestRoute", inst.AccountMetaSlice.Get(20)))
+						accountsBranch.Child(ag_format.Meta("         feedRoute", inst.AccountMetaSlice.Get(21)))
+						accountsBranch.Child(ag_format.Meta("             payer", inst.AccountMetaSlice.Get(22)))
+						accountsBranch.Child(ag_format.Meta("   consumerProgram", inst.AccountMetaSlice.Get(23)))
+						accountsBranch.Child(ag_format.Meta("         forwarder", inst.AccountMetaSlice.Get(24)))
+					})
+				})
+		})
+}
+
+func (obj VerifyAndForward) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `SignedReport` param:
+	err = encoder.Encode(obj.SignedReport)
+	if err != nil {
+		return err
+	}
+	// Serialize `ConsumerDiscriminator` param:
+	err = encoder.Encode(obj.ConsumerDiscriminator)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *VerifyAndForward) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `SignedReport`:
+	err = decoder.Decode(&obj.SignedReport)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ConsumerDiscriminator`:
+	err = decoder.Decode(&obj.ConsumerDiscriminator)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewVerifyAndForwardInstruction declares a new VerifyAndForward instruction with the provided parameters and accounts.
+// Optional accounts are set with the builder.
+func NewVerifyAndForwardInstruction(
+	// Parameters:
+	signedReport []byte,
+	consumerDiscriminator [8]uint8,
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	accessController ag_solanago.PublicKey,
+	user ag_solanago.PublicKey,
+	configAccount ag_solanago.PublicKey,
+	consumerProgram ag_solanago.PublicKey,
+	forwarder ag_solanago.PublicKey) *VerifyAndForward {
+	return NewVerifyAndForwardInstructionBuilder().
+		SetSignedReport(signedReport).
+		SetConsumerDiscriminator(consumerDiscriminator).
+		SetVerifierAccountAccount(verifierAccount).
+		SetAccessControllerAccount(accessController).
+		SetUserAccount(user).
+		SetConfigAccountAccount(configAccount).
+		SetConsumerProgramAccount(consumerProgram).
+		SetForwarderAccount(forwarder)
+}
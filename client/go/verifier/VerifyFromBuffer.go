@@ -0,0 +1,478 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Verifies the signed report staged in the report buffer, for reports too large for a single
+// transaction. The report data is written to the report output buffer when given, and to
+// return data otherwise.
+type VerifyFromBuffer struct {
+
+	// [0] = [] verifierAccount
+	//
+	// [1] = [] accessController
+	//
+	// [2] = [SIGNER] user
+	//
+	// [3] = [] configAccount
+	//
+	// [4] = [] feedAccessList (optional)
+	//
+	// [5] = [] accessGrant (optional)
+	//
+	// [6] = [] instructionsSysvar (optional)
+	//
+	// [7] = [WRITE] rateLimit (optional)
+	//
+	// [8] = [WRITE] usageCounter (optional)
+	//
+	// [9] = [] feeTokenMint (optional)
+	//
+	// [10] = [WRITE] requesterTokenAccount (optional)
+	//
+	// [11] = [WRITE] feeTokenVault (optional)
+	//
+	// [12] = [] tokenProgram (optional)
+	//
+	// [13] = [] feePreference (optional)
+	//
+	// [14] = [WRITE] nativeFeeVault (optional)
+	//
+	// [15] = [] systemProgram (optional)
+	//
+	// [16] = [WRITE] rewardPool (optional)
+	//
+	// [17] = [] subscriberDiscount (optional)
+	//
+	// [18] = [WRITE] receipt (optional)
+	//
+	// [19] = [] donNamespace (optional)
+	//
+	// [20] = [] configDigestRoute (optional)
+	//
+	// [21] = [] feedRoute (optional)
+	//
+	// [22] = [WRITE, SIGNER] payer (optional)
+	//
+	// [23] = [] reportBuffer
+	//
+	// [24] = [WRITE] reportOutput (optional)
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewVerifyFromBufferInstructionBuilder creates a new `VerifyFromBuffer` instruction builder.
+func NewVerifyFromBufferInstructionBuilder() *VerifyFromBuffer {
+	nd := &VerifyFromBuffer{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 25),
+	}
+	// Optional accounts are passed as the program ID until set
+	nd.AccountMetaSlice[4] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[5] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[6] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[7] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[8] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[9] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[10] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[11] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[12] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[13] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[14] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[15] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[16] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[17] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[18] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[19] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[20] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[21] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[22] = ag_solanago.Meta(ProgramID)
+	nd.AccountMetaSlice[24] = ag_solanago.Meta(ProgramID)
+	return nd
+}
+
+// SetVerifierAccountAccount sets the "verifierAccount" account.
+func (inst *VerifyFromBuffer) SetVerifierAccountAccount(verifierAccount ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(verifierAccount)
+	return inst
+}
+
+// GetVerifierAccountAccount gets the "verifierAccount" account.
+func (inst *VerifyFromBuffer) GetVerifierAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetAccessControllerAccount sets the "accessController" account.
+func (inst *VerifyFromBuffer) SetAccessControllerAccount(accessController ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(accessController)
+	return inst
+}
+
+// GetAccessControllerAccount gets the "accessController" account.
+func (inst *VerifyFromBuffer) GetAccessControllerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+// SetUserAccount sets the "user" account.
+func (inst *VerifyFromBuffer) SetUserAccount(user ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[2] = ag_solanago.Meta(user).SIGNER()
+	return inst
+}
+
+// GetUserAccount gets the "user" account.
+func (inst *VerifyFromBuffer) GetUserAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(2)
+}
+
+// SetConfigAccountAccount sets the "configAccount" account.
+func (inst *VerifyFromBuffer) SetConfigAccountAccount(configAccount ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[3] = ag_solanago.Meta(configAccount)
+	return inst
+}
+
+// GetConfigAccountAccount gets the "configAccount" account.
+func (inst *VerifyFromBuffer) GetConfigAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(3)
+}
+
+// SetFeedAccessListAccount sets the "feedAccessList" account.
+func (inst *VerifyFromBuffer) SetFeedAccessListAccount(feedAccessList ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[4] = ag_solanago.Meta(feedAccessList)
+	return inst
+}
+
+// GetFeedAccessListAccount gets the "feedAccessList" account.
+func (inst *VerifyFromBuffer) GetFeedAccessListAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(4)
+}
+
+// SetAccessGrantAccount sets the "accessGrant" account.
+func (inst *VerifyFromBuffer) SetAccessGrantAccount(accessGrant ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[5] = ag_solanago.Meta(accessGrant)
+	return inst
+}
+
+// GetAccessGrantAccount gets the "accessGrant" account.
+func (inst *VerifyFromBuffer) GetAccessGrantAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(5)
+}
+
+// SetInstructionsSysvarAccount sets the "instructionsSysvar" account.
+func (inst *VerifyFromBuffer) SetInstructionsSysvarAccount(instructionsSysvar ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[6] = ag_solanago.Meta(instructionsSysvar)
+	return inst
+}
+
+// GetInstructionsSysvarAccount gets the "instructionsSysvar" account.
+func (inst *VerifyFromBuffer) GetInstructionsSysvarAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(6)
+}
+
+// SetRateLimitAccount sets the "rateLimit" account.
+func (inst *VerifyFromBuffer) SetRateLimitAccount(rateLimit ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[7] = ag_solanago.Meta(rateLimit).WRITE()
+	return inst
+}
+
+// GetRateLimitAccount gets the "rateLimit" account.
+func (inst *VerifyFromBuffer) GetRateLimitAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(7)
+}
+
+// SetUsageCounterAccount sets the "usageCounter" account.
+func (inst *VerifyFromBuffer) SetUsageCounterAccount(usageCounter ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[8] = ag_solanago.Meta(usageCounter).WRITE()
+	return inst
+}
+
+// GetUsageCounterAccount gets the "usageCounter" account.
+func (inst *VerifyFromBuffer) GetUsageCounterAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(8)
+}
+
+// SetFeeTokenMintAccount sets the "feeTokenMint" account.
+func (inst *VerifyFromBuffer) SetFeeTokenMintAccount(feeTokenMint ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[9] = ag_solanago.Meta(feeTokenMint)
+	return inst
+}
+
+// GetFeeTokenMintAccount gets the "feeTokenMint" account.
+func (inst *VerifyFromBuffer) GetFeeTokenMintAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(9)
+}
+
+// SetRequesterTokenAccountAccount sets the "requesterTokenAccount" account.
+func (inst *VerifyFromBuffer) SetRequesterTokenAccountAccount(requesterTokenAccount ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[10] = ag_solanago.Meta(requesterTokenAccount).WRITE()
+	return inst
+}
+
+// GetRequesterTokenAccountAccount gets the "requesterTokenAccount" account.
+func (inst *VerifyFromBuffer) GetRequesterTokenAccountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(10)
+}
+
+// SetFeeTokenVaultAccount sets the "feeTokenVault" account.
+func (inst *VerifyFromBuffer) SetFeeTokenVaultAccount(feeTokenVault ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[11] = ag_solanago.Meta(feeTokenVault).WRITE()
+	return inst
+}
+
+// GetFeeTokenVaultAccount gets the "feeTokenVault" account.
+func (inst *VerifyFromBuffer) GetFeeTokenVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(11)
+}
+
+// SetTokenProgramAccount sets the "tokenProgram" account.
+func (inst *VerifyFromBuffer) SetTokenProgramAccount(tokenProgram ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[12] = ag_solanago.Meta(tokenProgram)
+	return inst
+}
+
+// GetTokenProgramAccount gets the "tokenProgram" account.
+func (inst *VerifyFromBuffer) GetTokenProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(12)
+}
+
+// SetFeePreferenceAccount sets the "feePreference" account.
+func (inst *VerifyFromBuffer) SetFeePreferenceAccount(feePreference ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[13] = ag_solanago.Meta(feePreference)
+	return inst
+}
+
+// GetFeePreferenceAccount gets the "feePreference" account.
+func (inst *VerifyFromBuffer) GetFeePreferenceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(13)
+}
+
+// SetNativeFeeVaultAccount sets the "nativeFeeVault" account.
+func (inst *VerifyFromBuffer) SetNativeFeeVaultAccount(nativeFeeVault ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[14] = ag_solanago.Meta(nativeFeeVault).WRITE()
+	return inst
+}
+
+// GetNativeFeeVaultAccount gets the "nativeFeeVault" account.
+func (inst *VerifyFromBuffer) GetNativeFeeVaultAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(14)
+}
+
+// SetSystemProgramAccount sets the "systemProgram" account.
+func (inst *VerifyFromBuffer) SetSystemProgramAccount(systemProgram ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[15] = ag_solanago.Meta(systemProgram)
+	return inst
+}
+
+// GetSystemProgramAccount gets the "systemProgram" account.
+func (inst *VerifyFromBuffer) GetSystemProgramAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(15)
+}
+
+// SetRewardPoolAccount sets the "rewardPool" account.
+func (inst *VerifyFromBuffer) SetRewardPoolAccount(rewardPool ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[16] = ag_solanago.Meta(rewardPool).WRITE()
+	return inst
+}
+
+// GetRewardPoolAccount gets the "rewardPool" account.
+func (inst *VerifyFromBuffer) GetRewardPoolAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(16)
+}
+
+// SetSubscriberDiscountAccount sets the "subscriberDiscount" account.
+func (inst *VerifyFromBuffer) SetSubscriberDiscountAccount(subscriberDiscount ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[17] = ag_solanago.Meta(subscriberDiscount)
+	return inst
+}
+
+// GetSubscriberDiscountAccount gets the "subscriberDiscount" account.
+func (inst *VerifyFromBuffer) GetSubscriberDiscountAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(17)
+}
+
+// SetReceiptAccount sets the "receipt" account.
+func (inst *VerifyFromBuffer) SetReceiptAccount(receipt ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[18] = ag_solanago.Meta(receipt).WRITE()
+	return inst
+}
+
+// GetReceiptAccount gets the "receipt" account.
+func (inst *VerifyFromBuffer) GetReceiptAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(18)
+}
+
+// SetDonNamespaceAccount sets the "donNamespace" account.
+func (inst *VerifyFromBuffer) SetDonNamespaceAccount(donNamespace ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[19] = ag_solanago.Meta(donNamespace)
+	return inst
+}
+
+// GetDonNamespaceAccount gets the "donNamespace" account.
+func (inst *VerifyFromBuffer) GetDonNamespaceAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(19)
+}
+
+// SetConfigDigestRouteAccount sets the "configDigestRoute" account.
+func (inst *VerifyFromBuffer) SetConfigDigestRouteAccount(configDigestRoute ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[20] = ag_solanago.Meta(configDigestRoute)
+	return inst
+}
+
+// GetConfigDigestRouteAccount gets the "configDigestRoute" account.
+func (inst *VerifyFromBuffer) GetConfigDigestRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(20)
+}
+
+// SetFeedRouteAccount sets the "feedRoute" account.
+func (inst *VerifyFromBuffer) SetFeedRouteAccount(feedRoute ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[21] = ag_solanago.Meta(feedRoute)
+	return inst
+}
+
+// GetFeedRouteAccount gets the "feedRoute" account.
+func (inst *VerifyFromBuffer) GetFeedRouteAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(21)
+}
+
+// SetPayerAccount sets the "payer" account.
+func (inst *VerifyFromBuffer) SetPayerAccount(payer ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[22] = ag_solanago.Meta(payer).WRITE().SIGNER()
+	return inst
+}
+
+// GetPayerAccount gets the "payer" account.
+func (inst *VerifyFromBuffer) GetPayerAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(22)
+}
+
+// SetReportBufferAccount sets the "reportBuffer" account.
+func (inst *VerifyFromBuffer) SetReportBufferAccount(reportBuffer ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[23] = ag_solanago.Meta(reportBuffer)
+	return inst
+}
+
+// GetReportBufferAccount gets the "reportBuffer" account.
+func (inst *VerifyFromBuffer) GetReportBufferAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(23)
+}
+
+// SetReportOutputAccount sets the "reportOutput" account.
+func (inst *VerifyFromBuffer) SetReportOutputAccount(reportOutput ag_solanago.PublicKey) *VerifyFromBuffer {
+	inst.AccountMetaSlice[24] = ag_solanago.Meta(reportOutput).WRITE()
+	return inst
+}
+
+// GetReportOutputAccount gets the "reportOutput" account.
+func (inst *VerifyFromBuffer) GetReportOutputAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(24)
+}
+
+func (inst VerifyFromBuffer) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_VerifyFromBuffer,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst VerifyFromBuffer) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *VerifyFromBuffer) Validate() error {
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.VerifierAccount is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.AccessController is not set")
+		}
+		if inst.AccountMetaSlice[2] == nil {
+			return errors.New("accounts.User is not set")
+		}
+		if inst.AccountMetaSlice[3] == nil {
+			return errors.New("accounts.ConfigAccount is not set")
+		}
+		if inst.AccountMetaSlice[23] == nil {
+			return errors.New("accounts.ReportBuffer is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *VerifyFromBuffer) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("VerifyFromBuffer")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=0]").ParentFunc(func(paramsBranch ag_treeout.Branches) {})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=25]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("          verifier", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("  accessController", inst.AccountMetaSlice.Get(1)))
+						accountsBranch.Child(ag_format.Meta("              user", inst.AccountMetaSlice.Get(2)))
+						accountsBranch.Child(ag_format.Meta("            config", inst.AccountMetaSlice.Get(3)))
+						accountsBranch.Child(ag_format.Meta("    feedAccessList", inst.AccountMetaSlice.Get(4)))
+						accountsBranch.Child(ag_format.Meta("       accessGrant", inst.AccountMetaSlice.Get(5)))
+						accountsBranch.Child(ag_format.Meta("instructionsSysvar", inst.AccountMetaSlice.Get(6)))
+						accountsBranch.Child(ag_format.Meta("         rateLimit", inst.AccountMetaSlice.Get(7)))
+						accountsBranch.Child(ag_format.Meta("      usageCounter", inst.AccountMetaSlice.Get(8)))
+						accountsBranch.Child(ag_format.Meta("      feeTokenMint", inst.AccountMetaSlice.Get(9)))
+						accountsBranch.Child(ag_format.Meta("    requesterToken", inst.AccountMetaSlice.Get(10)))
+						accountsBranch.Child(ag_format.Meta("     feeTokenVault", inst.AccountMetaSlice.Get(11)))
+						accountsBranch.Child(ag_format.Meta("      tokenProgram", inst.AccountMetaSlice.Get(12)))
+						accountsBranch.Child(ag_format.Meta("     feePreference", inst.AccountMetaSlice.Get(13)))
+						accountsBranch.Child(ag_format.Meta("    nativeFeeVault", inst.AccountMetaSlice.Get(14)))
+						accountsBranch.Child(ag_format.Meta("     systemProgram", inst.AccountMetaSlice.Get(15)))
+						accountsBranch.Child(ag_format.Meta("        rewardPool", inst.AccountMetaSlice.Get(16)))
+						accountsBranch.Child(ag_format.Meta("subscriberDiscount", inst.AccountMetaSlice.Get(17)))
+						accountsBranch.Child(ag_format.Meta("           receipt", inst.AccountMetaSlice.Get(18)))
+						accountsBranch.Child(ag_format.Meta("      donNamespace", inst.AccountMetaSlice.Get(19)))
+						accountsBranch.Child(ag_format.Meta(" configDigestRoute", inst.AccountMetaSlice.Get(20)))
+						accountsBranch.Child(ag_format.Meta("         feedRoute", inst.AccountMetaSlice.Get(21)))
+						accountsBranch.Child(ag_format.Meta("             payer", inst.AccountMetaSlice.Get(22)))
+						accountsBranch.Child(ag_format.Meta("      reportBuffer", inst.AccountMetaSlice.Get(23)))
+						accountsBranch.Child(ag_format.Meta("      reportOutput", inst.AccountMetaSlice.Get(24)))
+					})
+				})
+		})
+}
+
+func (obj VerifyFromBuffer) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	return nil
+}
+func (obj *VerifyFromBuffer) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	return nil
+}
+
+// NewVerifyFromBufferInstruction declares a new VerifyFromBuffer instruction with the provided parameters and accounts.
+// Optional accounts are set with the builder.
+func NewVerifyFromBufferInstruction(
+	// Accounts:
+	verifierAccount ag_solanago.PublicKey,
+	accessController ag_solanago.PublicKey,
+	user ag_solanago.PublicKey,
+	configAccount ag_solanago.PublicKey,
+	reportBuffer ag_solanago.PublicKey) *VerifyFromBuffer {
+	return NewVerifyFromBufferInstructionBuilder().
+		SetVerifierAccountAccount(verifierAccount).
+		SetAccessControllerAccount(accessController).
+		SetUserAccount(user).
+		SetConfigAccountAccount(configAccount).
+		SetReportBufferAccount(reportBuffer)
+}
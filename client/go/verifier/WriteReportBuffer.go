@@ -0,0 +1,169 @@
+// Code generated by https://github.com/gagliardetto/anchor-go. DO NOT EDIT.
+
+package verifier
+
+import (
+	"errors"
+	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
+	ag_format "github.com/gagliardetto/solana-go/text/format"
+	ag_treeout "github.com/gagliardetto/treeout"
+)
+
+// Writes a chunk of the signed report at `offset`. Writing at offset 0 starts a new report.
+type WriteReportBuffer struct {
+	Offset *uint32
+	Data   *[]byte
+
+	// [0] = [WRITE] reportBuffer
+	//
+	// [1] = [SIGNER] authority
+	ag_solanago.AccountMetaSlice `bin:"-"`
+}
+
+// NewWriteReportBufferInstructionBuilder creates a new `WriteReportBuffer` instruction builder.
+func NewWriteReportBufferInstructionBuilder() *WriteReportBuffer {
+	nd := &WriteReportBuffer{
+		AccountMetaSlice: make(ag_solanago.AccountMetaSlice, 2),
+	}
+	return nd
+}
+
+// SetOffset sets the "offset" parameter.
+func (inst *WriteReportBuffer) SetOffset(offset uint32) *WriteReportBuffer {
+	inst.Offset = &offset
+	return inst
+}
+
+// SetData sets the "data" parameter.
+func (inst *WriteReportBuffer) SetData(data []byte) *WriteReportBuffer {
+	inst.Data = &data
+	return inst
+}
+
+// SetReportBufferAccount sets the "reportBuffer" account.
+func (inst *WriteReportBuffer) SetReportBufferAccount(reportBuffer ag_solanago.PublicKey) *WriteReportBuffer {
+	inst.AccountMetaSlice[0] = ag_solanago.Meta(reportBuffer).WRITE()
+	return inst
+}
+
+// GetReportBufferAccount gets the "reportBuffer" account.
+func (inst *WriteReportBuffer) GetReportBufferAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(0)
+}
+
+// SetAuthorityAccount sets the "authority" account.
+func (inst *WriteReportBuffer) SetAuthorityAccount(authority ag_solanago.PublicKey) *WriteReportBuffer {
+	inst.AccountMetaSlice[1] = ag_solanago.Meta(authority).SIGNER()
+	return inst
+}
+
+// GetAuthorityAccount gets the "authority" account.
+func (inst *WriteReportBuffer) GetAuthorityAccount() *ag_solanago.AccountMeta {
+	return inst.AccountMetaSlice.Get(1)
+}
+
+func (inst WriteReportBuffer) Build() *Instruction {
+	return &Instruction{BaseVariant: ag_binary.BaseVariant{
+		Impl:   inst,
+		TypeID: Instruction_WriteReportBuffer,
+	}}
+}
+
+// ValidateAndBuild validates the instruction parameters and accounts;
+// if there is a validation error, it returns the error.
+// Otherwise, it builds and returns the instruction.
+func (inst WriteReportBuffer) ValidateAndBuild() (*Instruction, error) {
+	if err := inst.Validate(); err != nil {
+		return nil, err
+	}
+	return inst.Build(), nil
+}
+
+func (inst *WriteReportBuffer) Validate() error {
+	// Check whether all (required) parameters are set:
+	{
+		if inst.Offset == nil {
+			return errors.New("Offset parameter is not set")
+		}
+		if inst.Data == nil {
+			return errors.New("Data parameter is not set")
+		}
+	}
+
+	// Check whether all (required) accounts are set:
+	{
+		if inst.AccountMetaSlice[0] == nil {
+			return errors.New("accounts.ReportBuffer is not set")
+		}
+		if inst.AccountMetaSlice[1] == nil {
+			return errors.New("accounts.Authority is not set")
+		}
+	}
+	return nil
+}
+
+func (inst *WriteReportBuffer) EncodeToTree(parent ag_treeout.Branches) {
+	parent.Child(ag_format.Program(ProgramName, ProgramID)).
+		//
+		ParentFunc(func(programBranch ag_treeout.Branches) {
+			programBranch.Child(ag_format.Instruction("WriteReportBuffer")).
+				//
+				ParentFunc(func(instructionBranch ag_treeout.Branches) {
+
+					// Parameters of the instruction:
+					instructionBranch.Child("Params[len=2]").ParentFunc(func(paramsBranch ag_treeout.Branches) {
+						paramsBranch.Child(ag_format.Param("Offset", *inst.Offset))
+						paramsBranch.Child(ag_format.Param("  Data", *inst.Data))
+					})
+
+					// Accounts of the instruction:
+					instructionBranch.Child("Accounts[len=2]").ParentFunc(func(accountsBranch ag_treeout.Branches) {
+						accountsBranch.Child(ag_format.Meta("reportBuffer", inst.AccountMetaSlice.Get(0)))
+						accountsBranch.Child(ag_format.Meta("   authority", inst.AccountMetaSlice.Get(1)))
+					})
+				})
+		})
+}
+
+func (obj WriteReportBuffer) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Offset` param:
+	err = encoder.Encode(obj.Offset)
+	if err != nil {
+		return err
+	}
+	// Serialize `Data` param:
+	err = encoder.Encode(obj.Data)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+func (obj *WriteReportBuffer) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Offset`:
+	err = decoder.Decode(&obj.Offset)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Data`:
+	err = decoder.Decode(&obj.Data)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+// NewWriteReportBufferInstruction declares a new WriteReportBuffer instruction with the provided parameters and accounts.
+func NewWriteReportBufferInstruction(
+	// Parameters:
+	offset uint32,
+	data []byte,
+	// Accounts:
+	reportBuffer ag_solanago.PublicKey,
+	authority ag_solanago.PublicKey) *WriteReportBuffer {
+	return NewWriteReportBufferInstructionBuilder().
+		SetOffset(offset).
+		SetData(data).
+		SetReportBufferAccount(reportBuffer).
+		SetAuthorityAccount(authority)
+}
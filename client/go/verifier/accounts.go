@@ -5,13 +5,30 @@ package verifier
 import (
 	"fmt"
 	ag_binary "github.com/gagliardetto/binary"
+	ag_solanago "github.com/gagliardetto/solana-go"
 )
 
 type VerifierAccount struct {
-	Version               uint8
-	Padding               [7]uint8
-	VerifierAccountConfig VerifierAccountConfig
-	DonConfigs            DonConfigs
+	Version                uint8
+	Padding                [7]uint8
+	VerifierAccountConfig  VerifierAccountConfig
+	DonConfigs             DonConfigs
+	ConfigRotationEnabled  uint8
+	ConfigRotationPadding  [3]uint8
+	ConfigRotationNonce    uint32
+	RateLimitEnabled       uint8
+	RateLimitPadding       [7]uint8
+	DefaultRateLimit       RateLimit
+	UsageMeteringEnabled   uint8
+	UsageMeteringPadding   [7]uint8
+	FeeEnabled             uint8
+	FeePadding             [7]uint8
+	FeeConfig              FeeConfig
+	NativeFeeEnabled       uint8
+	NativeFeePadding       [5]uint8
+	NativeSurchargeBps     uint16
+	AcceptedSchemaVersions SchemaVersions
+	DonRouteCount          uint64
 }
 
 var VerifierAccountDiscriminator = [8]byte{81, 120, 248, 87, 107, 174, 58, 157}
@@ -42,6 +59,86 @@ func (obj VerifierAccount) MarshalWithEncoder(encoder *ag_binary.Encoder) (err e
 	if err != nil {
 		return err
 	}
+	// Serialize `ConfigRotationEnabled` param:
+	err = encoder.Encode(obj.ConfigRotationEnabled)
+	if err != nil {
+		return err
+	}
+	// Serialize `ConfigRotationPadding` param:
+	err = encoder.Encode(obj.ConfigRotationPadding)
+	if err != nil {
+		return err
+	}
+	// Serialize `ConfigRotationNonce` param:
+	err = encoder.Encode(obj.ConfigRotationNonce)
+	if err != nil {
+		return err
+	}
+	// Serialize `RateLimitEnabled` param:
+	err = encoder.Encode(obj.RateLimitEnabled)
+	if err != nil {
+		return err
+	}
+	// Serialize `RateLimitPadding` param:
+	err = encoder.Encode(obj.RateLimitPadding)
+	if err != nil {
+		return err
+	}
+	// Serialize `DefaultRateLimit` param:
+	err = encoder.Encode(obj.DefaultRateLimit)
+	if err != nil {
+		return err
+	}
+	// Serialize `UsageMeteringEnabled` param:
+	err = encoder.Encode(obj.UsageMeteringEnabled)
+	if err != nil {
+		return err
+	}
+	// Serialize `UsageMeteringPadding` param:
+	err = encoder.Encode(obj.UsageMeteringPadding)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeeEnabled` param:
+	err = encoder.Encode(obj.FeeEnabled)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeePadding` param:
+	err = encoder.Encode(obj.FeePadding)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeeConfig` param:
+	err = encoder.Encode(obj.FeeConfig)
+	if err != nil {
+		return err
+	}
+	// Serialize `NativeFeeEnabled` param:
+	err = encoder.Encode(obj.NativeFeeEnabled)
+	if err != nil {
+		return err
+	}
+	// Serialize `NativeFeePadding` param:
+	err = encoder.Encode(obj.NativeFeePadding)
+	if err != nil {
+		return err
+	}
+	// Serialize `NativeSurchargeBps` param:
+	err = encoder.Encode(obj.NativeSurchargeBps)
+	if err != nil {
+		return err
+	}
+	// Serialize `AcceptedSchemaVersions` param:
+	err = encoder.Encode(obj.AcceptedSchemaVersions)
+	if err != nil {
+		return err
+	}
+	// Serialize `DonRouteCount` param:
+	err = encoder.Encode(obj.DonRouteCount)
+	if err != nil {
+		return err
+	}
 	return nil
 }
 
@@ -79,5 +176,793 @@ func (obj *VerifierAccount) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (er
 	if err != nil {
 		return err
 	}
+	// Deserialize `ConfigRotationEnabled`:
+	err = decoder.Decode(&obj.ConfigRotationEnabled)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ConfigRotationPadding`:
+	err = decoder.Decode(&obj.ConfigRotationPadding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ConfigRotationNonce`:
+	err = decoder.Decode(&obj.ConfigRotationNonce)
+	if err != nil {
+		return err
+	}
+	// Deserialize `RateLimitEnabled`:
+	err = decoder.Decode(&obj.RateLimitEnabled)
+	if err != nil {
+		return err
+	}
+	// Deserialize `RateLimitPadding`:
+	err = decoder.Decode(&obj.RateLimitPadding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `DefaultRateLimit`:
+	err = decoder.Decode(&obj.DefaultRateLimit)
+	if err != nil {
+		return err
+	}
+	// Deserialize `UsageMeteringEnabled`:
+	err = decoder.Decode(&obj.UsageMeteringEnabled)
+	if err != nil {
+		return err
+	}
+	// Deserialize `UsageMeteringPadding`:
+	err = decoder.Decode(&obj.UsageMeteringPadding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `FeeEnabled`:
+	err = decoder.Decode(&obj.FeeEnabled)
+	if err != nil {
+		return err
+	}
+	// Deserialize `FeePadding`:
+	err = decoder.Decode(&obj.FeePadding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `FeeConfig`:
+	err = decoder.Decode(&obj.FeeConfig)
+	if err != nil {
+		return err
+	}
+	// Deserialize `NativeFeeEnabled`:
+	err = decoder.Decode(&obj.NativeFeeEnabled)
+	if err != nil {
+		return err
+	}
+	// Deserialize `NativeFeePadding`:
+	err = decoder.Decode(&obj.NativeFeePadding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `NativeSurchargeBps`:
+	err = decoder.Decode(&obj.NativeSurchargeBps)
+	if err != nil {
+		return err
+	}
+	// Deserialize `AcceptedSchemaVersions`:
+	err = decoder.Decode(&obj.AcceptedSchemaVersions)
+	if err != nil {
+		return err
+	}
+	// Deserialize `DonRouteCount`:
+	err = decoder.Decode(&obj.DonRouteCount)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type DonNamespace struct {
+	NamespaceId uint64
+	DonConfigs  NamespaceDonConfigs
+}
+
+var DonNamespaceDiscriminator = [8]byte{121, 91, 184, 61, 152, 213, 63, 238}
+
+func (obj DonNamespace) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(DonNamespaceDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `NamespaceId` param:
+	err = encoder.Encode(obj.NamespaceId)
+	if err != nil {
+		return err
+	}
+	// Serialize `DonConfigs` param:
+	err = encoder.Encode(obj.DonConfigs)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *DonNamespace) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(DonNamespaceDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[121 91 184 61 152 213 63 238]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `NamespaceId`:
+	err = decoder.Decode(&obj.NamespaceId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `DonConfigs`:
+	err = decoder.Decode(&obj.DonConfigs)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type DonRoute struct {
+	Key         [32]uint8
+	NamespaceId uint64
+}
+
+var DonRouteDiscriminator = [8]byte{17, 179, 36, 241, 224, 109, 250, 236}
+
+func (obj DonRoute) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(DonRouteDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `Key` param:
+	err = encoder.Encode(obj.Key)
+	if err != nil {
+		return err
+	}
+	// Serialize `NamespaceId` param:
+	err = encoder.Encode(obj.NamespaceId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *DonRoute) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(DonRouteDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[17 179 36 241 224 109 250 236]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `Key`:
+	err = decoder.Decode(&obj.Key)
+	if err != nil {
+		return err
+	}
+	// Deserialize `NamespaceId`:
+	err = decoder.Decode(&obj.NamespaceId)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type FeePreference struct {
+	Requester ag_solanago.PublicKey
+	Asset     uint8
+	Padding   [7]uint8
+}
+
+var FeePreferenceDiscriminator = [8]byte{7, 107, 132, 44, 196, 171, 219, 145}
+
+func (obj FeePreference) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(FeePreferenceDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `Requester` param:
+	err = encoder.Encode(obj.Requester)
+	if err != nil {
+		return err
+	}
+	// Serialize `Asset` param:
+	err = encoder.Encode(obj.Asset)
+	if err != nil {
+		return err
+	}
+	// Serialize `Padding` param:
+	err = encoder.Encode(obj.Padding)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *FeePreference) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(FeePreferenceDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[7 107 132 44 196 171 219 145]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `Requester`:
+	err = decoder.Decode(&obj.Requester)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Asset`:
+	err = decoder.Decode(&obj.Asset)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Padding`:
+	err = decoder.Decode(&obj.Padding)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type ReportBuffer struct {
+	Authority ag_solanago.PublicKey
+	BufferId  uint64
+	Len       uint32
+	Capacity  uint32
+}
+
+var ReportBufferDiscriminator = [8]byte{109, 44, 94, 7, 6, 171, 12, 131}
+
+func (obj ReportBuffer) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(ReportBufferDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `Authority` param:
+	err = encoder.Encode(obj.Authority)
+	if err != nil {
+		return err
+	}
+	// Serialize `BufferId` param:
+	err = encoder.Encode(obj.BufferId)
+	if err != nil {
+		return err
+	}
+	// Serialize `Len` param:
+	err = encoder.Encode(obj.Len)
+	if err != nil {
+		return err
+	}
+	// Serialize `Capacity` param:
+	err = encoder.Encode(obj.Capacity)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *ReportBuffer) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(ReportBufferDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[109 44 94 7 6 171 12 131]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `Authority`:
+	err = decoder.Decode(&obj.Authority)
+	if err != nil {
+		return err
+	}
+	// Deserialize `BufferId`:
+	err = decoder.Decode(&obj.BufferId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Len`:
+	err = decoder.Decode(&obj.Len)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Capacity`:
+	err = decoder.Decode(&obj.Capacity)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type RewardPool struct {
+	ConfigDigest    [32]uint8
+	TotalTokenFees  uint64
+	TotalNativeFees uint64
+	Recipients      RewardRecipients
+}
+
+var RewardPoolDiscriminator = [8]byte{134, 121, 197, 211, 133, 154, 82, 32}
+
+func (obj RewardPool) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(RewardPoolDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `ConfigDigest` param:
+	err = encoder.Encode(obj.ConfigDigest)
+	if err != nil {
+		return err
+	}
+	// Serialize `TotalTokenFees` param:
+	err = encoder.Encode(obj.TotalTokenFees)
+	if err != nil {
+		return err
+	}
+	// Serialize `TotalNativeFees` param:
+	err = encoder.Encode(obj.TotalNativeFees)
+	if err != nil {
+		return err
+	}
+	// Serialize `Recipients` param:
+	err = encoder.Encode(obj.Recipients)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *RewardPool) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(RewardPoolDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[134 121 197 211 133 154 82 32]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `ConfigDigest`:
+	err = decoder.Decode(&obj.ConfigDigest)
+	if err != nil {
+		return err
+	}
+	// Deserialize `TotalTokenFees`:
+	err = decoder.Decode(&obj.TotalTokenFees)
+	if err != nil {
+		return err
+	}
+	// Deserialize `TotalNativeFees`:
+	err = decoder.Decode(&obj.TotalNativeFees)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Recipients`:
+	err = decoder.Decode(&obj.Recipients)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type SubscriberDiscount struct {
+	Subscriber  ag_solanago.PublicKey
+	FeedId      [32]uint8
+	DiscountBps uint16
+	Waived      uint8
+	Padding     [5]uint8
+}
+
+var SubscriberDiscountDiscriminator = [8]byte{202, 50, 31, 139, 149, 175, 71, 19}
+
+func (obj SubscriberDiscount) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(SubscriberDiscountDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `Subscriber` param:
+	err = encoder.Encode(obj.Subscriber)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeedId` param:
+	err = encoder.Encode(obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Serialize `DiscountBps` param:
+	err = encoder.Encode(obj.DiscountBps)
+	if err != nil {
+		return err
+	}
+	// Serialize `Waived` param:
+	err = encoder.Encode(obj.Waived)
+	if err != nil {
+		return err
+	}
+	// Serialize `Padding` param:
+	err = encoder.Encode(obj.Padding)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *SubscriberDiscount) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(SubscriberDiscountDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[202 50 31 139 149 175 71 19]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `Subscriber`:
+	err = decoder.Decode(&obj.Subscriber)
+	if err != nil {
+		return err
+	}
+	// Deserialize `FeedId`:
+	err = decoder.Decode(&obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `DiscountBps`:
+	err = decoder.Decode(&obj.DiscountBps)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Waived`:
+	err = decoder.Decode(&obj.Waived)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Padding`:
+	err = decoder.Decode(&obj.Padding)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type UsageCounter struct {
+	Requester               ag_solanago.PublicKey
+	FeedId                  [32]uint8
+	Verifications           uint64
+	LastPeriodVerifications uint64
+	LastBillingPeriod       uint64
+}
+
+var UsageCounterDiscriminator = [8]byte{180, 18, 3, 177, 248, 50, 69, 67}
+
+func (obj UsageCounter) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(UsageCounterDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `Requester` param:
+	err = encoder.Encode(obj.Requester)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeedId` param:
+	err = encoder.Encode(obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Serialize `Verifications` param:
+	err = encoder.Encode(obj.Verifications)
+	if err != nil {
+		return err
+	}
+	// Serialize `LastPeriodVerifications` param:
+	err = encoder.Encode(obj.LastPeriodVerifications)
+	if err != nil {
+		return err
+	}
+	// Serialize `LastBillingPeriod` param:
+	err = encoder.Encode(obj.LastBillingPeriod)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *UsageCounter) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(UsageCounterDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[180 18 3 177 248 50 69 67]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `Requester`:
+	err = decoder.Decode(&obj.Requester)
+	if err != nil {
+		return err
+	}
+	// Deserialize `FeedId`:
+	err = decoder.Decode(&obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Verifications`:
+	err = decoder.Decode(&obj.Verifications)
+	if err != nil {
+		return err
+	}
+	// Deserialize `LastPeriodVerifications`:
+	err = decoder.Decode(&obj.LastPeriodVerifications)
+	if err != nil {
+		return err
+	}
+	// Deserialize `LastBillingPeriod`:
+	err = decoder.Decode(&obj.LastBillingPeriod)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type UserRateLimit struct {
+	User             ag_solanago.PublicKey
+	WindowStartSlot  uint64
+	Verifications    uint64
+	LimitOverride    RateLimit
+	HasLimitOverride uint8
+	Padding          [7]uint8
+}
+
+var UserRateLimitDiscriminator = [8]byte{156, 245, 156, 195, 8, 43, 139, 184}
+
+func (obj UserRateLimit) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(UserRateLimitDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `User` param:
+	err = encoder.Encode(obj.User)
+	if err != nil {
+		return err
+	}
+	// Serialize `WindowStartSlot` param:
+	err = encoder.Encode(obj.WindowStartSlot)
+	if err != nil {
+		return err
+	}
+	// Serialize `Verifications` param:
+	err = encoder.Encode(obj.Verifications)
+	if err != nil {
+		return err
+	}
+	// Serialize `LimitOverride` param:
+	err = encoder.Encode(obj.LimitOverride)
+	if err != nil {
+		return err
+	}
+	// Serialize `HasLimitOverride` param:
+	err = encoder.Encode(obj.HasLimitOverride)
+	if err != nil {
+		return err
+	}
+	// Serialize `Padding` param:
+	err = encoder.Encode(obj.Padding)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *UserRateLimit) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(UserRateLimitDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[156 245 156 195 8 43 139 184]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `User`:
+	err = decoder.Decode(&obj.User)
+	if err != nil {
+		return err
+	}
+	// Deserialize `WindowStartSlot`:
+	err = decoder.Decode(&obj.WindowStartSlot)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Verifications`:
+	err = decoder.Decode(&obj.Verifications)
+	if err != nil {
+		return err
+	}
+	// Deserialize `LimitOverride`:
+	err = decoder.Decode(&obj.LimitOverride)
+	if err != nil {
+		return err
+	}
+	// Deserialize `HasLimitOverride`:
+	err = decoder.Decode(&obj.HasLimitOverride)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Padding`:
+	err = decoder.Decode(&obj.Padding)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type VerificationReceipt struct {
+	FeedId                [32]uint8
+	ReportHash            [32]uint8
+	DonConfigId           [24]uint8
+	ObservationsTimestamp uint32
+	Padding               [4]uint8
+	Requester             ag_solanago.PublicKey
+	Slot                  uint64
+	Payer                 ag_solanago.PublicKey
+}
+
+var VerificationReceiptDiscriminator = [8]byte{32, 125, 3, 222, 137, 31, 118, 83}
+
+func (obj VerificationReceipt) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Write account discriminator:
+	err = encoder.WriteBytes(VerificationReceiptDiscriminator[:], false)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeedId` param:
+	err = encoder.Encode(obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Serialize `ReportHash` param:
+	err = encoder.Encode(obj.ReportHash)
+	if err != nil {
+		return err
+	}
+	// Serialize `DonConfigId` param:
+	err = encoder.Encode(obj.DonConfigId)
+	if err != nil {
+		return err
+	}
+	// Serialize `ObservationsTimestamp` param:
+	err = encoder.Encode(obj.ObservationsTimestamp)
+	if err != nil {
+		return err
+	}
+	// Serialize `Padding` param:
+	err = encoder.Encode(obj.Padding)
+	if err != nil {
+		return err
+	}
+	// Serialize `Requester` param:
+	err = encoder.Encode(obj.Requester)
+	if err != nil {
+		return err
+	}
+	// Serialize `Slot` param:
+	err = encoder.Encode(obj.Slot)
+	if err != nil {
+		return err
+	}
+	// Serialize `Payer` param:
+	err = encoder.Encode(obj.Payer)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *VerificationReceipt) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Read and check account discriminator:
+	{
+		discriminator, err := decoder.ReadTypeID()
+		if err != nil {
+			return err
+		}
+		if !discriminator.Equal(VerificationReceiptDiscriminator[:]) {
+			return fmt.Errorf(
+				"wrong discriminator: wanted %s, got %s",
+				"[32 125 3 222 137 31 118 83]",
+				fmt.Sprint(discriminator[:]))
+		}
+	}
+	// Deserialize `FeedId`:
+	err = decoder.Decode(&obj.FeedId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ReportHash`:
+	err = decoder.Decode(&obj.ReportHash)
+	if err != nil {
+		return err
+	}
+	// Deserialize `DonConfigId`:
+	err = decoder.Decode(&obj.DonConfigId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ObservationsTimestamp`:
+	err = decoder.Decode(&obj.ObservationsTimestamp)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Padding`:
+	err = decoder.Decode(&obj.Padding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Requester`:
+	err = decoder.Decode(&obj.Requester)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Slot`:
+	err = decoder.Decode(&obj.Slot)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Payer`:
+	err = decoder.Decode(&obj.Payer)
+	if err != nil {
+		return err
+	}
 	return nil
 }
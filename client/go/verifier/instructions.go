@@ -30,25 +30,178 @@ func init() {
 var (
 	Instruction_Verify = ag_binary.TypeID([8]byte{133, 161, 141, 48, 120, 198, 88, 150})
 
+	// Verifies the signed report staged in the report buffer, for reports too large for a single
+	// transaction. The report data is written to the report output buffer when given, and to
+	// return data otherwise.
+	Instruction_VerifyFromBuffer = ag_binary.TypeID([8]byte{95, 53, 132, 130, 149, 135, 167, 71})
+
+	// Creates a buffer of `capacity` bytes to stage a signed report in across transactions
+	Instruction_InitializeReportBuffer = ag_binary.TypeID([8]byte{79, 90, 124, 131, 142, 215, 48, 243})
+
+	// Writes a chunk of the signed report at `offset`. Writing at offset 0 starts a new report.
+	Instruction_WriteReportBuffer = ag_binary.TypeID([8]byte{10, 46, 104, 229, 249, 99, 32, 37})
+
+	// Closes a report buffer, returning its rent to the authority
+	Instruction_CloseReportBuffer = ag_binary.TypeID([8]byte{164, 184, 218, 45, 100, 45, 115, 166})
+
+	// Verifies the report like `verify`, then calls the consumer program with
+	// `consumer_discriminator` immediately followed by the Borsh serialized `DecodedReport`, so
+	// the data of the call holds nothing else the caller chose. The consumer receives the
+	// forwarder PDA as a signer, followed by the remaining accounts with their signer and
+	// writable flags.
+	Instruction_VerifyAndForward = ag_binary.TypeID([8]byte{129, 66, 117, 53, 225, 233, 235, 105})
+
+	// Closes a verification receipt at the request of its requester, returning its rent to the
+	// account that paid it
+	Instruction_CloseReceipt = ag_binary.TypeID([8]byte{126, 254, 244, 203, 124, 164, 134, 89})
+
 	Instruction_SetConfigWithActivationTime = ag_binary.TypeID([8]byte{189, 64, 69, 231, 128, 29, 197, 29})
 
 	Instruction_SetConfig = ag_binary.TypeID([8]byte{108, 158, 154, 175, 212, 98, 52, 66})
 
+	// Like `set_config_with_activation_time`, but fails unless `expected_don_config_id` matches
+	// the id computed from the sorted signers and `f`
+	Instruction_SetConfigWithActivationTimeChecked = ag_binary.TypeID([8]byte{132, 11, 6, 231, 209, 60, 17, 75})
+
+	// Like `set_config`, but fails unless `expected_don_config_id` matches the id computed from
+	// the sorted signers and `f`
+	Instruction_SetConfigChecked = ag_binary.TypeID([8]byte{25, 99, 137, 247, 198, 150, 188, 253})
+
 	Instruction_SetConfigActive = ag_binary.TypeID([8]byte{90, 95, 224, 173, 96, 184, 36, 136})
 
 	Instruction_RemoveLatestConfig = ag_binary.TypeID([8]byte{171, 221, 188, 175, 156, 87, 156, 63})
 
+	// Allows or disallows the active DON to set its successor config via `rotate_config`
+	Instruction_SetConfigRotationEnabled = ag_binary.TypeID([8]byte{237, 91, 133, 169, 220, 193, 204, 186})
+
+	// Sets the report schema versions `verify` accepts, replacing the previous list. The version
+	// is taken from the first two bytes of the feed id. An empty list accepts every version.
+	Instruction_SetAcceptedSchemaVersions = ag_binary.TypeID([8]byte{109, 204, 67, 234, 245, 124, 174, 80})
+
+	// Creates an empty DON namespace, whose config history verifies the reports routed to it
+	Instruction_InitializeDonNamespace = ag_binary.TypeID([8]byte{223, 3, 57, 149, 212, 92, 124, 36})
+
+	// Sets a new DON config in the namespace, validated like `set_config_with_activation_time`
+	Instruction_SetNamespaceConfigWithActivationTime = ag_binary.TypeID([8]byte{72, 26, 57, 59, 173, 204, 240, 167})
+
+	// Like `set_namespace_config_with_activation_time`, but fails unless `expected_don_config_id`
+	// matches the id computed from the sorted signers and `f`
+	Instruction_SetNamespaceConfigWithActivationTimeChecked = ag_binary.TypeID([8]byte{236, 104, 81, 195, 93, 16, 237, 4})
+
+	Instruction_SetNamespaceConfigActive = ag_binary.TypeID([8]byte{18, 222, 49, 23, 164, 237, 201, 230})
+
+	// Routes reports whose config digest or feed id is `key` to the DON namespace. A route of the
+	// config digest takes precedence over one of the feed id.
+	Instruction_InitializeDonRoute = ag_binary.TypeID([8]byte{190, 223, 88, 176, 254, 173, 218, 207})
+
+	// Removes a route, returning its rent to the owner. Its reports are verified against the
+	// verifier account's configs again.
+	Instruction_CloseDonRoute = ag_binary.TypeID([8]byte{58, 79, 203, 93, 106, 79, 88, 75})
+
+	// Enables or disables rate limiting. While enabled every `verify` must pass the user's rate
+	// limit account, which is checked against `max_verifications` per `window_slots` unless the
+	// user has their own limit. A `max_verifications` of 0 means no limit. `window_slots` must be
+	// non-zero when enabling.
+	Instruction_SetDefaultRateLimit = ag_binary.TypeID([8]byte{151, 230, 25, 70, 223, 185, 20, 92})
+
+	// Creates the rate limit account of a user. Anyone may pay for it.
+	Instruction_InitializeRateLimit = ag_binary.TypeID([8]byte{36, 132, 34, 217, 150, 48, 192, 165})
+
+	// Gives a user their own rate limit instead of the default one
+	Instruction_SetUserRateLimit = ag_binary.TypeID([8]byte{201, 92, 104, 196, 34, 154, 104, 252})
+
+	// Puts a user back on the default rate limit
+	Instruction_ClearUserRateLimit = ag_binary.TypeID([8]byte{214, 146, 46, 19, 52, 90, 163, 24})
+
+	// Enables or disables usage metering. While enabled every `verify` must pass the requester's
+	// usage counter for the report's feed.
+	Instruction_SetUsageMeteringEnabled = ag_binary.TypeID([8]byte{154, 148, 65, 57, 94, 71, 49, 64})
+
+	// Creates the usage counter of a requester for a feed. Anyone may pay for it.
+	Instruction_InitializeUsageCounter = ag_binary.TypeID([8]byte{117, 17, 192, 218, 157, 75, 90, 176})
+
+	// Closes `billing_period` for the usage counters passed as remaining accounts, keeping their
+	// count as the last period's usage and resetting them.
+	Instruction_SnapshotUsage = ag_binary.TypeID([8]byte{185, 188, 77, 144, 168, 218, 220, 41})
+
+	// Enables or disables fees in an SPL token and sets the token. While enabled `verify` transfers
+	// the report's `link_fee` in this token from requesters paying in it to the fee vault.
+	// The token cannot be changed once set, as reward pools track unclaimed fees in it.
+	Instruction_SetFeeConfig = ag_binary.TypeID([8]byte{221, 222, 52, 206, 114, 198, 64, 91})
+
+	// Allows or disallows paying fees in native SOL, charged the report's `native_fee` plus
+	// `surcharge_bps` basis points. Funds the native fee vault to be rent exempt if needed.
+	Instruction_SetNativeFeeConfig = ag_binary.TypeID([8]byte{209, 73, 86, 91, 248, 129, 255, 241})
+
+	// Creates the requester's fee preference with their choice of fee asset
+	Instruction_InitializeFeePreference = ag_binary.TypeID([8]byte{38, 112, 44, 73, 200, 194, 206, 211})
+
+	// Changes the asset the requester pays fees in
+	Instruction_SetFeePreference = ag_binary.TypeID([8]byte{91, 247, 5, 15, 207, 2, 145, 142})
+
+	// Creates a subscriber's discount record for a feed, or for all feeds with
+	// `GLOBAL_DISCOUNT_FEED_ID`. Anyone may pay for it, only the owner can grant a discount.
+	Instruction_InitializeSubscriberDiscount = ag_binary.TypeID([8]byte{241, 123, 0, 250, 80, 232, 60, 107})
+
+	// Sets a subscriber's discount on fees in basis points
+	Instruction_SetSubscriberDiscount = ag_binary.TypeID([8]byte{234, 159, 237, 21, 168, 160, 165, 15})
+
+	// Waives all fees of a subscriber, e.g. for internal service accounts. Waived subscribers
+	// need none of the fee accounts in `verify`.
+	Instruction_SetFeeWaiver = ag_binary.TypeID([8]byte{86, 70, 12, 47, 28, 158, 101, 105})
+
+	// Creates the reward pool collecting fees of reports with `config_digest`. Anyone may pay for it.
+	Instruction_InitializeRewardPool = ag_binary.TypeID([8]byte{139, 189, 60, 130, 44, 211, 218, 99})
+
+	// Sets who the pool's fees are paid to. Each recipient is paid for a signer of the DON config
+	// at `don_config_index`, of the DON namespace when given, and their weights must add up to
+	// `TOTAL_REWARD_WEIGHT`. Fees collected before the first recipients are set are shared among
+	// them. As with the EVM RewardManager's `updateRewardRecipients`, the current recipients must
+	// have claimed all their rewards before they are replaced, so `claim_rewards` for both assets
+	// should precede this in the same transaction.
+	Instruction_SetRewardRecipients = ag_binary.TypeID([8]byte{168, 200, 148, 243, 112, 145, 165, 199})
+
+	// Pays the recipients at `recipient_indices` their share of fees in `asset` collected since
+	// their last claim, so pools with many recipients can be claimed over several transactions.
+	// Anyone may submit it.
+	Instruction_ClaimRewards = ag_binary.TypeID([8]byte{4, 144, 132, 71, 116, 23, 151, 80})
+
+	// Sets a new DON config without the owner. The rotation must be signed by more than `f`
+	// signers of the currently active config over `Encoder::compute_config_rotation_hash`, which
+	// includes the verifier account's rotation nonce. Signatures are packed the same way as in a
+	// signed report.
+	Instruction_RotateConfig = ag_binary.TypeID([8]byte{14, 194, 225, 236, 162, 78, 30, 83})
+
+	// Returns the number of DON configs that have been set
+	Instruction_GetConfigCount = ag_binary.TypeID([8]byte{205, 244, 153, 103, 10, 12, 45, 140})
+
+	// Returns the DON config stored at `don_config_index`
+	Instruction_GetConfig = ag_binary.TypeID([8]byte{149, 105, 239, 181, 106, 218, 221, 71})
+
+	// Returns the DON config that `verify` would select for a report with the given timestamp.
+	// The config is returned even if it has been deactivated.
+	Instruction_GetConfigForTimestamp = ag_binary.TypeID([8]byte{153, 92, 27, 230, 237, 196, 215, 30})
+
+	// Returns the fee `verify` would charge the requester for the signed report, or `None` while
+	// fees are disabled or waived for the requester. The report is parsed and its schema version
+	// checked as in `verify` but its signatures are not checked.
+	Instruction_GetFeeQuote = ag_binary.TypeID([8]byte{197, 253, 162, 205, 102, 19, 220, 76})
+
+	// Returns the owner, proposed owner and access controller of the verifier
+	Instruction_GetAccountConfig = ag_binary.TypeID([8]byte{2, 52, 171, 128, 170, 27, 185, 59})
+
 	// Used to Set the access controller
-	// We use an optional access controller.
-	// See https://github.com/coral-xyz/anchor/pull/2101 on how option works in Anchor
+	// Pass the verifier program ID as the access controller to disable it.
 	Instruction_SetAccessController = ag_binary.TypeID([8]byte{86, 87, 56, 58, 148, 233, 95, 125})
 
+	// Disables the access controller, letting any user verify reports
+	Instruction_ClearAccessController = ag_binary.TypeID([8]byte{176, 226, 44, 48, 80, 118, 14, 98})
+
 	// initialize into existence the verifier account. You must realloc after this
 	Instruction_Initialize = ag_binary.TypeID([8]byte{175, 175, 109, 31, 13, 152, 155, 237})
 
 	// Initializes the verifier (admin) account data. Call after initialize + realloc
-	// We use an optional access controller.
-	// See https://github.com/coral-xyz/anchor/pull/2101 on how option works in Anchor
+	// Pass the verifier program ID as the access controller to leave it unset.
 	Instruction_InitializeAccountData = ag_binary.TypeID([8]byte{15, 88, 71, 247, 173, 45, 110, 216})
 
 	Instruction_ReallocAccount = ag_binary.TypeID([8]byte{51, 237, 126, 233, 52, 244, 186, 244})
@@ -63,16 +216,96 @@ func InstructionIDToName(id ag_binary.TypeID) string {
 	switch id {
 	case Instruction_Verify:
 		return "Verify"
+	case Instruction_VerifyFromBuffer:
+		return "VerifyFromBuffer"
+	case Instruction_InitializeReportBuffer:
+		return "InitializeReportBuffer"
+	case Instruction_WriteReportBuffer:
+		return "WriteReportBuffer"
+	case Instruction_CloseReportBuffer:
+		return "CloseReportBuffer"
+	case Instruction_VerifyAndForward:
+		return "VerifyAndForward"
+	case Instruction_CloseReceipt:
+		return "CloseReceipt"
 	case Instruction_SetConfigWithActivationTime:
 		return "SetConfigWithActivationTime"
 	case Instruction_SetConfig:
 		return "SetConfig"
+	case Instruction_SetConfigWithActivationTimeChecked:
+		return "SetConfigWithActivationTimeChecked"
+	case Instruction_SetConfigChecked:
+		return "SetConfigChecked"
 	case Instruction_SetConfigActive:
 		return "SetConfigActive"
 	case Instruction_RemoveLatestConfig:
 		return "RemoveLatestConfig"
+	case Instruction_SetConfigRotationEnabled:
+		return "SetConfigRotationEnabled"
+	case Instruction_SetAcceptedSchemaVersions:
+		return "SetAcceptedSchemaVersions"
+	case Instruction_InitializeDonNamespace:
+		return "InitializeDonNamespace"
+	case Instruction_SetNamespaceConfigWithActivationTime:
+		return "SetNamespaceConfigWithActivationTime"
+	case Instruction_SetNamespaceConfigWithActivationTimeChecked:
+		return "SetNamespaceConfigWithActivationTimeChecked"
+	case Instruction_SetNamespaceConfigActive:
+		return "SetNamespaceConfigActive"
+	case Instruction_InitializeDonRoute:
+		return "InitializeDonRoute"
+	case Instruction_CloseDonRoute:
+		return "CloseDonRoute"
+	case Instruction_SetDefaultRateLimit:
+		return "SetDefaultRateLimit"
+	case Instruction_InitializeRateLimit:
+		return "InitializeRateLimit"
+	case Instruction_SetUserRateLimit:
+		return "SetUserRateLimit"
+	case Instruction_ClearUserRateLimit:
+		return "ClearUserRateLimit"
+	case Instruction_SetUsageMeteringEnabled:
+		return "SetUsageMeteringEnabled"
+	case Instruction_InitializeUsageCounter:
+		return "InitializeUsageCounter"
+	case Instruction_SnapshotUsage:
+		return "SnapshotUsage"
+	case Instruction_SetFeeConfig:
+		return "SetFeeConfig"
+	case Instruction_SetNativeFeeConfig:
+		return "SetNativeFeeConfig"
+	case Instruction_InitializeFeePreference:
+		return "InitializeFeePreference"
+	case Instruction_SetFeePreference:
+		return "SetFeePreference"
+	case Instruction_InitializeSubscriberDiscount:
+		return "InitializeSubscriberDiscount"
+	case Instruction_SetSubscriberDiscount:
+		return "SetSubscriberDiscount"
+	case Instruction_SetFeeWaiver:
+		return "SetFeeWaiver"
+	case Instruction_InitializeRewardPool:
+		return "InitializeRewardPool"
+	case Instruction_SetRewardRecipients:
+		return "SetRewardRecipients"
+	case Instruction_ClaimRewards:
+		return "ClaimRewards"
+	case Instruction_RotateConfig:
+		return "RotateConfig"
+	case Instruction_GetConfigCount:
+		return "GetConfigCount"
+	case Instruction_GetConfig:
+		return "GetConfig"
+	case Instruction_GetConfigForTimestamp:
+		return "GetConfigForTimestamp"
+	case Instruction_GetFeeQuote:
+		return "GetFeeQuote"
+	case Instruction_GetAccountConfig:
+		return "GetAccountConfig"
 	case Instruction_SetAccessController:
 		return "SetAccessController"
+	case Instruction_ClearAccessController:
+		return "ClearAccessController"
 	case Instruction_Initialize:
 		return "Initialize"
 	case Instruction_InitializeAccountData:
@@ -106,21 +339,141 @@ var InstructionImplDef = ag_binary.NewVariantDefinition(
 		{
 			"verify", (*Verify)(nil),
 		},
+		{
+			"verify_from_buffer", (*VerifyFromBuffer)(nil),
+		},
+		{
+			"initialize_report_buffer", (*InitializeReportBuffer)(nil),
+		},
+		{
+			"write_report_buffer", (*WriteReportBuffer)(nil),
+		},
+		{
+			"close_report_buffer", (*CloseReportBuffer)(nil),
+		},
+		{
+			"verify_and_forward", (*VerifyAndForward)(nil),
+		},
+		{
+			"close_receipt", (*CloseReceipt)(nil),
+		},
 		{
 			"set_config_with_activation_time", (*SetConfigWithActivationTime)(nil),
 		},
 		{
 			"set_config", (*SetConfig)(nil),
 		},
+		{
+			"set_config_with_activation_time_checked", (*SetConfigWithActivationTimeChecked)(nil),
+		},
+		{
+			"set_config_checked", (*SetConfigChecked)(nil),
+		},
 		{
 			"set_config_active", (*SetConfigActive)(nil),
 		},
 		{
 			"remove_latest_config", (*RemoveLatestConfig)(nil),
 		},
+		{
+			"set_config_rotation_enabled", (*SetConfigRotationEnabled)(nil),
+		},
+		{
+			"set_accepted_schema_versions", (*SetAcceptedSchemaVersions)(nil),
+		},
+		{
+			"initialize_don_namespace", (*InitializeDonNamespace)(nil),
+		},
+		{
+			"set_namespace_config_with_activation_time", (*SetNamespaceConfigWithActivationTime)(nil),
+		},
+		{
+			"set_namespace_config_with_activation_time_checked", (*SetNamespaceConfigWithActivationTimeChecked)(nil),
+		},
+		{
+			"set_namespace_config_active", (*SetNamespaceConfigActive)(nil),
+		},
+		{
+			"initialize_don_route", (*InitializeDonRoute)(nil),
+		},
+		{
+			"close_don_route", (*CloseDonRoute)(nil),
+		},
+		{
+			"set_default_rate_limit", (*SetDefaultRateLimit)(nil),
+		},
+		{
+			"initialize_rate_limit", (*InitializeRateLimit)(nil),
+		},
+		{
+			"set_user_rate_limit", (*SetUserRateLimit)(nil),
+		},
+		{
+			"clear_user_rate_limit", (*ClearUserRateLimit)(nil),
+		},
+		{
+			"set_usage_metering_enabled", (*SetUsageMeteringEnabled)(nil),
+		},
+		{
+			"initialize_usage_counter", (*InitializeUsageCounter)(nil),
+		},
+		{
+			"snapshot_usage", (*SnapshotUsage)(nil),
+		},
+		{
+			"set_fee_config", (*SetFeeConfig)(nil),
+		},
+		{
+			"set_native_fee_config", (*SetNativeFeeConfig)(nil),
+		},
+		{
+			"initialize_fee_preference", (*InitializeFeePreference)(nil),
+		},
+		{
+			"set_fee_preference", (*SetFeePreference)(nil),
+		},
+		{
+			"initialize_subscriber_discount", (*InitializeSubscriberDiscount)(nil),
+		},
+		{
+			"set_subscriber_discount", (*SetSubscriberDiscount)(nil),
+		},
+		{
+			"set_fee_waiver", (*SetFeeWaiver)(nil),
+		},
+		{
+			"initialize_reward_pool", (*InitializeRewardPool)(nil),
+		},
+		{
+			"set_reward_recipients", (*SetRewardRecipients)(nil),
+		},
+		{
+			"claim_rewards", (*ClaimRewards)(nil),
+		},
+		{
+			"rotate_config", (*RotateConfig)(nil),
+		},
+		{
+			"get_config_count", (*GetConfigCount)(nil),
+		},
+		{
+			"get_config", (*GetConfig)(nil),
+		},
+		{
+			"get_config_for_timestamp", (*GetConfigForTimestamp)(nil),
+		},
+		{
+			"get_fee_quote", (*GetFeeQuote)(nil),
+		},
+		{
+			"get_account_config", (*GetAccountConfig)(nil),
+		},
 		{
 			"set_access_controller", (*SetAccessController)(nil),
 		},
+		{
+			"clear_access_controller", (*ClearAccessController)(nil),
+		},
 		{
 			"initialize", (*Initialize)(nil),
 		},
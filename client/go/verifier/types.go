@@ -226,3 +226,516 @@ func (obj *DonConfigs) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err err
 	}
 	return nil
 }
+
+type SchemaVersions struct {
+	Xs  [15]uint16
+	Len uint16
+}
+
+func (obj SchemaVersions) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Xs` param:
+	err = encoder.Encode(obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Serialize `Len` param:
+	err = encoder.Encode(obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *SchemaVersions) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Xs`:
+	err = decoder.Decode(&obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Len`:
+	err = decoder.Decode(&obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type NamespaceDonConfigs struct {
+	Len     uint16
+	Padding [6]uint8
+	Xs      [8]DonConfig
+}
+
+func (obj NamespaceDonConfigs) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Len` param:
+	err = encoder.Encode(obj.Len)
+	if err != nil {
+		return err
+	}
+	// Serialize `Padding` param:
+	err = encoder.Encode(obj.Padding)
+	if err != nil {
+		return err
+	}
+	// Serialize `Xs` param:
+	err = encoder.Encode(obj.Xs)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *NamespaceDonConfigs) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Len`:
+	err = decoder.Decode(&obj.Len)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Padding`:
+	err = decoder.Decode(&obj.Padding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Xs`:
+	err = decoder.Decode(&obj.Xs)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type FeeConfig struct {
+	TokenMint  ag_solanago.PublicKey
+	TokenVault ag_solanago.PublicKey
+}
+
+func (obj FeeConfig) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `TokenMint` param:
+	err = encoder.Encode(obj.TokenMint)
+	if err != nil {
+		return err
+	}
+	// Serialize `TokenVault` param:
+	err = encoder.Encode(obj.TokenVault)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *FeeConfig) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `TokenMint`:
+	err = decoder.Decode(&obj.TokenMint)
+	if err != nil {
+		return err
+	}
+	// Deserialize `TokenVault`:
+	err = decoder.Decode(&obj.TokenVault)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type FeeAsset ag_binary.BorshEnum
+
+const (
+	FeeAssetToken FeeAsset = iota
+	FeeAssetNative
+)
+
+func (value FeeAsset) String() string {
+	switch value {
+	case FeeAssetToken:
+		return "Token"
+	case FeeAssetNative:
+		return "Native"
+	default:
+		return ""
+	}
+}
+
+type RewardRecipient struct {
+	Payee               ag_solanago.PublicKey
+	Signer              [20]uint8
+	Padding             [4]uint8
+	Weight              uint64
+	TokenFeesClaimedTo  uint64
+	NativeFeesClaimedTo uint64
+}
+
+func (obj RewardRecipient) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Payee` param:
+	err = encoder.Encode(obj.Payee)
+	if err != nil {
+		return err
+	}
+	// Serialize `Signer` param:
+	err = encoder.Encode(obj.Signer)
+	if err != nil {
+		return err
+	}
+	// Serialize `Padding` param:
+	err = encoder.Encode(obj.Padding)
+	if err != nil {
+		return err
+	}
+	// Serialize `Weight` param:
+	err = encoder.Encode(obj.Weight)
+	if err != nil {
+		return err
+	}
+	// Serialize `TokenFeesClaimedTo` param:
+	err = encoder.Encode(obj.TokenFeesClaimedTo)
+	if err != nil {
+		return err
+	}
+	// Serialize `NativeFeesClaimedTo` param:
+	err = encoder.Encode(obj.NativeFeesClaimedTo)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *RewardRecipient) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Payee`:
+	err = decoder.Decode(&obj.Payee)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Signer`:
+	err = decoder.Decode(&obj.Signer)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Padding`:
+	err = decoder.Decode(&obj.Padding)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Weight`:
+	err = decoder.Decode(&obj.Weight)
+	if err != nil {
+		return err
+	}
+	// Deserialize `TokenFeesClaimedTo`:
+	err = decoder.Decode(&obj.TokenFeesClaimedTo)
+	if err != nil {
+		return err
+	}
+	// Deserialize `NativeFeesClaimedTo`:
+	err = decoder.Decode(&obj.NativeFeesClaimedTo)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type RewardRecipients struct {
+	Xs  [31]RewardRecipient
+	Len uint64
+}
+
+func (obj RewardRecipients) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Xs` param:
+	err = encoder.Encode(obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Serialize `Len` param:
+	err = encoder.Encode(obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *RewardRecipients) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Xs`:
+	err = decoder.Decode(&obj.Xs)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Len`:
+	err = decoder.Decode(&obj.Len)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type RateLimit struct {
+	MaxVerifications uint64
+	WindowSlots      uint64
+}
+
+func (obj RateLimit) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `MaxVerifications` param:
+	err = encoder.Encode(obj.MaxVerifications)
+	if err != nil {
+		return err
+	}
+	// Serialize `WindowSlots` param:
+	err = encoder.Encode(obj.WindowSlots)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *RateLimit) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `MaxVerifications`:
+	err = decoder.Decode(&obj.MaxVerifications)
+	if err != nil {
+		return err
+	}
+	// Deserialize `WindowSlots`:
+	err = decoder.Decode(&obj.WindowSlots)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type DonConfigDetails struct {
+	DonConfigIndex uint16
+	DonConfigId    [24]uint8
+	F              uint8
+	IsActive       bool
+	ActivationTime uint32
+	Signers        [][20]uint8
+}
+
+func (obj DonConfigDetails) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `DonConfigIndex` param:
+	err = encoder.Encode(obj.DonConfigIndex)
+	if err != nil {
+		return err
+	}
+	// Serialize `DonConfigId` param:
+	err = encoder.Encode(obj.DonConfigId)
+	if err != nil {
+		return err
+	}
+	// Serialize `F` param:
+	err = encoder.Encode(obj.F)
+	if err != nil {
+		return err
+	}
+	// Serialize `IsActive` param:
+	err = encoder.Encode(obj.IsActive)
+	if err != nil {
+		return err
+	}
+	// Serialize `ActivationTime` param:
+	err = encoder.Encode(obj.ActivationTime)
+	if err != nil {
+		return err
+	}
+	// Serialize `Signers` param:
+	err = encoder.Encode(obj.Signers)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *DonConfigDetails) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `DonConfigIndex`:
+	err = decoder.Decode(&obj.DonConfigIndex)
+	if err != nil {
+		return err
+	}
+	// Deserialize `DonConfigId`:
+	err = decoder.Decode(&obj.DonConfigId)
+	if err != nil {
+		return err
+	}
+	// Deserialize `F`:
+	err = decoder.Decode(&obj.F)
+	if err != nil {
+		return err
+	}
+	// Deserialize `IsActive`:
+	err = decoder.Decode(&obj.IsActive)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ActivationTime`:
+	err = decoder.Decode(&obj.ActivationTime)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Signers`:
+	err = decoder.Decode(&obj.Signers)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type FeeQuote struct {
+	Asset     FeeAsset
+	FeeAsset  ag_solanago.PublicKey
+	BaseFee   uint64
+	Surcharge uint64
+	Discount  uint64
+	Amount    uint64
+}
+
+func (obj FeeQuote) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Asset` param:
+	err = encoder.Encode(obj.Asset)
+	if err != nil {
+		return err
+	}
+	// Serialize `FeeAsset` param:
+	err = encoder.Encode(obj.FeeAsset)
+	if err != nil {
+		return err
+	}
+	// Serialize `BaseFee` param:
+	err = encoder.Encode(obj.BaseFee)
+	if err != nil {
+		return err
+	}
+	// Serialize `Surcharge` param:
+	err = encoder.Encode(obj.Surcharge)
+	if err != nil {
+		return err
+	}
+	// Serialize `Discount` param:
+	err = encoder.Encode(obj.Discount)
+	if err != nil {
+		return err
+	}
+	// Serialize `Amount` param:
+	err = encoder.Encode(obj.Amount)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *FeeQuote) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Asset`:
+	err = decoder.Decode(&obj.Asset)
+	if err != nil {
+		return err
+	}
+	// Deserialize `FeeAsset`:
+	err = decoder.Decode(&obj.FeeAsset)
+	if err != nil {
+		return err
+	}
+	// Deserialize `BaseFee`:
+	err = decoder.Decode(&obj.BaseFee)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Surcharge`:
+	err = decoder.Decode(&obj.Surcharge)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Discount`:
+	err = decoder.Decode(&obj.Discount)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Amount`:
+	err = decoder.Decode(&obj.Amount)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type RewardRecipientConfig struct {
+	Signer [20]uint8
+	Payee  ag_solanago.PublicKey
+	Weight uint64
+}
+
+func (obj RewardRecipientConfig) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Signer` param:
+	err = encoder.Encode(obj.Signer)
+	if err != nil {
+		return err
+	}
+	// Serialize `Payee` param:
+	err = encoder.Encode(obj.Payee)
+	if err != nil {
+		return err
+	}
+	// Serialize `Weight` param:
+	err = encoder.Encode(obj.Weight)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *RewardRecipientConfig) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Signer`:
+	err = decoder.Decode(&obj.Signer)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Payee`:
+	err = decoder.Decode(&obj.Payee)
+	if err != nil {
+		return err
+	}
+	// Deserialize `Weight`:
+	err = decoder.Decode(&obj.Weight)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+type VerifierAccountConfigDetails struct {
+	Owner            ag_solanago.PublicKey
+	ProposedOwner    ag_solanago.PublicKey
+	AccessController ag_solanago.PublicKey
+}
+
+func (obj VerifierAccountConfigDetails) MarshalWithEncoder(encoder *ag_binary.Encoder) (err error) {
+	// Serialize `Owner` param:
+	err = encoder.Encode(obj.Owner)
+	if err != nil {
+		return err
+	}
+	// Serialize `ProposedOwner` param:
+	err = encoder.Encode(obj.ProposedOwner)
+	if err != nil {
+		return err
+	}
+	// Serialize `AccessController` param:
+	err = encoder.Encode(obj.AccessController)
+	if err != nil {
+		return err
+	}
+	return nil
+}
+
+func (obj *VerifierAccountConfigDetails) UnmarshalWithDecoder(decoder *ag_binary.Decoder) (err error) {
+	// Deserialize `Owner`:
+	err = decoder.Decode(&obj.Owner)
+	if err != nil {
+		return err
+	}
+	// Deserialize `ProposedOwner`:
+	err = decoder.Decode(&obj.ProposedOwner)
+	if err != nil {
+		return err
+	}
+	// Deserialize `AccessController`:
+	err = decoder.Decode(&obj.AccessController)
+	if err != nil {
+		return err
+	}
+	return nil
+}
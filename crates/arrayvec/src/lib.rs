@@ -90,6 +90,13 @@ macro_rules! arrayvec {
                 self.xs[offset..offset + len].copy_from_slice(&data);
                 self.len += len as $capacity_ty;
             }
+
+            /// Shortens the array to `len` elements, doing nothing if it is already shorter.
+            pub fn truncate(&mut self, len: usize) {
+                if len < self.len() {
+                    self.len = len as $capacity_ty;
+                }
+            }
             
             /// Removes the last element from the array and returns it.
             /// Returns `None` if the array is empty.
@@ -197,6 +204,20 @@ mod tests {
         assert_eq!(vec.as_slice(), &[1, 2, 3]);
     }
 
+    #[test]
+    fn truncate() {
+        let mut vec = ArrayVec::new();
+        vec.push(1);
+        vec.push(2);
+        vec.push(3);
+
+        vec.truncate(5);
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+        vec.truncate(1);
+        assert_eq!(vec.as_slice(), &[1]);
+    }
+
     #[test]
     #[should_panic]
     fn insert_overflow() {
@@ -0,0 +1,213 @@
+use crate::discriminator;
+use crate::solana::{
+    load_current_index_checked, load_instruction_at_checked, AccountInfo, ProgramError, Pubkey,
+};
+use borsh::BorshDeserialize;
+
+/// A `verify` instruction found earlier in the same transaction
+#[derive(Debug, PartialEq)]
+pub struct PrecedingVerify {
+    /// The index of the `verify` instruction in the transaction
+    pub instruction_index: u16,
+    /// The user that signed the `verify` instruction
+    pub user: Pubkey,
+    /// The signed report, compressed in snappy format as it was passed to `verify`
+    pub signed_report: Vec<u8>,
+}
+
+#[derive(BorshDeserialize)]
+struct VerifyParams {
+    signed_report: Vec<u8>,
+}
+
+/// A helper struct for consumer programs that check a `verify` instruction earlier in the same
+/// transaction instead of calling the verifier program through CPI
+pub struct VerifierIntrospection;
+
+impl VerifierIntrospection {
+    /// Finds the closest `verify` instruction before the current one in the transaction.
+    ///
+    /// A transaction only succeeds if all of its instructions do, so a `verify` instruction found
+    /// this way has verified its report by the time the current instruction completes. Only
+    /// instructions at the top level of the transaction are visible in the instructions sysvar.
+    ///
+    /// # Parameters:
+    ///
+    /// * `instructions_sysvar` - The instructions sysvar account.
+    /// * `program_id` - The public key of the verifier program. Instructions to other programs are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the preceding `verify` instruction, or `None` if there is none. Fails if
+    /// `instructions_sysvar` is not the instructions sysvar or the `verify` instruction is malformed.
+    pub fn find_preceding_verify(
+        instructions_sysvar: &AccountInfo,
+        program_id: &Pubkey,
+    ) -> Result<Option<PrecedingVerify>, ProgramError> {
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+
+        for index in (0..current_index).rev() {
+            let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+            if instruction.program_id != *program_id
+                || !instruction.data.starts_with(&discriminator::VERIFY)
+            {
+                continue;
+            }
+
+            // The user is the third account of `verify`
+            let user = instruction
+                .accounts
+                .get(2)
+                .filter(|account| account.is_signer)
+                .ok_or(ProgramError::InvalidInstructionData)?
+                .pubkey;
+            let params = VerifyParams::try_from_slice(&instruction.data[discriminator::VERIFY.len()..])
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+            return Ok(Some(PrecedingVerify {
+                instruction_index: index,
+                user,
+                signed_report: params.signed_report,
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::{AccountMeta, Instruction};
+    use crate::VerifierInstructions;
+    use solana_sdk::sysvar::instructions::{
+        self, construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction,
+    };
+
+    // Builds instructions sysvar data for a transaction of `instructions` executing `current_index`
+    fn instructions_sysvar_data(instructions: &[Instruction], current_index: u16) -> Vec<u8> {
+        let borrowed_instructions = instructions
+            .iter()
+            .map(|instruction| BorrowedInstruction {
+                program_id: &instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|account| BorrowedAccountMeta {
+                        pubkey: &account.pubkey,
+                        is_signer: account.is_signer,
+                        is_writable: account.is_writable,
+                    })
+                    .collect(),
+                data: &instruction.data,
+            })
+            .collect::<Vec<_>>();
+
+        let mut data = construct_instructions_data(&borrowed_instructions);
+        // The current index is stored in the last two bytes
+        let len = data.len();
+        data[len - 2..].copy_from_slice(&current_index.to_le_bytes());
+        data
+    }
+
+    fn find_preceding_verify(
+        instructions: &[Instruction],
+        current_index: u16,
+        program_id: &Pubkey,
+    ) -> Result<Option<PrecedingVerify>, ProgramError> {
+        let mut data = instructions_sysvar_data(instructions, current_index);
+        let mut lamports = 0;
+        let owner = Pubkey::default();
+        let instructions_sysvar = AccountInfo::new(
+            &instructions::ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        VerifierIntrospection::find_preceding_verify(&instructions_sysvar, program_id)
+    }
+
+    fn verify_instruction(program_id: &Pubkey, user: &Pubkey, signed_report: Vec<u8>) -> Instruction {
+        VerifierInstructions::verify(
+            program_id,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            user,
+            &Pubkey::new_unique(),
+            signed_report,
+        )
+    }
+
+    fn consumer_instruction() -> Instruction {
+        Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![AccountMeta::new_readonly(instructions::ID, false)],
+            data: vec![],
+        }
+    }
+
+    #[test]
+    fn test_find_preceding_verify() {
+        let program_id = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let instructions = vec![
+            verify_instruction(&program_id, &user, vec![1u8; 64]),
+            verify_instruction(&program_id, &user, vec![2u8; 64]),
+            verify_instruction(&other_program_id, &user, vec![3u8; 64]),
+            consumer_instruction(),
+        ];
+
+        // The closest `verify` to the verifier program is found
+        assert_eq!(
+            find_preceding_verify(&instructions, 3, &program_id).unwrap(),
+            Some(PrecedingVerify {
+                instruction_index: 1,
+                user,
+                signed_report: vec![2u8; 64],
+            })
+        );
+        assert_eq!(
+            find_preceding_verify(&instructions, 3, &other_program_id)
+                .unwrap()
+                .map(|verify| verify.instruction_index),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_find_preceding_verify_without_verify() {
+        let program_id = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+
+        // Instructions after the current one are not considered
+        let instructions = vec![
+            consumer_instruction(),
+            verify_instruction(&program_id, &user, vec![1u8; 64]),
+        ];
+        assert_eq!(find_preceding_verify(&instructions, 0, &program_id).unwrap(), None);
+
+        // Only the verifier program's instructions count
+        let instructions = vec![
+            verify_instruction(&Pubkey::new_unique(), &user, vec![1u8; 64]),
+            consumer_instruction(),
+        ];
+        assert_eq!(find_preceding_verify(&instructions, 1, &program_id).unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_preceding_verify_with_malformed_verify() {
+        let program_id = Pubkey::new_unique();
+        let mut verify = verify_instruction(&program_id, &Pubkey::new_unique(), vec![1u8; 64]);
+        verify.data.truncate(discriminator::VERIFY.len() + 2);
+
+        assert_eq!(
+            find_preceding_verify(&[verify, consumer_instruction()], 1, &program_id),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
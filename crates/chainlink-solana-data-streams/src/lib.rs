@@ -1,22 +1,35 @@
 //! Chainlink Data Streams Client for Solana
 
+mod introspection;
+
 mod solana {
     #[cfg(not(target_os = "solana"))]
     pub use solana_sdk::{
+        account_info::AccountInfo,
         instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
         pubkey::Pubkey,
+        sysvar::instructions::{self, load_current_index_checked, load_instruction_at_checked},
     };
 
     #[cfg(target_os = "solana")]
     pub use solana_program::{
+        account_info::AccountInfo,
         instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
         pubkey::Pubkey,
+        sysvar::instructions::{self, load_current_index_checked, load_instruction_at_checked},
     };
 }
 
-use crate::solana::{AccountMeta, Instruction, Pubkey};
+pub use crate::introspection::*;
+
+use crate::solana::{instructions, AccountMeta, Instruction, Pubkey};
 use borsh::{BorshDeserialize, BorshSerialize};
 
+/// The system program, which creates receipts and transfers native fees
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+
 /// Program function name discriminators
 pub mod discriminator {
     pub const VERIFY: [u8; 8] = [133, 161, 141, 48, 120, 198, 88, 150];
@@ -27,6 +40,203 @@ struct VerifyParams {
     signed_report: Vec<u8>,
 }
 
+/// The optional accounts of a `verify` instruction, which the verifier requires depending on its
+/// configuration: access, rate limiting, usage metering, fees, receipts and DON routes. Accounts
+/// are derived from the verifier program ID where possible.
+#[derive(Clone, Debug)]
+pub struct VerifyOptionalAccounts {
+    program_id: Pubkey,
+    feed_access_list: Option<Pubkey>,
+    access_grant: Option<Pubkey>,
+    instructions_sysvar: Option<Pubkey>,
+    rate_limit: Option<Pubkey>,
+    usage_counter: Option<Pubkey>,
+    fee_token_mint: Option<Pubkey>,
+    requester_token_account: Option<Pubkey>,
+    fee_token_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    fee_preference: Option<Pubkey>,
+    native_fee_vault: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    reward_pool: Option<Pubkey>,
+    subscriber_discount: Option<Pubkey>,
+    receipt: Option<Pubkey>,
+    don_namespace: Option<Pubkey>,
+    config_digest_route: Option<Pubkey>,
+    feed_route: Option<Pubkey>,
+    payer: Option<Pubkey>,
+}
+
+impl VerifyOptionalAccounts {
+    /// No optional accounts, for the verifier program `program_id`
+    pub fn new(program_id: &Pubkey) -> Self {
+        Self {
+            program_id: *program_id,
+            feed_access_list: None,
+            access_grant: None,
+            instructions_sysvar: None,
+            rate_limit: None,
+            usage_counter: None,
+            fee_token_mint: None,
+            requester_token_account: None,
+            fee_token_vault: None,
+            token_program: None,
+            fee_preference: None,
+            native_fee_vault: None,
+            system_program: None,
+            reward_pool: None,
+            subscriber_discount: None,
+            receipt: None,
+            don_namespace: None,
+            config_digest_route: None,
+            feed_route: None,
+            payer: None,
+        }
+    }
+
+    /// The access controller's access list of the report's feed
+    pub fn feed_access_list(mut self, feed_access_list: &Pubkey) -> Self {
+        self.feed_access_list = Some(*feed_access_list);
+        self
+    }
+
+    /// The user's access grant in the access controller
+    pub fn access_grant(mut self, access_grant: &Pubkey) -> Self {
+        self.access_grant = Some(*access_grant);
+        self
+    }
+
+    /// The instructions sysvar, for programs calling `verify` through CPI with program access
+    pub fn instructions_sysvar(mut self) -> Self {
+        self.instructions_sysvar = Some(instructions::ID);
+        self
+    }
+
+    /// The user's rate limit account, required while rate limiting is enabled
+    pub fn rate_limit(mut self, user: &Pubkey) -> Self {
+        self.rate_limit = Some(self.pda(&[b"rate_limit", user.as_ref()]));
+        self
+    }
+
+    /// The user's usage counter of the report's feed, required while usage metering is enabled
+    pub fn usage_counter(mut self, user: &Pubkey, feed_id: &[u8; 32]) -> Self {
+        self.usage_counter = Some(self.pda(&[b"usage", user.as_ref(), feed_id]));
+        self
+    }
+
+    /// The accounts paying fees in the fee token, required while token fees are enabled. The user
+    /// is the authority of `requester_token_account`.
+    pub fn token_fee(
+        mut self,
+        fee_token_mint: &Pubkey,
+        requester_token_account: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Self {
+        self.fee_token_mint = Some(*fee_token_mint);
+        self.requester_token_account = Some(*requester_token_account);
+        self.fee_token_vault = Some(self.pda(&[b"fee_vault", fee_token_mint.as_ref()]));
+        self.token_program = Some(*token_program);
+        self
+    }
+
+    /// The user's choice of fee asset
+    pub fn fee_preference(mut self, user: &Pubkey) -> Self {
+        self.fee_preference = Some(self.pda(&[b"fee_preference", user.as_ref()]));
+        self
+    }
+
+    /// The native fee vault and the system program, required for fees paid in native SOL
+    pub fn native_fee(mut self) -> Self {
+        self.native_fee_vault = Some(self.pda(&[b"native_fee_vault"]));
+        self.system_program = Some(SYSTEM_PROGRAM_ID);
+        self
+    }
+
+    /// The reward pool of the report's config digest, required when a fee is charged
+    pub fn reward_pool(mut self, config_digest: &[u8; 32]) -> Self {
+        self.reward_pool = Some(self.pda(&[b"reward_pool", config_digest]));
+        self
+    }
+
+    /// The user's discount for the report's feed
+    pub fn subscriber_discount(mut self, user: &Pubkey, feed_id: &[u8; 32]) -> Self {
+        self.subscriber_discount =
+            Some(self.pda(&[b"subscriber_discount", user.as_ref(), feed_id]));
+        self
+    }
+
+    /// The receipt of the report's feed and observations timestamp, written by `verify`, and the
+    /// system program to create it
+    pub fn receipt(mut self, feed_id: &[u8; 32], observations_timestamp: u32) -> Self {
+        self.receipt =
+            Some(self.pda(&[b"receipt", feed_id, &observations_timestamp.to_le_bytes()]));
+        self.system_program = Some(SYSTEM_PROGRAM_ID);
+        self
+    }
+
+    /// The DON namespace the report is routed to
+    pub fn don_namespace(mut self, namespace_id: u64) -> Self {
+        self.don_namespace = Some(self.pda(&[b"don_namespace", &namespace_id.to_le_bytes()]));
+        self
+    }
+
+    /// The routes of the report's config digest and feed, required while any DON route exists
+    pub fn don_routes(mut self, config_digest: &[u8; 32], feed_id: &[u8; 32]) -> Self {
+        self.config_digest_route = Some(self.pda(&[b"don_route", config_digest]));
+        self.feed_route = Some(self.pda(&[b"don_route", feed_id]));
+        self
+    }
+
+    /// A signer paying native fees and receipt rent instead of the user
+    pub fn payer(mut self, payer: &Pubkey) -> Self {
+        self.payer = Some(*payer);
+        self
+    }
+
+    fn pda(&self, seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &self.program_id).0
+    }
+
+    /// Whether the user is debited for native fees or receipt rent
+    fn debits_user(&self) -> bool {
+        self.payer.is_none() && self.system_program.is_some()
+    }
+
+    /// The account metas in the order of `verify`, passing the program ID for missing accounts
+    fn to_account_metas(&self) -> Vec<AccountMeta> {
+        let optional = |account: Option<Pubkey>, is_writable: bool| match account {
+            Some(account) if is_writable => AccountMeta::new(account, false),
+            Some(account) => AccountMeta::new_readonly(account, false),
+            None => AccountMeta::new_readonly(self.program_id, false),
+        };
+        let mut accounts = vec![
+            optional(self.feed_access_list, false),
+            optional(self.access_grant, false),
+            optional(self.instructions_sysvar, false),
+            optional(self.rate_limit, true),
+            optional(self.usage_counter, true),
+            optional(self.fee_token_mint, false),
+            optional(self.requester_token_account, true),
+            optional(self.fee_token_vault, true),
+            optional(self.token_program, false),
+            optional(self.fee_preference, false),
+            optional(self.native_fee_vault, true),
+            optional(self.system_program, false),
+            optional(self.reward_pool, true),
+            optional(self.subscriber_discount, false),
+            optional(self.receipt, true),
+            optional(self.don_namespace, false),
+            optional(self.config_digest_route, false),
+            optional(self.feed_route, false),
+        ];
+        accounts.push(match self.payer {
+            Some(payer) => AccountMeta::new(payer, true),
+            None => AccountMeta::new_readonly(self.program_id, false),
+        });
+        accounts
+    }
+}
+
 /// A helper struct for creating Verifier program instructions
 pub struct VerifierInstructions;
 
@@ -60,6 +270,45 @@ impl VerifierInstructions {
             AccountMeta::new_readonly(*report_config_account, false),
         ];
 
+        Self::verify_instruction(program_id, accounts, signed_report)
+    }
+
+    /// Creates a verify instruction with the optional accounts the verifier's configuration
+    /// requires. Takes the same parameters as [`Self::verify`], and `optional_accounts` built with
+    /// [`VerifyOptionalAccounts::new`].
+    ///
+    /// The user is writable when it pays native fees or receipt rent, which is when the native fee
+    /// or receipt accounts are given without a payer.
+    pub fn verify_with_optional_accounts(
+        program_id: &Pubkey,
+        verifier_account: &Pubkey,
+        access_controller_account: &Pubkey,
+        user: &Pubkey,
+        report_config_account: &Pubkey,
+        signed_report: Vec<u8>,
+        optional_accounts: &VerifyOptionalAccounts,
+    ) -> Instruction {
+        let user = if optional_accounts.debits_user() {
+            AccountMeta::new(*user, true)
+        } else {
+            AccountMeta::new_readonly(*user, true)
+        };
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*verifier_account, false),
+            AccountMeta::new_readonly(*access_controller_account, false),
+            user,
+            AccountMeta::new_readonly(*report_config_account, false),
+        ];
+        accounts.extend(optional_accounts.to_account_metas());
+
+        Self::verify_instruction(program_id, accounts, signed_report)
+    }
+
+    fn verify_instruction(
+        program_id: &Pubkey,
+        accounts: Vec<AccountMeta>,
+        signed_report: Vec<u8>,
+    ) -> Instruction {
         // 8 bytes for discriminator
         // 4 bytes size of the length prefix for the signed_report vector
         let mut instruction_data = Vec::with_capacity(8 + 4 + signed_report.len());
@@ -118,4 +367,43 @@ mod tests {
         assert!(ix.accounts[2].is_signer);
         assert_eq!(ix.accounts[3].pubkey, expected_config);
     }
+
+    #[test]
+    fn test_create_verify_instruction_with_optional_accounts() {
+        let program_id = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let feed_id = [2u8; 32];
+        let report = vec![1u8; 64];
+
+        let optional_accounts = VerifyOptionalAccounts::new(&program_id)
+            .rate_limit(&user)
+            .receipt(&feed_id, 1_700_000_000);
+        let ix = VerifierInstructions::verify_with_optional_accounts(
+            &program_id,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &user,
+            &VerifierInstructions::get_config_pda(&report, &program_id),
+            report,
+            &optional_accounts,
+        );
+
+        assert!(ix.data.starts_with(&discriminator::VERIFY));
+        assert_eq!(ix.accounts.len(), 23);
+        // The user pays the receipt's rent
+        assert!(ix.accounts[2].is_signer && ix.accounts[2].is_writable);
+
+        let rate_limit =
+            Pubkey::find_program_address(&[b"rate_limit", user.as_ref()], &program_id).0;
+        assert_eq!(ix.accounts[7].pubkey, rate_limit);
+        assert!(ix.accounts[7].is_writable);
+        assert_eq!(ix.accounts[15].pubkey, SYSTEM_PROGRAM_ID);
+        assert!(ix.accounts[18].is_writable);
+
+        // Missing accounts are passed as the program
+        assert_eq!(ix.accounts[4].pubkey, program_id);
+        assert!(!ix.accounts[4].is_writable);
+        assert_eq!(ix.accounts[22].pubkey, program_id);
+        assert!(!ix.accounts[22].is_signer);
+    }
 }
@@ -30,11 +30,211 @@ pub struct AccessList {
 arrayvec!(AccessList, Pubkey, u64);
 const_assert!(size_of::<AccessList>() == size_of::<u64>() + size_of::<Pubkey>() * MAX_ALLOW_LIST);
 
+pub const MAX_SUBSCRIPTIONS: usize = 64;
+
+/// Access granted to `address` until `valid_until` (inclusive, unix timestamp)
+#[zero_copy]
+#[derive(Default)]
+pub struct Subscription {
+    pub address: Pubkey,
+    pub valid_until: i64,
+}
+
+#[zero_copy]
+pub struct Subscriptions {
+    xs: [Subscription; MAX_SUBSCRIPTIONS],
+    len: u64,
+}
+arrayvec!(Subscriptions, Subscription, u64);
+const_assert!(
+    size_of::<Subscriptions>() == size_of::<u64>() + size_of::<Subscription>() * MAX_SUBSCRIPTIONS
+);
+
+impl Subscriptions {
+    /// Subscriptions are kept sorted by address
+    pub fn find(&self, address: &Pubkey) -> std::result::Result<usize, usize> {
+        self.binary_search_by_key(address, |subscription| subscription.address)
+    }
+
+    pub fn is_valid(&self, address: &Pubkey, now: i64) -> bool {
+        self.find(address)
+            .is_ok_and(|index| self[index].valid_until >= now)
+    }
+}
+
+/// How the access list is interpreted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessListMode {
+    /// Only addresses on the list have access
+    Allow,
+    /// Everyone except addresses on the list has access
+    Deny,
+}
+
+pub const MAX_ADMINS: usize = 8;
+
+/// Delegated admins, allowed to add and remove access on behalf of the owner
+#[zero_copy]
+pub struct Admins {
+    xs: [Pubkey; MAX_ADMINS],
+    len: u64,
+}
+arrayvec!(Admins, Pubkey, u64);
+const_assert!(size_of::<Admins>() == size_of::<u64>() + size_of::<Pubkey>() * MAX_ADMINS);
+
+/// Outcome of `batch_add_access`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchAddAccessResult {
+    pub added: u32,
+    /// Already on the access list, or repeated in the batch
+    pub skipped: u32,
+    /// Not added because the access list is full
+    pub rejected: u32,
+}
+
+/// Outcome of `batch_remove_access`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchRemoveAccessResult {
+    pub removed: u32,
+    /// Not on the access list, or repeated in the batch
+    pub skipped: u32,
+}
+
 #[account(zero_copy)]
 pub struct AccessController {
     pub owner: Pubkey,
     pub proposed_owner: Pubkey,
     pub access_list: AccessList,
+    // Expiring access grants, kept alongside the access list
+    pub subscriptions: Subscriptions,
+    // Programs whose CPI calls are allowed regardless of the signing PDA
+    pub program_access_list: AccessList,
+    pub admins: Admins,
+    // `AccessListMode`, allow list by default
+    pub access_list_mode: u8,
+    pub access_list_mode_padding: [u8; 7],
+}
+
+impl AccessController {
+    pub fn access_list_mode(&self) -> AccessListMode {
+        match self.access_list_mode {
+            0 => AccessListMode::Allow,
+            _ => AccessListMode::Deny,
+        }
+    }
+
+    /// Whether `authority` may add and remove access: the owner or a delegated admin
+    pub fn is_access_admin(&self, authority: &Pubkey) -> bool {
+        self.owner == *authority || self.admins.binary_search(authority).is_ok()
+    }
+}
+
+pub const FEED_ACCESS_LIST_SEED: &[u8] = b"feed_access";
+
+/// Per-feed access list, derived from `[FEED_ACCESS_LIST_SEED, access_controller, feed_id]`
+#[account(zero_copy)]
+pub struct FeedAccessList {
+    pub access_controller: Pubkey,
+    pub feed_id: [u8; 32],
+    pub access_list: AccessList,
+}
+const_assert!(size_of::<FeedAccessList>() == size_of::<Pubkey>() + 32 + size_of::<AccessList>());
+
+pub const ACCESS_GRANT_SEED: &[u8] = b"access_grant";
+
+/// Access for a single address, derived from `[ACCESS_GRANT_SEED, access_controller, address]`.
+/// Each grant is its own account, so the number of allowed addresses is not bounded by
+/// `MAX_ALLOW_LIST`.
+#[account(zero_copy)]
+pub struct AccessGrant {
+    pub access_controller: Pubkey,
+    pub address: Pubkey,
+}
+const_assert!(size_of::<AccessGrant>() == size_of::<Pubkey>() * 2);
+
+/// `address` was added to the access list, or granted access. In deny mode the list denies access.
+#[event]
+pub struct AccessAdded {
+    pub access_controller: Pubkey,
+    pub address: Pubkey,
+}
+
+/// `address` was removed from the access list, or its access grant revoked
+#[event]
+pub struct AccessRemoved {
+    pub access_controller: Pubkey,
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct AccessListModeSet {
+    pub access_controller: Pubkey,
+    pub mode: AccessListMode,
+}
+
+#[event]
+pub struct OwnershipTransferRequested {
+    pub access_controller: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub access_controller: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+}
+
+#[event]
+pub struct SubscriptionExtended {
+    pub access_controller: Pubkey,
+    pub address: Pubkey,
+    pub valid_until: i64,
+}
+
+#[event]
+pub struct SubscriptionRemoved {
+    pub access_controller: Pubkey,
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct FeedAccessAdded {
+    pub access_controller: Pubkey,
+    pub feed_id: [u8; 32],
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct FeedAccessRemoved {
+    pub access_controller: Pubkey,
+    pub feed_id: [u8; 32],
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct AdminAdded {
+    pub access_controller: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct AdminRemoved {
+    pub access_controller: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ProgramAccessAdded {
+    pub access_controller: Pubkey,
+    pub program: Pubkey,
+}
+
+#[event]
+pub struct ProgramAccessRemoved {
+    pub access_controller: Pubkey,
+    pub program: Pubkey,
 }
 
 #[program]
@@ -52,12 +252,23 @@ pub mod access_controller {
     ) -> Result<()> {
         let state = &mut *ctx.accounts.state.load_mut()?;
         state.proposed_owner = proposed_owner;
+        emit!(OwnershipTransferRequested {
+            access_controller: ctx.accounts.state.key(),
+            from: state.owner,
+            to: proposed_owner,
+        });
         Ok(())
     }
 
     pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
         let state = &mut *ctx.accounts.state.load_mut()?;
+        let from = state.owner;
         state.owner = std::mem::take(&mut state.proposed_owner);
+        emit!(OwnershipTransferred {
+            access_controller: ctx.accounts.state.key(),
+            from,
+            to: state.owner,
+        });
         Ok(())
     }
 
@@ -72,7 +283,13 @@ pub mod access_controller {
             // already present
             Ok(_i) => (),
             // not found, insert
-            Err(i) => state.access_list.insert(i, address),
+            Err(i) => {
+                state.access_list.insert(i, address);
+                emit!(AccessAdded {
+                    access_controller: ctx.accounts.state.key(),
+                    address,
+                });
+            }
         }
         Ok(())
     }
@@ -85,15 +302,520 @@ pub mod access_controller {
         if let Ok(index) = index {
             state.access_list.remove(index);
             // we don't need to sort again since the list is still sorted
+            emit!(AccessRemoved {
+                access_controller: ctx.accounts.state.key(),
+                address,
+            });
+        }
+
+        // removing access also ends any subscription
+        if let Ok(index) = state.subscriptions.find(&address) {
+            state.subscriptions.remove(index);
+            emit!(SubscriptionRemoved {
+                access_controller: ctx.accounts.state.key(),
+                address,
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds `addresses` to the access list in a single merge pass. Once the list is full the
+    /// remaining new addresses are rejected instead of failing the whole batch.
+    pub fn batch_add_access(
+        ctx: Context<BatchUpdateAccess>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<BatchAddAccessResult> {
+        let mut addresses = addresses;
+        let mut state = ctx.accounts.state.load_mut()?;
+        let mut result = BatchAddAccessResult::default();
+
+        let batch_len = addresses.len();
+        addresses.sort_unstable();
+        addresses.dedup();
+        result.skipped = (batch_len - addresses.len()) as u32;
+
+        let existing = state.access_list.to_vec();
+        let capacity = state.access_list.capacity();
+        let mut merged = Vec::with_capacity(capacity);
+        let mut i = 0;
+        for address in addresses {
+            while i < existing.len() && existing[i] < address {
+                merged.push(existing[i]);
+                i += 1;
+            }
+            if i < existing.len() && existing[i] == address {
+                result.skipped += 1;
+            } else if existing.len() + (result.added as usize) < capacity {
+                merged.push(address);
+                result.added += 1;
+                emit!(AccessAdded {
+                    access_controller: ctx.accounts.state.key(),
+                    address,
+                });
+            } else {
+                result.rejected += 1;
+            }
+        }
+        merged.extend_from_slice(&existing[i..]);
+
+        state.access_list.clear();
+        state.access_list.extend(&merged);
+        Ok(result)
+    }
+
+    /// Removes `addresses` from the access list in a single pass, ending their subscriptions
+    /// like `remove_access` does.
+    pub fn batch_remove_access(
+        ctx: Context<BatchUpdateAccess>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<BatchRemoveAccessResult> {
+        let mut addresses = addresses;
+        let mut state = ctx.accounts.state.load_mut()?;
+        let mut result = BatchRemoveAccessResult::default();
+
+        let batch_len = addresses.len();
+        addresses.sort_unstable();
+        addresses.dedup();
+
+        // compact in place, preserving the sort order
+        let mut len = 0;
+        let mut j = 0;
+        for i in 0..state.access_list.len() {
+            let address = state.access_list[i];
+            while j < addresses.len() && addresses[j] < address {
+                j += 1;
+            }
+            if j < addresses.len() && addresses[j] == address {
+                result.removed += 1;
+                j += 1;
+                emit!(AccessRemoved {
+                    access_controller: ctx.accounts.state.key(),
+                    address,
+                });
+            } else {
+                state.access_list[len] = address;
+                len += 1;
+            }
+        }
+        state.access_list.truncate(len);
+        result.skipped = batch_len as u32 - result.removed;
+
+        for address in &addresses {
+            if let Ok(index) = state.subscriptions.find(address) {
+                state.subscriptions.remove(index);
+                emit!(SubscriptionRemoved {
+                    access_controller: ctx.accounts.state.key(),
+                    address: *address,
+                });
+            }
+        }
+        Ok(result)
+    }
+
+    /// Switches how the access list is interpreted. The list must be empty to change modes, as
+    /// its entries would otherwise gain the opposite meaning.
+    pub fn set_access_list_mode(ctx: Context<SetAccessListMode>, mode: AccessListMode) -> Result<()> {
+        let mut state = ctx.accounts.state.load_mut()?;
+        require!(
+            state.access_list_mode() == mode || state.access_list.is_empty(),
+            ErrorCode::InvalidInput
+        );
+        state.access_list_mode = mode as u8;
+        emit!(AccessListModeSet {
+            access_controller: ctx.accounts.state.key(),
+            mode,
+        });
+        Ok(())
+    }
+
+    pub fn add_admin(ctx: Context<UpdateAdmins>) -> Result<()> {
+        let mut state = ctx.accounts.state.load_mut()?;
+        // if the len reaches array len, we're at capacity
+        require!(state.admins.remaining_capacity() > 0, ErrorCode::Full);
+
+        let admin = ctx.accounts.admin.key();
+
+        match state.admins.binary_search(&admin) {
+            // already present
+            Ok(_i) => (),
+            // not found, insert
+            Err(i) => {
+                state.admins.insert(i, admin);
+                emit!(AdminAdded {
+                    access_controller: ctx.accounts.state.key(),
+                    admin,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove_admin(ctx: Context<UpdateAdmins>) -> Result<()> {
+        let mut state = ctx.accounts.state.load_mut()?;
+        let admin = ctx.accounts.admin.key();
+
+        let index = state.admins.binary_search(&admin);
+        if let Ok(index) = index {
+            state.admins.remove(index);
+            // we don't need to sort again since the list is still sorted
+            emit!(AdminRemoved {
+                access_controller: ctx.accounts.state.key(),
+                admin,
+            });
+        }
+        Ok(())
+    }
+
+    /// Allows every CPI call made by `program`, whichever PDA signs it
+    pub fn add_program_access(ctx: Context<UpdateProgramAccess>) -> Result<()> {
+        let mut state = ctx.accounts.state.load_mut()?;
+        // if the len reaches array len, we're at capacity
+        require!(
+            state.program_access_list.remaining_capacity() > 0,
+            ErrorCode::Full
+        );
+
+        let program = ctx.accounts.program.key();
+
+        match state.program_access_list.binary_search(&program) {
+            // already present
+            Ok(_i) => (),
+            // not found, insert
+            Err(i) => {
+                state.program_access_list.insert(i, program);
+                emit!(ProgramAccessAdded {
+                    access_controller: ctx.accounts.state.key(),
+                    program,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove_program_access(ctx: Context<UpdateProgramAccess>) -> Result<()> {
+        let mut state = ctx.accounts.state.load_mut()?;
+        let program = ctx.accounts.program.key();
+
+        let index = state.program_access_list.binary_search(&program);
+        if let Ok(index) = index {
+            state.program_access_list.remove(index);
+            // we don't need to sort again since the list is still sorted
+            emit!(ProgramAccessRemoved {
+                access_controller: ctx.accounts.state.key(),
+                program,
+            });
+        }
+        Ok(())
+    }
+
+    /// Grants `address` access until `valid_until`. An existing subscription is only ever extended.
+    pub fn extend_subscription(ctx: Context<ExtendSubscription>, valid_until: i64) -> Result<()> {
+        require!(
+            valid_until >= Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidInput
+        );
+
+        let mut state = ctx.accounts.state.load_mut()?;
+        let address = ctx.accounts.address.key();
+
+        let valid_until = match state.subscriptions.find(&address) {
+            Ok(i) => {
+                let subscription = &mut state.subscriptions[i];
+                subscription.valid_until = subscription.valid_until.max(valid_until);
+                subscription.valid_until
+            }
+            Err(i) => {
+                // if the len reaches array len, we're at capacity
+                require!(state.subscriptions.remaining_capacity() > 0, ErrorCode::Full);
+                state.subscriptions.insert(i, Subscription { address, valid_until });
+                valid_until
+            }
+        };
+        emit!(SubscriptionExtended {
+            access_controller: ctx.accounts.state.key(),
+            address,
+            valid_until,
+        });
+        Ok(())
+    }
+
+    /// Permissionless crank removing subscriptions that have expired
+    pub fn purge_expired_subscriptions(ctx: Context<PurgeExpiredSubscriptions>) -> Result<()> {
+        let mut state = ctx.accounts.state.load_mut()?;
+        let now = Clock::get()?.unix_timestamp;
+
+        // compact in place, preserving the sort order
+        let mut len = 0;
+        for i in 0..state.subscriptions.len() {
+            if state.subscriptions[i].valid_until >= now {
+                state.subscriptions[len] = state.subscriptions[i];
+                len += 1;
+            } else {
+                emit!(SubscriptionRemoved {
+                    access_controller: ctx.accounts.state.key(),
+                    address: state.subscriptions[i].address,
+                });
+            }
+        }
+        state.subscriptions.truncate(len);
+        Ok(())
+    }
+
+    /// Grows an access controller created before the account layout was extended
+    pub fn realloc_account(_ctx: Context<ReallocAccount>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn initialize_feed_access_list(
+        ctx: Context<InitializeFeedAccessList>,
+        feed_id: [u8; 32],
+    ) -> Result<()> {
+        let mut feed_access_list = ctx.accounts.feed_access_list.load_init()?;
+        feed_access_list.access_controller = ctx.accounts.state.key();
+        feed_access_list.feed_id = feed_id;
+        Ok(())
+    }
+
+    pub fn add_feed_access(ctx: Context<UpdateFeedAccess>) -> Result<()> {
+        let mut feed_access_list = ctx.accounts.feed_access_list.load_mut()?;
+        // if the len reaches array len, we're at capacity
+        require!(
+            feed_access_list.access_list.remaining_capacity() > 0,
+            ErrorCode::Full
+        );
+
+        let address = ctx.accounts.address.key();
+
+        match feed_access_list.access_list.binary_search(&address) {
+            // already present
+            Ok(_i) => (),
+            // not found, insert
+            Err(i) => {
+                feed_access_list.access_list.insert(i, address);
+                emit!(FeedAccessAdded {
+                    access_controller: ctx.accounts.state.key(),
+                    feed_id: feed_access_list.feed_id,
+                    address,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove_feed_access(ctx: Context<UpdateFeedAccess>) -> Result<()> {
+        let mut feed_access_list = ctx.accounts.feed_access_list.load_mut()?;
+        let address = ctx.accounts.address.key();
+
+        let index = feed_access_list.access_list.binary_search(&address);
+        if let Ok(index) = index {
+            feed_access_list.access_list.remove(index);
+            // we don't need to sort again since the list is still sorted
+            emit!(FeedAccessRemoved {
+                access_controller: ctx.accounts.state.key(),
+                feed_id: feed_access_list.feed_id,
+                address,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn grant_access(ctx: Context<GrantAccess>) -> Result<()> {
+        let mut access_grant = ctx.accounts.access_grant.load_init()?;
+        access_grant.access_controller = ctx.accounts.state.key();
+        access_grant.address = ctx.accounts.address.key();
+        emit!(AccessAdded {
+            access_controller: access_grant.access_controller,
+            address: access_grant.address,
+        });
+        Ok(())
+    }
+
+    /// Closes the access grant, returning its rent to the access controller owner
+    pub fn revoke_access(ctx: Context<RevokeAccess>) -> Result<()> {
+        emit!(AccessRemoved {
+            access_controller: ctx.accounts.state.key(),
+            address: ctx.accounts.address.key(),
+        });
+        Ok(())
+    }
+
+    /// Moves the first `remaining_accounts.len()` entries of the access list into access grants.
+    /// Each remaining account must be the (writable) access grant address of the matching entry.
+    /// Grants that already exist are kept as is. Only allow lists can be migrated, as the entries
+    /// of a deny list block access rather than grant it.
+    pub fn migrate_access_list<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateAccessList<'info>>,
+    ) -> Result<()> {
+        let state_key = ctx.accounts.state.key();
+        let mut state = ctx.accounts.state.load_mut()?;
+        require!(
+            state.access_list_mode() == AccessListMode::Allow,
+            ErrorCode::InvalidInput
+        );
+        require!(
+            ctx.remaining_accounts.len() <= state.access_list.len(),
+            ErrorCode::InvalidInput
+        );
+
+        let space = 8 + size_of::<AccessGrant>();
+
+        for (address, access_grant) in state.access_list.iter().zip(ctx.remaining_accounts) {
+            let (expected, bump) = Pubkey::find_program_address(
+                &[ACCESS_GRANT_SEED, state_key.as_ref(), address.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(access_grant.key(), expected, ErrorCode::InvalidInput);
+
+            // already granted
+            if access_grant.owner == ctx.program_id {
+                continue;
+            }
+
+            create_pda_account(
+                &ctx.accounts.owner.to_account_info(),
+                access_grant,
+                &ctx.accounts.system_program.to_account_info(),
+                space,
+                ctx.program_id,
+                &[ACCESS_GRANT_SEED, state_key.as_ref(), address.as_ref(), &[bump]],
+            )?;
+
+            let loader = AccountLoader::<AccessGrant>::try_from_unchecked(ctx.program_id, access_grant)?;
+            {
+                let mut grant = loader.load_init()?;
+                grant.access_controller = state_key;
+                grant.address = *address;
+            }
+            loader.exit(ctx.program_id)?;
         }
+
+        // access is unchanged, so no events are emitted
+        // the migrated entries are a prefix of the sorted list, so the rest stays sorted
+        let migrated = ctx.remaining_accounts.len();
+        let len = state.access_list.len();
+        state.access_list.copy_within(migrated..len, 0);
+        state.access_list.truncate(len - migrated);
         Ok(())
     }
 }
 
-/// Check if `address` is on the access control list.
+/// Creates the PDA `account` owned by `owner`, as Anchor's `init` does. Anyone can transfer
+/// lamports to a PDA before it is created, so only the rent shortfall is paid for an account that
+/// already holds lamports, and it is allocated and assigned instead of created.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    use anchor_lang::system_program::{allocate, assign, create_account, transfer};
+    use anchor_lang::system_program::{Allocate, Assign, CreateAccount, Transfer};
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer_seeds = &[seeds];
+
+    if account.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            shortfall,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
+
+/// Check if `address` is on the access control list or holds a subscription that has not expired.
+/// In deny mode, check that `address` is not on the access control list instead.
 pub fn has_access(loader: &AccountLoader<AccessController>, address: &Pubkey) -> Result<bool> {
     let state = loader.load()?;
-    Ok(state.access_list.binary_search(address).is_ok())
+    let listed = state.access_list.binary_search(address).is_ok();
+    match state.access_list_mode() {
+        AccessListMode::Allow => {
+            Ok(listed || state.subscriptions.is_valid(address, Clock::get()?.unix_timestamp))
+        }
+        AccessListMode::Deny => Ok(!listed),
+    }
+}
+
+/// Check if `address` is on the access control list in deny mode. A denied address has no
+/// access through any other means.
+pub fn is_denied(loader: &AccountLoader<AccessController>, address: &Pubkey) -> Result<bool> {
+    let state = loader.load()?;
+    Ok(state.access_list_mode() == AccessListMode::Deny
+        && state.access_list.binary_search(address).is_ok())
+}
+
+/// Check if `address` may access `feed_id`, either through the feed's access list or, as a
+/// fallback, the access control list.
+pub fn has_feed_access(
+    loader: &AccountLoader<AccessController>,
+    feed_access_list: &AccountLoader<FeedAccessList>,
+    feed_id: &[u8; 32],
+    address: &Pubkey,
+) -> Result<bool> {
+    let feed_access_list = feed_access_list.load()?;
+    require!(
+        feed_access_list.access_controller == loader.key() && feed_access_list.feed_id == *feed_id,
+        ErrorCode::InvalidInput
+    );
+    Ok(feed_access_list.access_list.binary_search(address).is_ok()
+        || has_access(loader, address)?)
+}
+
+/// Check if CPI calls made by `program_id` are allowed.
+pub fn has_program_access(loader: &AccountLoader<AccessController>, program_id: &Pubkey) -> Result<bool> {
+    let state = loader.load()?;
+    Ok(state.program_access_list.binary_search(program_id).is_ok())
+}
+
+/// Check if `address` has access through `access_grant`, when one is provided.
+pub fn has_access_grant(
+    loader: &AccountLoader<AccessController>,
+    access_grant: Option<&AccountLoader<AccessGrant>>,
+    address: &Pubkey,
+) -> Result<bool> {
+    let Some(access_grant) = access_grant else {
+        return Ok(false);
+    };
+    let access_grant = access_grant.load()?;
+    require!(
+        access_grant.access_controller == loader.key() && access_grant.address == *address,
+        ErrorCode::InvalidInput
+    );
+    Ok(true)
 }
 
 #[derive(Accounts)]
@@ -121,9 +843,10 @@ pub struct AcceptOwnership<'info> {
 
 #[derive(Accounts)]
 pub struct AddAccess<'info> {
-    #[account(mut, has_one = owner)]
+    #[account(mut)]
     pub state: AccountLoader<'info, AccessController>,
-    #[account(address = state.load()?.owner @ ErrorCode::Unauthorized)]
+    /// The owner or a delegated admin
+    #[account(constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
     pub owner: Signer<'info>,
     /// CHECK: We don't impose any limits since this could be any signer.
     pub address: UncheckedAccount<'info>,
@@ -131,10 +854,156 @@ pub struct AddAccess<'info> {
 
 #[derive(Accounts)]
 pub struct RemoveAccess<'info> {
-    #[account(mut, has_one = owner)]
+    #[account(mut)]
+    pub state: AccountLoader<'info, AccessController>,
+    /// The owner or a delegated admin
+    #[account(constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    /// CHECK: We don't impose any limits since this could be any signer.
+    pub address: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BatchUpdateAccess<'info> {
+    #[account(mut)]
+    pub state: AccountLoader<'info, AccessController>,
+    /// The owner or a delegated admin
+    #[account(constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAccessListMode<'info> {
+    #[account(mut)]
+    pub state: AccountLoader<'info, AccessController>,
+    #[account(address = state.load()?.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAdmins<'info> {
+    #[account(mut)]
     pub state: AccountLoader<'info, AccessController>,
     #[account(address = state.load()?.owner @ ErrorCode::Unauthorized)]
     pub owner: Signer<'info>,
     /// CHECK: We don't impose any limits since this could be any signer.
+    pub admin: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProgramAccess<'info> {
+    #[account(mut)]
+    pub state: AccountLoader<'info, AccessController>,
+    #[account(address = state.load()?.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    /// CHECK: Any executable account, its key is the program ID.
+    #[account(constraint = program.executable @ ErrorCode::InvalidInput)]
+    pub program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendSubscription<'info> {
+    #[account(mut)]
+    pub state: AccountLoader<'info, AccessController>,
+    /// The owner or a delegated admin
+    #[account(constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    /// CHECK: We don't impose any limits since this could be any signer.
+    pub address: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PurgeExpiredSubscriptions<'info> {
+    #[account(mut)]
+    pub state: AccountLoader<'info, AccessController>,
+}
+
+#[derive(Accounts)]
+pub struct ReallocAccount<'info> {
+    #[account(
+        mut,
+        realloc = 8 + size_of::<AccessController>(),
+        realloc::payer = owner,
+        realloc::zero = true)]
+    pub state: AccountLoader<'info, AccessController>,
+    #[account(mut, address = state.load()?.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(feed_id: [u8; 32])]
+pub struct InitializeFeedAccessList<'info> {
+    pub state: AccountLoader<'info, AccessController>,
+    /// The owner or a delegated admin
+    #[account(mut, constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    #[account(
+        init,
+        seeds = [FEED_ACCESS_LIST_SEED, state.key().as_ref(), feed_id.as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + size_of::<FeedAccessList>())]
+    pub feed_access_list: AccountLoader<'info, FeedAccessList>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeedAccess<'info> {
+    pub state: AccountLoader<'info, AccessController>,
+    /// The owner or a delegated admin
+    #[account(constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        constraint = feed_access_list.load()?.access_controller == state.key() @ ErrorCode::InvalidInput)]
+    pub feed_access_list: AccountLoader<'info, FeedAccessList>,
+    /// CHECK: We don't impose any limits since this could be any signer.
     pub address: UncheckedAccount<'info>,
 }
+
+#[derive(Accounts)]
+pub struct GrantAccess<'info> {
+    pub state: AccountLoader<'info, AccessController>,
+    /// The owner or a delegated admin
+    #[account(mut, constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    /// CHECK: We don't impose any limits since this could be any signer.
+    pub address: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [ACCESS_GRANT_SEED, state.key().as_ref(), address.key().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + size_of::<AccessGrant>())]
+    pub access_grant: AccountLoader<'info, AccessGrant>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeAccess<'info> {
+    pub state: AccountLoader<'info, AccessController>,
+    /// The owner or a delegated admin
+    #[account(constraint = state.load()?.is_access_admin(&owner.key()) @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    /// CHECK: We don't impose any limits since this could be any signer.
+    pub address: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [ACCESS_GRANT_SEED, state.key().as_ref(), address.key().as_ref()],
+        bump,
+        close = state_owner)]
+    pub access_grant: AccountLoader<'info, AccessGrant>,
+    /// CHECK: The access controller owner, who receives the grant's rent whoever revokes it.
+    #[account(mut, address = state.load()?.owner @ ErrorCode::InvalidInput)]
+    pub state_owner: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccessList<'info> {
+    #[account(mut)]
+    pub state: AccountLoader<'info, AccessController>,
+    #[account(mut, address = state.load()?.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
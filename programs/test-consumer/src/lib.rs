@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::InstructionData;
+use verifier::common::FORWARDER_SEED;
+use verifier::domain::DecodedReport;
+
+declare_id!("3sMTMxR4wivAysNkm25ywULcZgNndZiMYqP6Tvmy5CWL");
+
+/// A consumer of the verifier, used by its integration tests
+#[program]
+pub mod test_consumer {
+    use super::*;
+
+    /// Receives a report from `verify_and_forward`, which must be its only argument since the
+    /// verifier forwards the report right after the discriminator
+    pub fn consume(_ctx: Context<ConsumeContext>, report: DecodedReport) -> Result<()> {
+        emit!(ReportConsumed {
+            feed_id: report.feed_id,
+            observations_timestamp: report.observations_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Calls the verifier's `verify` with the remaining accounts, as a program relying on its
+    /// program access would. Takes the same data as `verify`.
+    pub fn verify<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RelayVerifyContext<'info>>,
+        signed_report: Vec<u8>,
+    ) -> Result<()> {
+        let verifier_program = &ctx.accounts.verifier_program;
+        let instruction = Instruction {
+            program_id: verifier_program.key(),
+            accounts: ctx
+                .remaining_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: verifier::instruction::Verify { signed_report }.data(),
+        };
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(verifier_program.to_account_info());
+        invoke(&instruction, &account_infos)?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ConsumeContext<'info> {
+    /// Only the verifier can sign for its forwarder, so the report was verified by it
+    #[account(seeds = [FORWARDER_SEED], bump, seeds::program = verifier::ID)]
+    pub forwarder: Signer<'info>,
+}
+
+/// The accounts of the verifier's `verify` follow as remaining accounts
+#[derive(Accounts)]
+pub struct RelayVerifyContext<'info> {
+    pub verifier_program: Program<'info, verifier::program::Verifier>,
+}
+
+#[event]
+pub struct ReportConsumed {
+    pub feed_id: [u8; 32],
+    pub observations_timestamp: u32,
+}
@@ -1 +1,3 @@
-pub const MAX_NUMBER_OF_ORACLES: u8 = 31;
\ No newline at end of file
+pub const MAX_NUMBER_OF_ORACLES: u8 = 31;
+// Seed of the PDA signing the calls `verify_and_forward` makes to consumer programs
+pub const FORWARDER_SEED: &[u8] = b"forwarder";
@@ -0,0 +1,17 @@
+use crate::errors::ErrorCode;
+use crate::state::VerificationReceipt;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseReceiptContext<'info> {
+    #[account(
+        mut,
+        close = payer,
+        constraint = receipt.load()?.requester == requester.key() @ ErrorCode::Unauthorized,
+        constraint = receipt.load()?.payer == payer.key() @ ErrorCode::InvalidInputs)]
+    pub receipt: AccountLoader<'info, VerificationReceipt>,
+    pub requester: Signer<'info>,
+    /// CHECK: Checked against the receipt, receives its rent.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,59 @@
+use crate::errors::ErrorCode;
+use crate::state::{DonNamespace, DonRoute, VerifierAccount, DON_NAMESPACE_SEED, DON_ROUTE_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(namespace_id: u64)]
+pub struct InitializeDonNamespaceContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(
+        init,
+        seeds = [DON_NAMESPACE_SEED, namespace_id.to_le_bytes().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + std::mem::size_of::<DonNamespace>())]
+    pub don_namespace: AccountLoader<'info, DonNamespace>,
+    #[account(mut, address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDonNamespaceContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut)]
+    pub don_namespace: AccountLoader<'info, DonNamespace>,
+    #[account(address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(key: [u8; 32])]
+pub struct InitializeDonRouteContext<'info> {
+    #[account(mut, seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(
+        init,
+        seeds = [DON_ROUTE_SEED, key.as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + std::mem::size_of::<DonRoute>())]
+    pub don_route: AccountLoader<'info, DonRoute>,
+    /// The namespace the reports are routed to
+    pub don_namespace: AccountLoader<'info, DonNamespace>,
+    #[account(mut, address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDonRouteContext<'info> {
+    #[account(mut, seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut, close = owner)]
+    pub don_route: AccountLoader<'info, DonRoute>,
+    #[account(mut, address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+}
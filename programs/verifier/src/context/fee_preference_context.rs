@@ -0,0 +1,23 @@
+use crate::state::{FeePreference, FEE_PREFERENCE_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeFeePreferenceContext<'info> {
+    #[account(
+        init,
+        seeds = [FEE_PREFERENCE_SEED, requester.key().as_ref()],
+        bump,
+        payer = requester,
+        space = 8 + std::mem::size_of::<FeePreference>())]
+    pub fee_preference: AccountLoader<'info, FeePreference>,
+    #[account(mut)]
+    pub requester: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeePreferenceContext<'info> {
+    #[account(mut, seeds = [FEE_PREFERENCE_SEED, requester.key().as_ref()], bump)]
+    pub fee_preference: AccountLoader<'info, FeePreference>,
+    pub requester: Signer<'info>,
+}
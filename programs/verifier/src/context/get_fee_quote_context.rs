@@ -0,0 +1,17 @@
+use crate::state::{FeePreference, SubscriberDiscount, VerifierAccount, FEE_PREFERENCE_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct GetFeeQuoteContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    /// CHECK: Only used to derive the requester's accounts.
+    pub requester: UncheckedAccount<'info>,
+    #[account(seeds = [FEE_PREFERENCE_SEED, requester.key().as_ref()], bump)]
+    pub fee_preference: Option<AccountLoader<'info, FeePreference>>,
+    /// Required to quote fees in the token
+    pub fee_token_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// The requester's discount for the report's feed or for all feeds
+    pub subscriber_discount: Option<AccountLoader<'info, SubscriberDiscount>>,
+}
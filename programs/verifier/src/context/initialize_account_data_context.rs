@@ -8,7 +8,8 @@ pub struct InitializeAccountDataContext<'info> {
     #[account(mut, seeds = [b"verifier"], bump)]
     pub verifier_account: AccountLoader<'info, VerifierAccount>,
     pub owner: Signer<'info>,
-    pub access_controller: Option<AccountLoader<'info, access_controller::AccessController>>,
+    /// CHECK: Checked by `access_controller_key`, the verifier program ID leaves it unset.
+    pub access_controller: UncheckedAccount<'info>,
     #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
     pub program: Program<'info, Verifier>,
     #[account(constraint = program_data.upgrade_authority_address == Some(owner.key()) @ ErrorCode::Unauthorized)]
@@ -0,0 +1,18 @@
+use crate::state::{UserRateLimit, RATE_LIMIT_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeRateLimitContext<'info> {
+    #[account(
+        init,
+        seeds = [RATE_LIMIT_SEED, user.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<UserRateLimit>())]
+    pub rate_limit: AccountLoader<'info, UserRateLimit>,
+    /// CHECK: Any address may be rate limited.
+    pub user: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
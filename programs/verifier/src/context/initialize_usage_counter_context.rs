@@ -0,0 +1,19 @@
+use crate::state::{UsageCounter, USAGE_COUNTER_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(feed_id: [u8; 32])]
+pub struct InitializeUsageCounterContext<'info> {
+    #[account(
+        init,
+        seeds = [USAGE_COUNTER_SEED, requester.key().as_ref(), feed_id.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<UsageCounter>())]
+    pub usage_counter: AccountLoader<'info, UsageCounter>,
+    /// CHECK: Any address may verify reports.
+    pub requester: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -6,6 +6,20 @@ mod accept_ownership_context;
 mod update_config_context;
 mod realloc_account_context;
 mod initialize_account_data_context;
+mod read_verifier_account_context;
+mod rotate_config_context;
+mod initialize_rate_limit_context;
+mod set_user_rate_limit_context;
+mod initialize_usage_counter_context;
+mod set_fee_config_context;
+mod set_native_fee_config_context;
+mod fee_preference_context;
+mod reward_pool_context;
+mod get_fee_quote_context;
+mod subscriber_discount_context;
+mod close_receipt_context;
+mod report_buffer_context;
+mod don_namespace_context;
 
 pub use initialize_account_data_context::*;
 pub use initialize_account_context::*;
@@ -14,4 +28,18 @@ pub use verify_context::*;
 pub use transfer_ownership_context::*;
 pub use accept_ownership_context::*;
 pub use update_config_context::*;
-pub use realloc_account_context::*;
\ No newline at end of file
+pub use realloc_account_context::*;
+pub use read_verifier_account_context::*;
+pub use rotate_config_context::*;
+pub use initialize_rate_limit_context::*;
+pub use set_user_rate_limit_context::*;
+pub use initialize_usage_counter_context::*;
+pub use set_fee_config_context::*;
+pub use set_native_fee_config_context::*;
+pub use fee_preference_context::*;
+pub use reward_pool_context::*;
+pub use get_fee_quote_context::*;
+pub use subscriber_discount_context::*;
+pub use close_receipt_context::*;
+pub use report_buffer_context::*;
+pub use don_namespace_context::*;
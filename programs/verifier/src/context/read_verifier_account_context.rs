@@ -0,0 +1,8 @@
+use crate::state::VerifierAccount;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReadVerifierAccountContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+}
@@ -0,0 +1,38 @@
+use crate::errors::ErrorCode;
+use crate::state::{ReportBuffer, REPORT_BUFFER_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(buffer_id: u64, capacity: u32)]
+pub struct InitializeReportBufferContext<'info> {
+    #[account(
+        init,
+        seeds = [REPORT_BUFFER_SEED, authority.key().as_ref(), &buffer_id.to_le_bytes()],
+        bump,
+        payer = authority,
+        space = ReportBuffer::DATA_OFFSET + capacity as usize)]
+    pub report_buffer: AccountLoader<'info, ReportBuffer>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WriteReportBufferContext<'info> {
+    #[account(
+        mut,
+        constraint = report_buffer.load()?.authority == authority.key() @ ErrorCode::Unauthorized)]
+    pub report_buffer: AccountLoader<'info, ReportBuffer>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseReportBufferContext<'info> {
+    #[account(
+        mut,
+        close = authority,
+        constraint = report_buffer.load()?.authority == authority.key() @ ErrorCode::Unauthorized)]
+    pub report_buffer: AccountLoader<'info, ReportBuffer>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
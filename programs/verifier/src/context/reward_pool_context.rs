@@ -0,0 +1,50 @@
+use crate::errors::ErrorCode;
+use crate::state::{DonNamespace, RewardPool, VerifierAccount, NATIVE_FEE_VAULT_SEED, REWARD_POOL_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+#[instruction(config_digest: [u8; 32])]
+pub struct InitializeRewardPoolContext<'info> {
+    #[account(
+        init,
+        seeds = [REWARD_POOL_SEED, config_digest.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<RewardPool>())]
+    pub reward_pool: AccountLoader<'info, RewardPool>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRewardRecipientsContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut)]
+    pub reward_pool: AccountLoader<'info, RewardPool>,
+    #[account(address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    /// The DON namespace holding the DON config, for pools of a namespace's DON
+    pub don_namespace: Option<AccountLoader<'info, DonNamespace>>,
+}
+
+/// The claimed recipients' token accounts or addresses, for token or native rewards respectively,
+/// are passed as remaining accounts in the order of their indices.
+#[derive(Accounts)]
+pub struct ClaimRewardsContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut)]
+    pub reward_pool: AccountLoader<'info, RewardPool>,
+    /// Required for token rewards, checked against the fee config
+    pub fee_token_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub fee_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// Required for native rewards
+    #[account(mut, seeds = [NATIVE_FEE_VAULT_SEED], bump)]
+    pub native_fee_vault: Option<SystemAccount<'info>>,
+    pub system_program: Option<Program<'info, System>>,
+}
@@ -2,6 +2,7 @@ use crate::state::VerifierAccount;
 use crate::errors::ErrorCode;
 use access_controller::AccessController;
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 #[derive(Accounts)]
 pub struct SetAccessControllerContext<'info> {
@@ -9,6 +10,25 @@ pub struct SetAccessControllerContext<'info> {
     pub verifier_account: AccountLoader<'info, VerifierAccount>,
     #[account(address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
     pub owner: Signer<'info>,
-    pub access_controller: Option<AccountLoader<'info, AccessController>>,
+    /// CHECK: Checked by `access_controller_key`, the verifier program ID disables the access controller.
+    pub access_controller: UncheckedAccount<'info>,
 }
 
+/// Returns the access controller to store for `access_controller`, which must be an access
+/// controller account or the verifier program ID to disable the access controller. The account is
+/// required so that omitting it cannot silently disable the access controller.
+pub fn access_controller_key(access_controller: &AccountInfo) -> Result<Pubkey> {
+    if access_controller.key() == crate::ID {
+        return Ok(Pubkey::default());
+    }
+    require_keys_eq!(
+        *access_controller.owner,
+        access_controller::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+    require!(
+        access_controller.try_borrow_data()?.starts_with(AccessController::DISCRIMINATOR),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    Ok(access_controller.key())
+}
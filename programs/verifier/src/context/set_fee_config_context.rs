@@ -0,0 +1,26 @@
+use crate::errors::ErrorCode;
+use crate::state::{VerifierAccount, FEE_VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct SetFeeConfigContext<'info> {
+    #[account(mut, seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut, address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub fee_token_mint: InterfaceAccount<'info, Mint>,
+    /// Created on first use, owned by the verifier account
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [FEE_VAULT_SEED, fee_token_mint.key().as_ref()],
+        bump,
+        token::mint = fee_token_mint,
+        token::authority = verifier_account,
+        token::token_program = token_program)]
+    pub fee_token_vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,15 @@
+use crate::errors::ErrorCode;
+use crate::state::{VerifierAccount, NATIVE_FEE_VAULT_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetNativeFeeConfigContext<'info> {
+    #[account(mut, seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut, address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+    /// Funded to be rent exempt on first use
+    #[account(mut, seeds = [NATIVE_FEE_VAULT_SEED], bump)]
+    pub native_fee_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
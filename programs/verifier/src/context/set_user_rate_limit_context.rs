@@ -0,0 +1,15 @@
+use crate::errors::ErrorCode;
+use crate::state::{UserRateLimit, VerifierAccount, RATE_LIMIT_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetUserRateLimitContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut, seeds = [RATE_LIMIT_SEED, user.key().as_ref()], bump)]
+    pub rate_limit: AccountLoader<'info, UserRateLimit>,
+    /// CHECK: Only used to derive the rate limit account.
+    pub user: UncheckedAccount<'info>,
+    #[account(address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+}
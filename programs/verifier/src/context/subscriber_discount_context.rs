@@ -0,0 +1,30 @@
+use crate::errors::ErrorCode;
+use crate::state::{SubscriberDiscount, VerifierAccount, SUBSCRIBER_DISCOUNT_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(feed_id: [u8; 32])]
+pub struct InitializeSubscriberDiscountContext<'info> {
+    #[account(
+        init,
+        seeds = [SUBSCRIBER_DISCOUNT_SEED, subscriber.key().as_ref(), feed_id.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<SubscriberDiscount>())]
+    pub subscriber_discount: AccountLoader<'info, SubscriberDiscount>,
+    /// CHECK: Any address may verify reports.
+    pub subscriber: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSubscriberDiscountContext<'info> {
+    #[account(seeds = [b"verifier"], bump)]
+    pub verifier_account: AccountLoader<'info, VerifierAccount>,
+    #[account(mut)]
+    pub subscriber_discount: AccountLoader<'info, SubscriberDiscount>,
+    #[account(address = verifier_account.load()?.verifier_account_config.owner @ ErrorCode::Unauthorized)]
+    pub owner: Signer<'info>,
+}
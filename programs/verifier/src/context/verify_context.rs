@@ -1,6 +1,13 @@
+use crate::common::FORWARDER_SEED;
 use crate::errors::ErrorCode;
-use crate::state::VerifierAccount;
+use crate::state::{
+    DonNamespace, FeePreference, ReportBuffer, RewardPool, SubscriberDiscount, UsageCounter, UserRateLimit, VerifierAccount, FEE_PREFERENCE_SEED,
+    NATIVE_FEE_VAULT_SEED, RATE_LIMIT_SEED,
+};
+use crate::util::Introspection;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct VerifyContext<'info> {
@@ -12,13 +19,127 @@ pub struct VerifyContext<'info> {
             verifier_account.load()?.verifier_account_config.access_controller == access_controller.key() 
             @ ErrorCode::InvalidAccessController)]
     pub access_controller: AccountLoader<'info, access_controller::AccessController>,
+    /// When a feed access list is provided, access is checked against the report's feed in `verify`.
+    /// Addresses denied by the access controller are rejected regardless.
     #[account(
         constraint =
             verifier_account.load()?.verifier_account_config.access_controller == Pubkey::default() ||
-            access_controller::has_access(&access_controller, &user.key())? 
+            (!access_controller::is_denied(&access_controller, &user.key())? && (
+                feed_access_list.is_some() ||
+                access_controller::has_access_grant(&access_controller, access_grant.as_ref(), &user.key())? ||
+                caller_has_program_access(&access_controller, instructions_sysvar.as_ref())? ||
+                access_controller::has_access(&access_controller, &user.key())?
+            )) 
             @ ErrorCode::Unauthorized
     )]
     pub user: Signer<'info>,
     /// CHECK: Program will validate this based on report input.
-    pub config_account: UncheckedAccount<'info>
+    pub config_account: UncheckedAccount<'info>,
+    pub feed_access_list: Option<AccountLoader<'info, access_controller::FeedAccessList>>,
+    /// The user's access grant, for access controllers with more users than the access list holds
+    pub access_grant: Option<AccountLoader<'info, access_controller::AccessGrant>>,
+    /// Required for CPI callers relying on program access, to identify the calling program
+    /// CHECK: Address is checked against the instructions sysvar.
+    #[account(address = sysvar::instructions::ID @ ErrorCode::InvalidInputs)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    /// The user's rate limit account, required while rate limiting is enabled
+    #[account(mut, seeds = [RATE_LIMIT_SEED, user.key().as_ref()], bump)]
+    pub rate_limit: Option<AccountLoader<'info, UserRateLimit>>,
+    /// The user's usage counter for the report's feed, required while usage metering is enabled.
+    /// Checked against the report in `verify`.
+    #[account(mut)]
+    pub usage_counter: Option<AccountLoader<'info, UsageCounter>>,
+    /// The fee token accounts, required while fees are enabled. The requester's token account
+    /// pays the report's fee into the verifier's fee vault, with the user as its authority.
+    /// Checked against the fee config in `verify`.
+    pub fee_token_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub requester_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub fee_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// The user's choice of fee asset. Without it fees are paid in the token while token fees are
+    /// enabled, and in native SOL otherwise.
+    #[account(seeds = [FEE_PREFERENCE_SEED, user.key().as_ref()], bump)]
+    pub fee_preference: Option<AccountLoader<'info, FeePreference>>,
+    /// Required for fees paid in native SOL
+    #[account(mut, seeds = [NATIVE_FEE_VAULT_SEED], bump)]
+    pub native_fee_vault: Option<SystemAccount<'info>>,
+    pub system_program: Option<Program<'info, System>>,
+    /// The reward pool of the report's config digest, required when a fee is charged.
+    /// Checked against the report in `verify`.
+    #[account(mut)]
+    pub reward_pool: Option<AccountLoader<'info, RewardPool>>,
+    /// The user's discount for the report's feed or for all feeds. Checked against the report in
+    /// `verify`.
+    pub subscriber_discount: Option<AccountLoader<'info, SubscriberDiscount>>,
+    /// The receipt of the report's feed and observations timestamp, written by `verify` when given.
+    /// Requires the system program to create it, paid for by the payer.
+    /// CHECK: Address is checked against the report in `verify`.
+    #[account(mut)]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    /// The DON namespace the report is routed to, verified against instead of the verifier
+    /// account's configs. Checked against the routes in `verify`.
+    pub don_namespace: Option<AccountLoader<'info, DonNamespace>>,
+    /// The routes of the report's config digest and feed id, required while any route exists. A
+    /// route that was never initialized shows the report is not routed by that key.
+    /// CHECK: Addresses are checked against the report in `verify`.
+    pub config_digest_route: Option<UncheckedAccount<'info>>,
+    /// CHECK: Address is checked against the report in `verify`.
+    pub feed_route: Option<UncheckedAccount<'info>>,
+    /// Pays native fees and receipt rent instead of the user, who is otherwise debited and must be
+    /// writable
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+}
+
+impl<'info> VerifyContext<'info> {
+    /// The account debited for native fees and receipt rent
+    pub(crate) fn sol_payer(&self) -> Result<AccountInfo<'info>> {
+        match &self.payer {
+            Some(payer) => Ok(payer.to_account_info()),
+            None => {
+                require!(self.user.is_writable, ErrorCode::PayerRequired);
+                Ok(self.user.to_account_info())
+            }
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct VerifyFromBufferContext<'info> {
+    pub verify: VerifyContext<'info>,
+    /// The buffer holding the signed report
+    pub report_buffer: AccountLoader<'info, ReportBuffer>,
+    /// The user's buffer the report data is written to instead of return data
+    #[account(mut, constraint = report_output.load()?.authority == verify.user.key() @ ErrorCode::Unauthorized)]
+    pub report_output: Option<AccountLoader<'info, ReportBuffer>>,
+}
+
+/// The accounts passed through to the consumer follow as remaining accounts
+#[derive(Accounts)]
+pub struct VerifyAndForwardContext<'info> {
+    pub verify: VerifyContext<'info>,
+    /// The program called with the decoded report
+    /// CHECK: Any executable program may consume reports.
+    #[account(executable)]
+    pub consumer_program: UncheckedAccount<'info>,
+    /// Signs the call to the consumer, which should require it to trust the report
+    /// CHECK: Holds no data, only used as a signer.
+    #[account(seeds = [FORWARDER_SEED], bump)]
+    pub forwarder: UncheckedAccount<'info>,
+}
+
+/// Check if the program calling `verify` through CPI is allowed by the access controller
+pub(crate) fn caller_has_program_access(
+    access_controller: &AccountLoader<access_controller::AccessController>,
+    instructions_sysvar: Option<&UncheckedAccount>,
+) -> Result<bool> {
+    let Some(instructions_sysvar) = instructions_sysvar else {
+        return Ok(false);
+    };
+    match Introspection::calling_program(instructions_sysvar)? {
+        Some(program_id) => access_controller::has_program_access(access_controller, &program_id),
+        None => Ok(false),
+    }
 }
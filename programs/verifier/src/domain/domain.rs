@@ -1,3 +1,6 @@
+use crate::state::FeeAsset;
+use anchor_lang::prelude::*;
+
 #[derive(Debug, PartialEq)]
 pub struct SignedReport<'a> {
     pub report_context:  &'a [[u8; 32]; 3],
@@ -10,5 +13,75 @@ pub struct SignedReport<'a> {
 #[derive(Debug, PartialEq)]
 pub struct Report<'a> {
     pub feed_id: &'a [u8; 32],
+    // Taken from the first two bytes of the feed id
+    pub schema_version: u16,
     pub report_timestamp: u32,
 }
+
+/// Fees carried by reports of schema v2 and later, with 18 decimals
+#[derive(Debug, Default, PartialEq)]
+pub struct ReportFees {
+    pub native_fee: u128,
+    pub link_fee: u128,
+}
+
+/// A verified report as forwarded to consumer programs by `verify_and_forward`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct DecodedReport {
+    pub feed_id: [u8; 32],
+    // The first two bytes of the feed id
+    pub schema_version: u16,
+    pub valid_from_timestamp: u32,
+    pub observations_timestamp: u32,
+    // Fees with 18 decimals, zero for schemas without fees
+    pub native_fee: u128,
+    pub link_fee: u128,
+    // Zero for schemas without fees
+    pub expires_at: u32,
+    // The schema's remaining fields, e.g. the benchmark price, bid and ask of v3 reports
+    pub values: Vec<i128>,
+}
+
+/// A DON config as returned by the read-only config queries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct DonConfigDetails {
+    pub don_config_index: u16,
+    pub don_config_id: [u8; 24],
+    pub f: u8,
+    pub is_active: bool,
+    pub activation_time: u32,
+    pub signers: Vec<[u8; 20]>,
+}
+
+/// The fee `verify` charges for a report, as returned by `get_fee_quote`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FeeQuote {
+    pub asset: FeeAsset,
+    /// The token mint, or the system program for native SOL
+    pub fee_asset: Pubkey,
+    /// The report's fee in base units of the asset
+    pub base_fee: u64,
+    pub surcharge: u64,
+    /// The subscriber's discount on the base fee plus surcharge
+    pub discount: u64,
+    /// The amount charged
+    pub amount: u64,
+}
+
+/// A reward recipient as set by `set_reward_recipients`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct RewardRecipientConfig {
+    /// A signer of the DON config the pool's reports are verified with
+    pub signer: [u8; 20],
+    pub payee: Pubkey,
+    /// Share of the pool's fees, out of `TOTAL_REWARD_WEIGHT`
+    pub weight: u64,
+}
+
+/// The verifier account settings as returned by the read-only config queries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct VerifierAccountConfigDetails {
+    pub owner: Pubkey,
+    pub proposed_owner: Pubkey,
+    pub access_controller: Pubkey,
+}
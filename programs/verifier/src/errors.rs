@@ -38,4 +38,42 @@ pub enum ErrorCode {
     ConfigDeactivated,
     #[msg("Invalid inputs")]
     InvalidInputs,
+    #[msg("DonConfig id does not match the expected id")]
+    DonConfigIdMismatch,
+    #[msg("Config rotation is disabled")]
+    ConfigRotationDisabled,
+    #[msg("Rate limit account is required")]
+    RateLimitAccountRequired,
+    #[msg("Rate limit exceeded")]
+    RateLimitExceeded,
+    #[msg("Usage counter is required")]
+    UsageCounterRequired,
+    #[msg("Fee accounts are required")]
+    FeeAccountsRequired,
+    #[msg("Fee asset is not accepted")]
+    FeeAssetNotAccepted,
+    #[msg("Reward pool is required")]
+    RewardPoolRequired,
+    #[msg("Reward recipients have unclaimed rewards")]
+    UnclaimedRewards,
+    #[msg("Invalid reward recipients")]
+    InvalidRewardRecipients,
+    #[msg("Subscriber discount does not apply")]
+    SubscriberDiscountMismatch,
+    #[msg("System program is required")]
+    SystemProgramRequired,
+    #[msg("Report buffer is too small")]
+    ReportBufferTooSmall,
+    #[msg("Report schema version is not accepted")]
+    SchemaVersionNotAccepted,
+    #[msg("Unknown report schema version")]
+    UnknownSchemaVersion,
+    #[msg("Report is not routed to the DON namespace")]
+    DonRouteMismatch,
+    #[msg("Payer is required when the user is not writable")]
+    PayerRequired,
+    #[msg("Fee token mint cannot be changed")]
+    FeeTokenMintChanged,
+    #[msg("DON routes are required")]
+    DonRoutesRequired,
 }
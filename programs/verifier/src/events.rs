@@ -1,3 +1,5 @@
+use crate::domain::RewardRecipientConfig;
+use crate::state::FeeAsset;
 use anchor_lang::prelude::*;
 
 #[event]
@@ -27,4 +29,138 @@ pub struct ConfigSet {
 #[event]
 pub struct AccessControllerSet {
     pub access_controller: Pubkey,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct ConfigRotationEnabledSet {
+    pub enabled: bool,
+}
+
+#[event]
+pub struct ConfigRotated {
+    pub don_config_id: String,
+    // The rotation nonce the signatures were made over
+    pub config_rotation_nonce: u32,
+}
+
+#[event]
+pub struct AcceptedSchemaVersionsSet {
+    pub schema_versions: Vec<u16>,
+}
+
+#[event]
+pub struct DefaultRateLimitSet {
+    pub enabled: bool,
+    pub max_verifications: u64,
+    pub window_slots: u64,
+}
+
+#[event]
+pub struct UserRateLimitSet {
+    pub user: Pubkey,
+    pub max_verifications: u64,
+    pub window_slots: u64,
+}
+
+#[event]
+pub struct UserRateLimitCleared {
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct UsageMeteringEnabledSet {
+    pub enabled: bool,
+}
+
+#[event]
+pub struct UsageSnapshot {
+    pub requester: Pubkey,
+    pub feed_id: [u8; 32],
+    pub billing_period: u64,
+    pub verifications: u64,
+}
+
+#[event]
+pub struct FeeConfigSet {
+    pub enabled: bool,
+    pub token_mint: Pubkey,
+    pub token_vault: Pubkey,
+}
+
+#[event]
+pub struct FeePaid {
+    pub requester: Pubkey,
+    pub feed_id: [u8; 32],
+    // The token mint the fee was paid in, or the system program for native SOL
+    pub fee_asset: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct NativeFeeConfigSet {
+    pub enabled: bool,
+    pub surcharge_bps: u16,
+}
+
+#[event]
+pub struct FeePreferenceSet {
+    pub requester: Pubkey,
+    pub asset: FeeAsset,
+}
+
+#[event]
+pub struct SubscriberDiscountSet {
+    pub subscriber: Pubkey,
+    // `GLOBAL_DISCOUNT_FEED_ID` for a discount on all feeds
+    pub feed_id: [u8; 32],
+    pub discount_bps: u16,
+}
+
+#[event]
+pub struct FeeWaiverSet {
+    pub subscriber: Pubkey,
+    pub feed_id: [u8; 32],
+    pub waived: bool,
+}
+
+#[event]
+pub struct RewardRecipientsSet {
+    pub config_digest: [u8; 32],
+    pub recipients: Vec<RewardRecipientConfig>,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub config_digest: [u8; 32],
+    pub payee: Pubkey,
+    pub asset: FeeAsset,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DonNamespaceConfigSet {
+    pub namespace_id: u64,
+    pub don_config_id: String,
+    pub signers: Vec<[u8; 20]>,
+    pub f: u8,
+    pub don_config_index: u16,
+}
+
+#[event]
+pub struct DonNamespaceConfigActivated {
+    pub namespace_id: u64,
+    pub don_config_id: String,
+    pub is_active: bool,
+}
+
+#[event]
+pub struct DonRouteSet {
+    pub key: [u8; 32],
+    pub namespace_id: u64,
+}
+
+#[event]
+pub struct DonRouteRemoved {
+    pub key: [u8; 32],
+    pub namespace_id: u64,
+}
@@ -1,10 +1,12 @@
-use crate::domain::{Report, SignedReport};
+use crate::domain::{DecodedReport, Report, ReportFees, SignedReport};
 use crate::errors::ErrorCode;
+use crate::evm::SchemaLayout;
 use anchor_lang::solana_program::keccak::hash as keccak256;
 use ethabi::{decode, ParamType, Token};
 use std::convert::TryInto;
 
 const WORD_SIZE: usize = 32; // EVM word size in bytes
+const CONFIG_ROTATION_DOMAIN: &[u8] = b"DON_CONFIG_ROTATION";
 
 pub struct Encoder {}
 
@@ -150,6 +152,8 @@ impl Encoder {
         Ok(array_slice)
     }
 
+    /// Reads the feed id and observations timestamp from the report. The schema version is taken
+    /// from the first two bytes of the feed id and selects where the timestamp is read from.
     pub fn parse_report_details_from_report<'a>(
         report_data: &'a [u8],
     ) -> Result<Report<'a>, ErrorCode> {
@@ -160,22 +164,96 @@ impl Encoder {
             .ok_or(ErrorCode::BadVerification)?
             .try_into()
             .map_err(|_| ErrorCode::BadVerification)?;
+        let schema_version = u16::from_be_bytes([feed_id[0], feed_id[1]]);
+        let layout = SchemaLayout::for_version(schema_version)?;
 
         // Extract report_timestamp from report_data
-        let report_timestamp = u32::from_be_bytes(
-            report_data
-                .get(92..96)
-                .ok_or(ErrorCode::BadVerification)?
-                .try_into()
-                .map_err(|_| ErrorCode::BadVerification)?,
-        );
+        let report_timestamp = Self::read_u256_as_u32(report_data, layout.observations_timestamp)?;
 
         Ok(Report {
             feed_id,
+            schema_version,
+            report_timestamp,
+        })
+    }
+
+    /// Reads `native_fee` and `link_fee` from the report at the offsets of its schema
+    pub fn parse_report_fees(report_data: &[u8]) -> Result<ReportFees, ErrorCode> {
+        let Report { schema_version, .. } = Self::parse_report_details_from_report(report_data)?;
+        let layout = SchemaLayout::for_version(schema_version)?;
+
+        Ok(ReportFees {
+            native_fee: Self::read_u256_as_u128(report_data, layout.native_fee)?,
+            link_fee: Self::read_u256_as_u128(report_data, layout.link_fee)?,
+        })
+    }
+
+    /// Decodes the report's common fields at the offsets of its schema, and its remaining fields
+    /// as signed integers.
+    pub fn decode_report(report_data: &[u8]) -> Result<DecodedReport, ErrorCode> {
+        if report_data.len() % WORD_SIZE != 0 {
+            return Err(ErrorCode::BadVerification);
+        }
+
+        let Report {
+            feed_id,
+            schema_version,
             report_timestamp,
+        } = Self::parse_report_details_from_report(report_data)?;
+        let layout = SchemaLayout::for_version(schema_version)?;
+        let valid_from_timestamp = Self::read_u256_as_u32(report_data, layout.valid_from_timestamp)?;
+        let fees = Self::parse_report_fees(report_data)?;
+        let expires_at = Self::read_u256_as_u32(report_data, layout.expires_at)?;
+
+        let values = (layout.first_value..report_data.len() / WORD_SIZE)
+            .map(|index| Self::read_i256_as_i128(report_data, index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DecodedReport {
+            feed_id: *feed_id,
+            schema_version,
+            valid_from_timestamp,
+            observations_timestamp: report_timestamp,
+            native_fee: fees.native_fee,
+            link_fee: fees.link_fee,
+            expires_at,
+            values,
         })
     }
 
+    // Reads the word at `index`, failing if the value does not fit in a u32
+    fn read_u256_as_u32(data: &[u8], index: usize) -> Result<u32, ErrorCode> {
+        u32::try_from(Self::read_u256_as_u128(data, index)?).map_err(|_| ErrorCode::BadVerification)
+    }
+
+    // Reads the two's complement word at `index`, failing if the value does not fit in an i128
+    fn read_i256_as_i128(data: &[u8], index: usize) -> Result<i128, ErrorCode> {
+        let word = data
+            .get(index * WORD_SIZE..(index + 1) * WORD_SIZE)
+            .ok_or(ErrorCode::BadVerification)?;
+        let value = i128::from_be_bytes(
+            word[16..].try_into().map_err(|_| ErrorCode::BadVerification)?,
+        );
+        let sign_extension = if value < 0 { 0xff } else { 0 };
+        if word[..16].iter().any(|byte| *byte != sign_extension) {
+            return Err(ErrorCode::BadVerification);
+        }
+        Ok(value)
+    }
+
+    // Reads the word at `index`, failing if the value does not fit in a u128
+    fn read_u256_as_u128(data: &[u8], index: usize) -> Result<u128, ErrorCode> {
+        let word = data
+            .get(index * WORD_SIZE..(index + 1) * WORD_SIZE)
+            .ok_or(ErrorCode::BadVerification)?;
+        if word[..16].iter().any(|byte| *byte != 0) {
+            return Err(ErrorCode::BadVerification);
+        }
+        Ok(u128::from_be_bytes(
+            word[16..].try_into().map_err(|_| ErrorCode::BadVerification)?,
+        ))
+    }
+
     pub fn encode_don_config_id(signers: &[[u8; 20]], f: u8) -> Vec<u8> {
         // `abi.encodePacked` includes padding for array types. Padded to multiple of 32 bytes.
         // `+1` for `f` byte
@@ -190,6 +268,25 @@ impl Encoder {
     }
     
 
+    /// Computes the digest the active DON signs to rotate to a new config.
+    /// `abi.encodePacked(domain, program_id, active_don_config_id, new_don_config_id, new_don_config_index, rotation_nonce)`
+    pub fn compute_config_rotation_hash(
+        program_id: &[u8; 32],
+        active_don_config_id: &[u8; 24],
+        new_don_config_id: &[u8; 24],
+        new_don_config_index: u16,
+        rotation_nonce: u32,
+    ) -> [u8; 32] {
+        keccak256(&[
+            CONFIG_ROTATION_DOMAIN,
+            &program_id[..],
+            &active_don_config_id[..],
+            &new_don_config_id[..],
+            &new_don_config_index.to_be_bytes()[..],
+            &rotation_nonce.to_be_bytes()[..],
+        ].concat()).to_bytes()
+    }
+
     pub fn compute_don_config_id(encoded: &[u8]) -> [u8; 24] {
         let hash = keccak256(encoded);
         // For consistency with source chain contract - first 24 bytes of hash is `don_config_id`.
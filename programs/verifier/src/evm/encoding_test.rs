@@ -0,0 +1,101 @@
+use super::{Encoder, SchemaLayout};
+use crate::errors::ErrorCode;
+
+const WORD_SIZE: usize = 32;
+const OBSERVATIONS_TIMESTAMP: u128 = 1_700_000_000;
+
+/// ABI encodes a report of `schema_version` with the given words after the feed id
+fn report_data(schema_version: u16, words: &[u128]) -> Vec<u8> {
+    let mut feed_id = [0xab; WORD_SIZE];
+    feed_id[..2].copy_from_slice(&schema_version.to_be_bytes());
+
+    let mut data = feed_id.to_vec();
+    for word in words {
+        data.extend_from_slice(&[0; WORD_SIZE - 16]);
+        data.extend_from_slice(&word.to_be_bytes());
+    }
+    data
+}
+
+/// A v3 report: timestamps, fees and expiry followed by the benchmark price, bid and ask
+fn v3_report_data() -> Vec<u8> {
+    let mut data = report_data(
+        3,
+        &[
+            1_727_467_477,
+            OBSERVATIONS_TIMESTAMP,
+            118_647_852_657_900,
+            25_234_531_311_164_200,
+            0,
+            655_442_225_238_888_900,
+            655_292_586_749_804_350,
+        ],
+    );
+    // A negative ask, sign extended to the full word
+    data.extend_from_slice(&[0xff; WORD_SIZE - 16]);
+    data.extend_from_slice(&(-655_615_783_467_747_900i128).to_be_bytes());
+    data
+}
+
+#[test]
+fn test_parse_report_details_reads_schema_offsets() {
+    let layout = SchemaLayout::for_version(3).unwrap();
+    assert_eq!(layout.observations_timestamp, 2);
+    assert_eq!(layout.native_fee, 3);
+
+    let report_data = v3_report_data();
+    let details = Encoder::parse_report_details_from_report(&report_data).unwrap();
+    assert_eq!(details.feed_id[..], report_data[..WORD_SIZE]);
+    assert_eq!(details.schema_version, 3);
+    assert_eq!(details.report_timestamp, OBSERVATIONS_TIMESTAMP as u32);
+
+    let fees = Encoder::parse_report_fees(&report_data).unwrap();
+    assert_eq!(fees.native_fee, 118_647_852_657_900);
+    assert_eq!(fees.link_fee, 25_234_531_311_164_200);
+}
+
+#[test]
+fn test_parse_report_details_with_unknown_schema_version() {
+    // v1 reports carry their observations timestamp in the first word, schema 0 is not a
+    // schema at all, and schemas after v10 are not known yet
+    for schema_version in [0, 1, 11, 0xffff] {
+        let mut report_data = v3_report_data();
+        report_data[..2].copy_from_slice(&u16::to_be_bytes(schema_version));
+
+        assert!(matches!(
+            Encoder::parse_report_details_from_report(&report_data),
+            Err(ErrorCode::UnknownSchemaVersion)
+        ));
+        assert!(matches!(
+            Encoder::parse_report_fees(&report_data),
+            Err(ErrorCode::UnknownSchemaVersion)
+        ));
+        assert!(Encoder::decode_report(&report_data).is_err());
+    }
+}
+
+#[test]
+fn test_decode_report() {
+    let report_data = v3_report_data();
+
+    let decoded_report = Encoder::decode_report(&report_data).unwrap();
+    assert_eq!(decoded_report.feed_id[..], report_data[..WORD_SIZE]);
+    assert_eq!(decoded_report.schema_version, 3);
+    assert_eq!(decoded_report.valid_from_timestamp, 1_727_467_477);
+    assert_eq!(decoded_report.observations_timestamp, OBSERVATIONS_TIMESTAMP as u32);
+    assert_eq!(decoded_report.native_fee, 118_647_852_657_900);
+    assert_eq!(decoded_report.link_fee, 25_234_531_311_164_200);
+    assert_eq!(decoded_report.expires_at, 0);
+    assert_eq!(
+        decoded_report.values,
+        vec![655_442_225_238_888_900, 655_292_586_749_804_350, -655_615_783_467_747_900]
+    );
+}
+
+#[test]
+fn test_decode_report_with_partial_word() {
+    let mut report_data = v3_report_data();
+    report_data.pop();
+
+    assert!(matches!(Encoder::decode_report(&report_data), Err(ErrorCode::BadVerification)));
+}
@@ -1,4 +1,5 @@
 mod encoding;
+mod schema;
 mod signature;
 
 #[cfg(test)]
@@ -7,4 +8,5 @@ mod encoding_test;
 mod signature_test;
 
 pub use encoding::*;
+pub use schema::*;
 pub use signature::*;
\ No newline at end of file
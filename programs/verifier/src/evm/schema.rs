@@ -0,0 +1,39 @@
+use crate::errors::ErrorCode;
+use std::ops::RangeInclusive;
+
+/// Word indices of the fields a report schema carries after the feed id
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SchemaLayout {
+    pub valid_from_timestamp: usize,
+    pub observations_timestamp: usize,
+    pub native_fee: usize,
+    pub link_fee: usize,
+    pub expires_at: usize,
+    // Index of the first schema-specific field
+    pub first_value: usize,
+}
+
+/// The schema versions the verifier reads, keyed by the first two bytes of the feed id. They
+/// differ only in their values, so all of them share one layout. v1 reports carry their
+/// observations timestamp in the first word and no fees, and are not supported.
+pub const SUPPORTED_SCHEMA_VERSIONS: RangeInclusive<u16> = 2..=10;
+
+const LAYOUT: SchemaLayout = SchemaLayout {
+    valid_from_timestamp: 1,
+    observations_timestamp: 2,
+    native_fee: 3,
+    link_fee: 4,
+    expires_at: 5,
+    first_value: 6,
+};
+
+impl SchemaLayout {
+    /// Returns the layout of `schema_version`, failing for schemas the verifier does not know
+    pub fn for_version(schema_version: u16) -> Result<SchemaLayout, ErrorCode> {
+        if SUPPORTED_SCHEMA_VERSIONS.contains(&schema_version) {
+            Ok(LAYOUT)
+        } else {
+            Err(ErrorCode::UnknownSchemaVersion)
+        }
+    }
+}
@@ -9,6 +9,8 @@ pub mod util;
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak::hash as keccak256;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked};
 use common::*;
 use context::*;
 use domain::*;
@@ -27,164 +29,837 @@ declare_id!("Gt9S41PtjR58CbG9JhJ3J6vxesqrNAswbWYbLNTMZA3c");
 #[program]
 pub mod verifier {
     use super::*;
-    use solana_program::program::{set_return_data};
+    use solana_program::instruction::{AccountMeta, Instruction};
+    use solana_program::program::{invoke_signed, set_return_data};
 
     pub fn verify(ctx: Context<VerifyContext>, signed_report: Vec<u8>) -> Result<()> {
         let verifier_account = ctx.accounts.verifier_account.load()?;
 
-        let report_data = verify_report(&ctx, &signed_report, &verifier_account)?;
+        let report_data = verify_report(ctx.accounts, &signed_report, &verifier_account)?;
+
+        consume_rate_limit(ctx.accounts, &verifier_account)?;
 
         set_return_data(&report_data);
 
         Ok(())
     }
 
+    /// Verifies the signed report staged in the report buffer, for reports too large for a single
+    /// transaction. The report data is written to the report output buffer when given, and to
+    /// return data otherwise.
+    pub fn verify_from_buffer(ctx: Context<VerifyFromBufferContext>) -> Result<()> {
+        let report_buffer = &ctx.accounts.report_buffer;
+        let len = report_buffer.load()?.len as usize;
+        let buffer_data = report_buffer.as_ref().try_borrow_data()?;
+        let signed_report = &buffer_data[ReportBuffer::DATA_OFFSET..ReportBuffer::DATA_OFFSET + len];
+
+        let accounts = &ctx.accounts.verify;
+        let verifier_account = accounts.verifier_account.load()?;
+
+        let report_data = verify_report(accounts, signed_report, &verifier_account)?;
+        drop(buffer_data);
+
+        consume_rate_limit(accounts, &verifier_account)?;
+
+        match &ctx.accounts.report_output {
+            Some(report_output) => write_report_buffer_data(report_output, 0, &report_data, true)?,
+            None => set_return_data(&report_data),
+        }
+
+        Ok(())
+    }
+
+    /// Creates a buffer of `capacity` bytes to stage a signed report in across transactions
+    pub fn initialize_report_buffer(
+        ctx: Context<InitializeReportBufferContext>,
+        buffer_id: u64,
+        capacity: u32,
+    ) -> Result<()> {
+        let mut report_buffer = ctx.accounts.report_buffer.load_init()?;
+        report_buffer.authority = ctx.accounts.authority.key();
+        report_buffer.buffer_id = buffer_id;
+        report_buffer.capacity = capacity;
+        Ok(())
+    }
+
+    /// Writes a chunk of the signed report at `offset`. Writing at offset 0 starts a new report.
+    pub fn write_report_buffer(
+        ctx: Context<WriteReportBufferContext>,
+        offset: u32,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        write_report_buffer_data(&ctx.accounts.report_buffer, offset as usize, &data, offset == 0)
+    }
+
+    /// Closes a report buffer, returning its rent to the authority
+    pub fn close_report_buffer(_ctx: Context<CloseReportBufferContext>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Verifies the report like `verify`, then calls the consumer program with
+    /// `consumer_discriminator` immediately followed by the Borsh serialized `DecodedReport`, so
+    /// the data of the call holds nothing else the caller chose. The consumer receives the
+    /// forwarder PDA as a signer, followed by the remaining accounts with their signer and
+    /// writable flags.
+    pub fn verify_and_forward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyAndForwardContext<'info>>,
+        signed_report: Vec<u8>,
+        consumer_discriminator: [u8; 8],
+    ) -> Result<()> {
+        let report_data = {
+            let accounts = &ctx.accounts.verify;
+            let verifier_account = accounts.verifier_account.load()?;
+            let report_data = verify_report(accounts, &signed_report, &verifier_account)?;
+            consume_rate_limit(accounts, &verifier_account)?;
+            report_data
+        };
+
+        let decoded_report = Encoder::decode_report(&report_data)?;
+        let mut data = consumer_discriminator.to_vec();
+        decoded_report.serialize(&mut data)?;
+
+        let forwarder = &ctx.accounts.forwarder;
+        let consumer_program = &ctx.accounts.consumer_program;
+        let instruction = Instruction {
+            program_id: consumer_program.key(),
+            accounts: std::iter::once(AccountMeta::new_readonly(forwarder.key(), true))
+                .chain(ctx.remaining_accounts.iter().map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                }))
+                .collect(),
+            data,
+        };
+        let mut account_infos = vec![forwarder.to_account_info()];
+        account_infos.extend_from_slice(ctx.remaining_accounts);
+        account_infos.push(consumer_program.to_account_info());
+        invoke_signed(
+            &instruction,
+            &account_infos,
+            &[&[FORWARDER_SEED, &[ctx.bumps.forwarder]]],
+        )?;
+        Ok(())
+    }
+
+    /// Closes a verification receipt at the request of its requester, returning its rent to the
+    /// account that paid it
+    pub fn close_receipt(_ctx: Context<CloseReceiptContext>) -> Result<()> {
+        Ok(())
+    }
+
     pub fn set_config_with_activation_time(
         ctx: Context<UpdateConfigContext>,
         signers: Vec<[u8; 20]>,
         f: u8,
         activation_time: u32,
     ) -> Result<()> {
-        require!(f > 0, errors::ErrorCode::FaultToleranceMustBePositive);
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+
+        add_don_config(&mut verifier_account, signers, f, activation_time, None)
+    }
+
+    pub fn set_config(
+        ctx: Context<UpdateConfigContext>,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+    ) -> Result<()> {
+        set_config_with_activation_time(
+            ctx,
+            signers,
+            f,
+            Clock::get()?.unix_timestamp as u32,
+        )
+    }
+
+    /// Like `set_config_with_activation_time`, but fails unless `expected_don_config_id` matches
+    /// the id computed from the sorted signers and `f`
+    pub fn set_config_with_activation_time_checked(
+        ctx: Context<UpdateConfigContext>,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: u32,
+        expected_don_config_id: [u8; 24],
+    ) -> Result<()> {
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+
+        add_don_config(
+            &mut verifier_account,
+            signers,
+            f,
+            activation_time,
+            Some(expected_don_config_id),
+        )
+    }
+
+    /// Like `set_config`, but fails unless `expected_don_config_id` matches the id computed from
+    /// the sorted signers and `f`
+    pub fn set_config_checked(
+        ctx: Context<UpdateConfigContext>,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        expected_don_config_id: [u8; 24],
+    ) -> Result<()> {
+        set_config_with_activation_time_checked(
+            ctx,
+            signers,
+            f,
+            Clock::get()?.unix_timestamp as u32,
+            expected_don_config_id,
+        )
+    }
 
+    pub fn set_config_active(
+        ctx: Context<UpdateConfigContext>,
+        don_config_index: u64,
+        is_active: u8,
+    ) -> Result<()> {
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
         require!(
-            signers.len() > 3 * f as usize,
-            errors::ErrorCode::InsufficientSigners
+            don_config_index < verifier_account.don_configs.len() as u64,
+            errors::ErrorCode::DonConfigDoesNotExist
         );
+        verifier_account.don_configs[don_config_index as usize].is_active = is_active;
+        emit!(ConfigActivated {
+            don_config_id: hex_encode(
+                verifier_account.don_configs[don_config_index as usize].don_config_id
+            ),
+            is_active: is_active != 0,
+        });
+        Ok(())
+    }
 
+    pub fn remove_latest_config(ctx: Context<UpdateConfigContext>) -> Result<()> {
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
         require!(
-            signers.len() <= MAX_NUMBER_OF_ORACLES as usize,
-            errors::ErrorCode::ExcessSigners
+            !verifier_account.don_configs.is_empty(),
+            errors::ErrorCode::DonConfigDoesNotExist
         );
+        let c = verifier_account.don_configs.pop().unwrap();
+        emit!(ConfigRemoved {
+            don_config_id: hex_encode(c.don_config_id)
+        });
+        Ok(())
+    }
+
+    /// Allows or disallows the active DON to set its successor config via `rotate_config`
+    pub fn set_config_rotation_enabled(
+        ctx: Context<UpdateConfigContext>,
+        enabled: bool,
+    ) -> Result<()> {
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        verifier_account.config_rotation_enabled = u8::from(enabled);
+        emit!(ConfigRotationEnabledSet { enabled });
+        Ok(())
+    }
 
-        // Check that activationTime is not in the future.
+    /// Sets the report schema versions `verify` accepts, replacing the previous list. The version
+    /// is taken from the first two bytes of the feed id. An empty list accepts every version.
+    pub fn set_accepted_schema_versions(
+        ctx: Context<UpdateConfigContext>,
+        schema_versions: Vec<u16>,
+    ) -> Result<()> {
         require!(
-            activation_time <= Clock::get()?.unix_timestamp as u32,
-            errors::ErrorCode::BadActivationTime
+            schema_versions.len() <= MAX_NUMBER_OF_SCHEMA_VERSIONS,
+            errors::ErrorCode::InvalidInputs
+        );
+        require!(
+            schema_versions
+                .iter()
+                .enumerate()
+                .all(|(i, version)| !schema_versions[..i].contains(version)),
+            errors::ErrorCode::InvalidInputs
         );
 
         let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        verifier_account.accepted_schema_versions.clear();
+        verifier_account.accepted_schema_versions.extend(&schema_versions);
+        emit!(AcceptedSchemaVersionsSet { schema_versions });
+        Ok(())
+    }
+
+    /// Creates an empty DON namespace, whose config history verifies the reports routed to it
+    pub fn initialize_don_namespace(
+        ctx: Context<InitializeDonNamespaceContext>,
+        namespace_id: u64,
+    ) -> Result<()> {
+        let mut don_namespace = ctx.accounts.don_namespace.load_init()?;
+        don_namespace.namespace_id = namespace_id;
+        Ok(())
+    }
+
+    /// Sets a new DON config in the namespace, validated like `set_config_with_activation_time`
+    pub fn set_namespace_config_with_activation_time(
+        ctx: Context<UpdateDonNamespaceContext>,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: u32,
+    ) -> Result<()> {
+        let mut don_namespace = ctx.accounts.don_namespace.load_mut()?;
+
+        add_namespace_don_config(&mut don_namespace, signers, f, activation_time, None)
+    }
 
-        // Check we haven't reached the max number of configs
+    /// Like `set_namespace_config_with_activation_time`, but fails unless `expected_don_config_id`
+    /// matches the id computed from the sorted signers and `f`
+    pub fn set_namespace_config_with_activation_time_checked(
+        ctx: Context<UpdateDonNamespaceContext>,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: u32,
+        expected_don_config_id: [u8; 24],
+    ) -> Result<()> {
+        let mut don_namespace = ctx.accounts.don_namespace.load_mut()?;
+
+        add_namespace_don_config(
+            &mut don_namespace,
+            signers,
+            f,
+            activation_time,
+            Some(expected_don_config_id),
+        )
+    }
+
+    pub fn set_namespace_config_active(
+        ctx: Context<UpdateDonNamespaceContext>,
+        don_config_index: u64,
+        is_active: u8,
+    ) -> Result<()> {
+        let mut don_namespace = ctx.accounts.don_namespace.load_mut()?;
         require!(
-            verifier_account.don_configs.len() < MAX_NUMBER_OF_DON_CONFIGS,
-            errors::ErrorCode::MaxNumberOfConfigsReached
+            don_config_index < don_namespace.don_configs.len() as u64,
+            errors::ErrorCode::DonConfigDoesNotExist
         );
+        don_namespace.don_configs[don_config_index as usize].is_active = is_active;
+        emit!(DonNamespaceConfigActivated {
+            namespace_id: don_namespace.namespace_id,
+            don_config_id: hex_encode(
+                don_namespace.don_configs[don_config_index as usize].don_config_id
+            ),
+            is_active: is_active != 0,
+        });
+        Ok(())
+    }
 
-        // Sort signers to ensure donConfigId is deterministic.
-        let mut sorted_signers = signers;
-        sorted_signers.sort_unstable();
+    /// Routes reports whose config digest or feed id is `key` to the DON namespace. A route of the
+    /// config digest takes precedence over one of the feed id.
+    pub fn initialize_don_route(
+        ctx: Context<InitializeDonRouteContext>,
+        key: [u8; 32],
+    ) -> Result<()> {
+        let namespace_id = ctx.accounts.don_namespace.load()?.namespace_id;
+        let mut don_route = ctx.accounts.don_route.load_init()?;
+        don_route.key = key;
+        don_route.namespace_id = namespace_id;
+        ctx.accounts.verifier_account.load_mut()?.don_route_count += 1;
+        emit!(DonRouteSet { key, namespace_id });
+        Ok(())
+    }
+
+    /// Removes a route, returning its rent to the owner. Its reports are verified against the
+    /// verifier account's configs again.
+    pub fn close_don_route(ctx: Context<CloseDonRouteContext>) -> Result<()> {
+        let don_route = ctx.accounts.don_route.load()?;
+        ctx.accounts.verifier_account.load_mut()?.don_route_count -= 1;
+        emit!(DonRouteRemoved {
+            key: don_route.key,
+            namespace_id: don_route.namespace_id,
+        });
+        Ok(())
+    }
+
+    /// Enables or disables rate limiting. While enabled every `verify` must pass the user's rate
+    /// limit account, which is checked against `max_verifications` per `window_slots` unless the
+    /// user has their own limit. A `max_verifications` of 0 means no limit. `window_slots` must be
+    /// non-zero when enabling.
+    pub fn set_default_rate_limit(
+        ctx: Context<UpdateConfigContext>,
+        enabled: bool,
+        max_verifications: u64,
+        window_slots: u64,
+    ) -> Result<()> {
+        require!(!enabled || window_slots > 0, errors::ErrorCode::InvalidInputs);
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        verifier_account.rate_limit_enabled = u8::from(enabled);
+        verifier_account.default_rate_limit = RateLimit {
+            max_verifications,
+            window_slots,
+        };
+        emit!(DefaultRateLimitSet {
+            enabled,
+            max_verifications,
+            window_slots,
+        });
+        Ok(())
+    }
+
+    /// Creates the rate limit account of a user. Anyone may pay for it.
+    pub fn initialize_rate_limit(ctx: Context<InitializeRateLimitContext>) -> Result<()> {
+        let mut rate_limit = ctx.accounts.rate_limit.load_init()?;
+        rate_limit.user = ctx.accounts.user.key();
+        Ok(())
+    }
+
+    /// Gives a user their own rate limit instead of the default one
+    pub fn set_user_rate_limit(
+        ctx: Context<SetUserRateLimitContext>,
+        max_verifications: u64,
+        window_slots: u64,
+    ) -> Result<()> {
+        require!(window_slots > 0, errors::ErrorCode::InvalidInputs);
+        let mut rate_limit = ctx.accounts.rate_limit.load_mut()?;
+        rate_limit.limit_override = RateLimit {
+            max_verifications,
+            window_slots,
+        };
+        rate_limit.has_limit_override = 1;
+        emit!(UserRateLimitSet {
+            user: rate_limit.user,
+            max_verifications,
+            window_slots,
+        });
+        Ok(())
+    }
+
+    /// Puts a user back on the default rate limit
+    pub fn clear_user_rate_limit(ctx: Context<SetUserRateLimitContext>) -> Result<()> {
+        let mut rate_limit = ctx.accounts.rate_limit.load_mut()?;
+        rate_limit.limit_override = RateLimit::default();
+        rate_limit.has_limit_override = 0;
+        emit!(UserRateLimitCleared {
+            user: rate_limit.user,
+        });
+        Ok(())
+    }
+
+    /// Enables or disables usage metering. While enabled every `verify` must pass the requester's
+    /// usage counter for the report's feed.
+    pub fn set_usage_metering_enabled(
+        ctx: Context<UpdateConfigContext>,
+        enabled: bool,
+    ) -> Result<()> {
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        verifier_account.usage_metering_enabled = u8::from(enabled);
+        emit!(UsageMeteringEnabledSet { enabled });
+        Ok(())
+    }
 
-        // Check for duplicate addresses in signers.
+    /// Creates the usage counter of a requester for a feed. Anyone may pay for it.
+    pub fn initialize_usage_counter(
+        ctx: Context<InitializeUsageCounterContext>,
+        feed_id: [u8; 32],
+    ) -> Result<()> {
+        let mut usage_counter = ctx.accounts.usage_counter.load_init()?;
+        usage_counter.requester = ctx.accounts.requester.key();
+        usage_counter.feed_id = feed_id;
+        Ok(())
+    }
+
+    /// Closes `billing_period` for the usage counters passed as remaining accounts, keeping their
+    /// count as the last period's usage and resetting them.
+    pub fn snapshot_usage<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateConfigContext<'info>>,
+        billing_period: u64,
+    ) -> Result<()> {
+        for account in ctx.remaining_accounts {
+            let usage_counter = AccountLoader::<UsageCounter>::try_from(account)?;
+            require!(account.is_writable, errors::ErrorCode::InvalidInputs);
+            let mut usage_counter = usage_counter.load_mut()?;
+
+            usage_counter.last_period_verifications = std::mem::take(&mut usage_counter.verifications);
+            usage_counter.last_billing_period = billing_period;
+
+            emit!(UsageSnapshot {
+                requester: usage_counter.requester,
+                feed_id: usage_counter.feed_id,
+                billing_period,
+                verifications: usage_counter.last_period_verifications,
+            });
+        }
+        Ok(())
+    }
+
+    /// Enables or disables fees in an SPL token and sets the token. While enabled `verify` transfers
+    /// the report's `link_fee` in this token from requesters paying in it to the fee vault.
+    /// The token cannot be changed once set, as reward pools track unclaimed fees in it.
+    pub fn set_fee_config(ctx: Context<SetFeeConfigContext>, enabled: bool) -> Result<()> {
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        let token_mint = verifier_account.fee_config.token_mint;
         require!(
-            !SliceUtil::has_duplicates_sorted(&sorted_signers),
-            errors::ErrorCode::NonUniqueSignatures
+            token_mint == Pubkey::default() || token_mint == ctx.accounts.fee_token_mint.key(),
+            errors::ErrorCode::FeeTokenMintChanged
         );
+        verifier_account.fee_enabled = u8::from(enabled);
+        verifier_account.fee_config = FeeConfig {
+            token_mint: ctx.accounts.fee_token_mint.key(),
+            token_vault: ctx.accounts.fee_token_vault.key(),
+        };
+        emit!(FeeConfigSet {
+            enabled,
+            token_mint: verifier_account.fee_config.token_mint,
+            token_vault: verifier_account.fee_config.token_vault,
+        });
+        Ok(())
+    }
 
-        let don_config_id = Encoder::compute_don_config_id(&Encoder::encode_don_config_id(&sorted_signers, f));
+    /// Allows or disallows paying fees in native SOL, charged the report's `native_fee` plus
+    /// `surcharge_bps` basis points. Funds the native fee vault to be rent exempt if needed.
+    pub fn set_native_fee_config(
+        ctx: Context<SetNativeFeeConfigContext>,
+        enabled: bool,
+        surcharge_bps: u16,
+    ) -> Result<()> {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        let shortfall = rent_exempt_minimum.saturating_sub(ctx.accounts.native_fee_vault.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: ctx.accounts.native_fee_vault.to_account_info(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
 
-        // Check if there are any existing configs
-        if let Some(last_don_config) = verifier_account.don_configs.last() {
-            // Check the config we're setting isn't already set as the current active config as this will increase search costs unnecessarily when verifying historic reports
-            require!(
-                last_don_config.don_config_id != don_config_id,
-                errors::ErrorCode::DonConfigAlreadyExists
-            );
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        verifier_account.native_fee_enabled = u8::from(enabled);
+        verifier_account.native_surcharge_bps = surcharge_bps;
+        emit!(NativeFeeConfigSet {
+            enabled,
+            surcharge_bps,
+        });
+        Ok(())
+    }
 
-            // Check that activation time is after the last config
-            require!(
-                last_don_config.activation_time < activation_time,
-                errors::ErrorCode::BadActivationTime
-            );
-        }
+    /// Creates the requester's fee preference with their choice of fee asset
+    pub fn initialize_fee_preference(
+        ctx: Context<InitializeFeePreferenceContext>,
+        asset: FeeAsset,
+    ) -> Result<()> {
+        let mut fee_preference = ctx.accounts.fee_preference.load_init()?;
+        fee_preference.requester = ctx.accounts.requester.key();
+        fee_preference.asset = asset as u8;
+        emit!(FeePreferenceSet {
+            requester: fee_preference.requester,
+            asset,
+        });
+        Ok(())
+    }
 
-        // Register the signers for this DON
-        let mut signers_array = SigningKeys::default();
-        for signer in sorted_signers.iter() {
-            require!(
-                !is_zero_address(signer),
-                errors::ErrorCode::ZeroAddress
-            );
+    /// Changes the asset the requester pays fees in
+    pub fn set_fee_preference(ctx: Context<SetFeePreferenceContext>, asset: FeeAsset) -> Result<()> {
+        let mut fee_preference = ctx.accounts.fee_preference.load_mut()?;
+        fee_preference.asset = asset as u8;
+        emit!(FeePreferenceSet {
+            requester: fee_preference.requester,
+            asset,
+        });
+        Ok(())
+    }
+
+    /// Creates a subscriber's discount record for a feed, or for all feeds with
+    /// `GLOBAL_DISCOUNT_FEED_ID`. Anyone may pay for it, only the owner can grant a discount.
+    pub fn initialize_subscriber_discount(
+        ctx: Context<InitializeSubscriberDiscountContext>,
+        feed_id: [u8; 32],
+    ) -> Result<()> {
+        let mut subscriber_discount = ctx.accounts.subscriber_discount.load_init()?;
+        subscriber_discount.subscriber = ctx.accounts.subscriber.key();
+        subscriber_discount.feed_id = feed_id;
+        Ok(())
+    }
+
+    /// Sets a subscriber's discount on fees in basis points
+    pub fn set_subscriber_discount(
+        ctx: Context<SetSubscriberDiscountContext>,
+        discount_bps: u16,
+    ) -> Result<()> {
+        require!(
+            discount_bps as u64 <= BASIS_POINTS,
+            errors::ErrorCode::InvalidInputs
+        );
+        let mut subscriber_discount = ctx.accounts.subscriber_discount.load_mut()?;
+        subscriber_discount.discount_bps = discount_bps;
+        emit!(SubscriberDiscountSet {
+            subscriber: subscriber_discount.subscriber,
+            feed_id: subscriber_discount.feed_id,
+            discount_bps,
+        });
+        Ok(())
+    }
+
+    /// Waives all fees of a subscriber, e.g. for internal service accounts. Waived subscribers
+    /// need none of the fee accounts in `verify`.
+    pub fn set_fee_waiver(ctx: Context<SetSubscriberDiscountContext>, waived: bool) -> Result<()> {
+        let mut subscriber_discount = ctx.accounts.subscriber_discount.load_mut()?;
+        subscriber_discount.waived = u8::from(waived);
+        emit!(FeeWaiverSet {
+            subscriber: subscriber_discount.subscriber,
+            feed_id: subscriber_discount.feed_id,
+            waived,
+        });
+        Ok(())
+    }
+
+    /// Creates the reward pool collecting fees of reports with `config_digest`. Anyone may pay for it.
+    pub fn initialize_reward_pool(
+        ctx: Context<InitializeRewardPoolContext>,
+        config_digest: [u8; 32],
+    ) -> Result<()> {
+        let mut reward_pool = ctx.accounts.reward_pool.load_init()?;
+        reward_pool.config_digest = config_digest;
+        Ok(())
+    }
+
+    /// Sets who the pool's fees are paid to. Each recipient is paid for a signer of the DON config
+    /// at `don_config_index`, of the DON namespace when given, and their weights must add up to
+    /// `TOTAL_REWARD_WEIGHT`. Fees collected before the first recipients are set are shared among
+    /// them. As with the EVM RewardManager's `updateRewardRecipients`, the current recipients must
+    /// have claimed all their rewards before they are replaced, so `claim_rewards` for both assets
+    /// should precede this in the same transaction.
+    pub fn set_reward_recipients(
+        ctx: Context<SetRewardRecipientsContext>,
+        don_config_index: u64,
+        recipients: Vec<RewardRecipientConfig>,
+    ) -> Result<()> {
+        let verifier_account = ctx.accounts.verifier_account.load()?;
+        let don_namespace = ctx
+            .accounts
+            .don_namespace
+            .as_ref()
+            .map(|don_namespace| don_namespace.load())
+            .transpose()?;
+        let don_configs: &[DonConfig] = match &don_namespace {
+            Some(don_namespace) => &don_namespace.don_configs,
+            None => &verifier_account.don_configs,
+        };
+        require!(
+            don_config_index < don_configs.len() as u64,
+            errors::ErrorCode::DonConfigDoesNotExist
+        );
+        let don_config = &don_configs[don_config_index as usize];
+
+        let mut reward_pool = ctx.accounts.reward_pool.load_mut()?;
+        require!(reward_pool.is_settled(), errors::ErrorCode::UnclaimedRewards);
 
-            signers_array.push(SigningKey {
-                key: *signer,
+        let signers: Vec<[u8; 20]> = recipients.iter().map(|recipient| recipient.signer).collect();
+        require!(
+            !recipients.is_empty()
+                && recipients.len() <= reward_pool.recipients.capacity()
+                && !SliceUtil::has_duplicates(&signers)
+                && signers.iter().all(|signer| don_config.signers.iter().any(|s| s.key == *signer))
+                && recipients.iter().all(|recipient| recipient.weight > 0)
+                && recipients.iter().try_fold(0u64, |total, recipient| total.checked_add(recipient.weight))
+                    == Some(TOTAL_REWARD_WEIGHT),
+            errors::ErrorCode::InvalidRewardRecipients
+        );
+
+        // New recipients of a pool that already had some only share in fees collected from now on
+        let (token_fees_claimed_to, native_fees_claimed_to) = if reward_pool.recipients.is_empty() {
+            (0, 0)
+        } else {
+            (reward_pool.total_token_fees, reward_pool.total_native_fees)
+        };
+        reward_pool.recipients.clear();
+        for recipient in &recipients {
+            reward_pool.recipients.push(RewardRecipient {
+                payee: recipient.payee,
+                signer: recipient.signer,
+                weight: recipient.weight,
+                token_fees_claimed_to,
+                native_fees_claimed_to,
+                ..Default::default()
             });
         }
 
-        verifier_account.don_configs.push(DonConfig {
-            don_config_id,
-            f,
-            is_active: 1,
-            activation_time,
-            _padding: 0,
-            signers: signers_array,
+        emit!(RewardRecipientsSet {
+            config_digest: reward_pool.config_digest,
+            recipients,
         });
+        Ok(())
+    }
 
-        emit!(ConfigSet {
-            don_config_id: hex_encode(don_config_id),
-            signers: sorted_signers,
-            f,
-            don_config_index: (verifier_account.don_configs.len() - 1) as u16,
-        });
+    /// Pays the recipients at `recipient_indices` their share of fees in `asset` collected since
+    /// their last claim, so pools with many recipients can be claimed over several transactions.
+    /// Anyone may submit it.
+    pub fn claim_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRewardsContext<'info>>,
+        asset: FeeAsset,
+        recipient_indices: Vec<u8>,
+    ) -> Result<()> {
+        let mut reward_pool = ctx.accounts.reward_pool.load_mut()?;
+        require!(
+            ctx.remaining_accounts.len() == recipient_indices.len()
+                && recipient_indices
+                    .iter()
+                    .all(|index| (*index as usize) < reward_pool.recipients.len()),
+            errors::ErrorCode::InvalidInputs
+        );
+
+        for (index, payee_account) in recipient_indices.iter().zip(ctx.remaining_accounts.iter()) {
+            let index = *index as usize;
+            let amount = reward_pool.claim(index, asset);
+            let payee = reward_pool.recipients[index].payee;
+            if amount == 0 {
+                continue;
+            }
 
+            match asset {
+                FeeAsset::Token => pay_token_reward(&ctx, payee_account, &payee, amount)?,
+                FeeAsset::Native => pay_native_reward(&ctx, payee_account, &payee, amount)?,
+            }
+
+            emit!(RewardsClaimed {
+                config_digest: reward_pool.config_digest,
+                payee,
+                asset,
+                amount,
+            });
+        }
         Ok(())
     }
 
-    pub fn set_config(
-        ctx: Context<UpdateConfigContext>,
+    /// Sets a new DON config without the owner. The rotation must be signed by more than `f`
+    /// signers of the currently active config over `Encoder::compute_config_rotation_hash`, which
+    /// includes the verifier account's rotation nonce. Signatures are packed the same way as in a
+    /// signed report.
+    pub fn rotate_config(
+        ctx: Context<RotateConfigContext>,
         signers: Vec<[u8; 20]>,
         f: u8,
+        rs: Vec<[u8; 32]>,
+        ss: Vec<[u8; 32]>,
+        raw_vs: [u8; 32],
     ) -> Result<()> {
-        set_config_with_activation_time(
-            ctx,
-            signers,
-            f,
-            Clock::get()?.unix_timestamp as u32,
-        )
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        require!(
+            verifier_account.config_rotation_enabled != 0,
+            errors::ErrorCode::ConfigRotationDisabled
+        );
+
+        let activation_time = Clock::get()?.unix_timestamp as u32;
+
+        // The config that would verify a report observed now authorises the rotation
+        let active_don_config_index = verifier_account
+            .don_configs
+            .find_index_for_timestamp(activation_time)
+            .ok_or(errors::ErrorCode::DonConfigDoesNotExist)?;
+        let active_don_config = &verifier_account.don_configs[active_don_config_index];
+        require!(
+            active_don_config.is_active != 0,
+            errors::ErrorCode::ConfigDeactivated
+        );
+
+        let mut sorted_signers = signers;
+        sorted_signers.sort_unstable();
+        let new_don_config_id = Encoder::compute_don_config_id(&Encoder::encode_don_config_id(&sorted_signers, f));
+
+        let rotation_hash = Encoder::compute_config_rotation_hash(
+            &ID.to_bytes(),
+            &active_don_config.don_config_id,
+            &new_don_config_id,
+            verifier_account.don_configs.len() as u16,
+            verifier_account.config_rotation_nonce,
+        );
+
+        let rotation_signers = recover_signers(&rotation_hash, &rs, &ss, &raw_vs)?;
+        check_signers(&rotation_signers, active_don_config)?;
+
+        add_don_config(&mut verifier_account, sorted_signers, f, activation_time, None)?;
+
+        emit!(ConfigRotated {
+            don_config_id: hex_encode(new_don_config_id),
+            config_rotation_nonce: verifier_account.config_rotation_nonce,
+        });
+        verifier_account.config_rotation_nonce += 1;
+        Ok(())
     }
 
-    pub fn set_config_active(
-        ctx: Context<UpdateConfigContext>,
+    /// Returns the number of DON configs that have been set
+    pub fn get_config_count(ctx: Context<ReadVerifierAccountContext>) -> Result<u16> {
+        let verifier_account = ctx.accounts.verifier_account.load()?;
+        Ok(verifier_account.don_configs.len() as u16)
+    }
+
+    /// Returns the DON config stored at `don_config_index`
+    pub fn get_config(
+        ctx: Context<ReadVerifierAccountContext>,
         don_config_index: u64,
-        is_active: u8,
-    ) -> Result<()> {
-        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+    ) -> Result<DonConfigDetails> {
+        let verifier_account = ctx.accounts.verifier_account.load()?;
         require!(
             don_config_index < verifier_account.don_configs.len() as u64,
             errors::ErrorCode::DonConfigDoesNotExist
         );
-        verifier_account.don_configs[don_config_index as usize].is_active = is_active;
-        emit!(ConfigActivated {
-            don_config_id: hex_encode(
-                verifier_account.don_configs[don_config_index as usize].don_config_id
-            ),
-            is_active: is_active != 0,
-        });
-        Ok(())
+        let index = don_config_index as usize;
+        Ok(don_config_details(index, &verifier_account.don_configs[index]))
     }
 
-    pub fn remove_latest_config(ctx: Context<UpdateConfigContext>) -> Result<()> {
-        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+    /// Returns the DON config that `verify` would select for a report with the given timestamp.
+    /// The config is returned even if it has been deactivated.
+    pub fn get_config_for_timestamp(
+        ctx: Context<ReadVerifierAccountContext>,
+        timestamp: u32,
+    ) -> Result<DonConfigDetails> {
+        let verifier_account = ctx.accounts.verifier_account.load()?;
+        let index = verifier_account
+            .don_configs
+            .find_index_for_timestamp(timestamp)
+            .ok_or(errors::ErrorCode::DonConfigDoesNotExist)?;
+        Ok(don_config_details(index, &verifier_account.don_configs[index]))
+    }
+
+    /// Returns the fee `verify` would charge the requester for the signed report, or `None` while
+    /// fees are disabled or waived for the requester. The report is parsed and its schema version
+    /// checked as in `verify` but its signatures are not checked.
+    pub fn get_fee_quote(
+        ctx: Context<GetFeeQuoteContext>,
+        signed_report: Vec<u8>,
+    ) -> Result<Option<FeeQuote>> {
+        let verifier_account = ctx.accounts.verifier_account.load()?;
+
+        let decompressed_report = Compressor::decompress(&signed_report);
+        let SignedReport { report_data, .. } = Encoder::parse_signed_report(&decompressed_report)?;
+        let report = Encoder::parse_report_details_from_report(report_data)?;
         require!(
-            !verifier_account.don_configs.is_empty(),
-            errors::ErrorCode::DonConfigDoesNotExist
+            verifier_account.accepted_schema_versions.accepts(report.schema_version),
+            errors::ErrorCode::SchemaVersionNotAccepted
         );
-        let c = verifier_account.don_configs.pop().unwrap();
-        emit!(ConfigRemoved {
-            don_config_id: hex_encode(c.don_config_id)
-        });
-        Ok(())
+
+        compute_fee_quote(
+            &verifier_account,
+            &ctx.accounts.requester.key(),
+            report.feed_id,
+            ctx.accounts.fee_preference.as_ref(),
+            ctx.accounts.fee_token_mint.as_deref(),
+            ctx.accounts.subscriber_discount.as_ref(),
+            report_data,
+        )
+    }
+
+    /// Returns the owner, proposed owner and access controller of the verifier
+    pub fn get_account_config(
+        ctx: Context<ReadVerifierAccountContext>,
+    ) -> Result<VerifierAccountConfigDetails> {
+        let verifier_account = ctx.accounts.verifier_account.load()?;
+        let config = &verifier_account.verifier_account_config;
+        Ok(VerifierAccountConfigDetails {
+            owner: config.owner,
+            proposed_owner: config.proposed_owner,
+            access_controller: config.access_controller,
+        })
     }
 
     /// Used to Set the access controller
-    /// We use an optional access controller. 
-    /// See https://github.com/coral-xyz/anchor/pull/2101 on how option works in Anchor 
+    /// Pass the verifier program ID as the access controller to disable it.
     pub fn set_access_controller(ctx: Context<SetAccessControllerContext>) -> Result<()> {
         let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
 
-        verifier_account.verifier_account_config.access_controller = ctx.accounts.access_controller
-            .as_ref()
-            .map_or(Pubkey::default(), |ac| ac.key());
+        verifier_account.verifier_account_config.access_controller =
+            access_controller_key(&ctx.accounts.access_controller)?;
         
         emit!(AccessControllerSet {
             access_controller: verifier_account.verifier_account_config.access_controller.key()
@@ -192,6 +867,17 @@ pub mod verifier {
         Ok(())
     }
 
+    /// Disables the access controller, letting any user verify reports
+    pub fn clear_access_controller(ctx: Context<UpdateConfigContext>) -> Result<()> {
+        let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
+        verifier_account.verifier_account_config.access_controller = Pubkey::default();
+
+        emit!(AccessControllerSet {
+            access_controller: Pubkey::default()
+        });
+        Ok(())
+    }
+
     /// initialize into existence the verifier account. You must realloc after this
     pub fn initialize(
         _ctx: Context<InitializeContext>
@@ -201,17 +887,15 @@ pub mod verifier {
     }
 
     /// Initializes the verifier (admin) account data. Call after initialize + realloc
-    /// We use an optional access controller. 
-    /// See https://github.com/coral-xyz/anchor/pull/2101 on how option works in Anchor 
+    /// Pass the verifier program ID as the access controller to leave it unset.
     pub fn initialize_account_data(
         ctx: Context<InitializeAccountDataContext>
     ) -> Result<()> {
         let mut verifier_account = ctx.accounts.verifier_account.load_mut()?;
         require!(verifier_account.version == 0, errors::ErrorCode::InvalidInputs); // assert uninitialized state
         verifier_account.version = 1;
-        if let Some(access_controller) = &ctx.accounts.access_controller {
-            verifier_account.verifier_account_config.access_controller = access_controller.key();
-        }
+        verifier_account.verifier_account_config.access_controller =
+            access_controller_key(&ctx.accounts.access_controller)?;
         verifier_account.verifier_account_config.owner = ctx.accounts.owner.key();
         Ok(())
     }
@@ -238,7 +922,7 @@ pub mod verifier {
 }
 
 fn verify_report(
-    ctx: &Context<VerifyContext>,
+    accounts: &VerifyContext,
     signed_report: &[u8],
     verifier_account: &Ref<VerifierAccount>
 ) -> Result<Vec<u8>> {
@@ -255,10 +939,553 @@ fn verify_report(
 
     let (expected_config_account, _) = Pubkey::find_program_address(&[&report_context[0]], &ID);
     require!(
-        expected_config_account == ctx.accounts.config_account.key(),
+        expected_config_account == accounts.config_account.key(),
         errors::ErrorCode::InvalidConfigAccount
     );
 
+    let signed_payload_hash = keccak256(&[
+        &keccak256(report_data).to_bytes()[..],
+        &report_context[0][..],
+        &report_context[1][..],
+        &report_context[2][..]
+    ].concat());
+
+    let signers = recover_signers(&signed_payload_hash.to_bytes(), rs, ss, raw_vs)?;
+
+    // Parse report details from report_data
+    let report = Encoder::parse_report_details_from_report(report_data)?;
+    require!(
+        verifier_account.accepted_schema_versions.accepts(report.schema_version),
+        errors::ErrorCode::SchemaVersionNotAccepted
+    );
+
+    // With a feed access list the user may be allowed for this feed only
+    if let Some(feed_access_list) = &accounts.feed_access_list {
+        require!(
+            verifier_account.verifier_account_config.access_controller == Pubkey::default() ||
+            access_controller::has_access_grant(
+                &accounts.access_controller,
+                accounts.access_grant.as_ref(),
+                &accounts.user.key(),
+            )? ||
+            caller_has_program_access(
+                &accounts.access_controller,
+                accounts.instructions_sysvar.as_ref(),
+            )? ||
+            access_controller::has_feed_access(
+                &accounts.access_controller,
+                feed_access_list,
+                report.feed_id,
+                &accounts.user.key(),
+            )?,
+            errors::ErrorCode::Unauthorized
+        );
+    }
+
+    // Reports routed to a DON namespace are verified against its configs instead
+    let don_namespace =
+        load_don_namespace(accounts, verifier_account, &report_context[0], report.feed_id)?;
+    let don_configs: &[DonConfig] = match &don_namespace {
+        Some(don_namespace) => &don_namespace.don_configs,
+        None => &verifier_account.don_configs,
+    };
+
+    // Find the active DON configuration based on the report timestamp
+    let active_don_config_index = don_configs
+        .find_index_for_timestamp(report.report_timestamp)
+        .ok_or(errors::ErrorCode::BadVerification)?;
+    let active_don_config = &don_configs[active_don_config_index];
+
+    // Ensure the active DON is indeed active
+    require!(
+        active_don_config.is_active != 0,
+        errors::ErrorCode::ConfigDeactivated
+    );
+
+    check_signers(&signers, active_don_config)?;
+
+    record_usage(accounts, verifier_account, report.feed_id)?;
+
+    write_receipt(accounts, &report, report_data, active_don_config)?;
+
+    charge_fee(accounts, verifier_account, report_data, &report_context[0], report.feed_id)?;
+
+    emit!(ReportVerified {
+        feed_id: *report.feed_id,
+        requester: accounts.user.key(),
+    });
+
+    Ok(report_data.to_vec())
+}
+
+/// Loads the DON namespace the report is routed to by its config digest, or else by its feed id.
+/// While any route exists both routes must be given, so routed reports cannot be verified against
+/// the verifier account's configs. Reports without a route are verified against those.
+fn load_don_namespace<'a>(
+    accounts: &'a VerifyContext,
+    verifier_account: &VerifierAccount,
+    config_digest: &[u8; 32],
+    feed_id: &[u8; 32],
+) -> Result<Option<Ref<'a, DonNamespace>>> {
+    let namespace_id = match (&accounts.config_digest_route, &accounts.feed_route) {
+        (Some(config_digest_route), Some(feed_route)) => {
+            match read_don_route(config_digest_route, config_digest)? {
+                Some(namespace_id) => Some(namespace_id),
+                None => read_don_route(feed_route, feed_id)?,
+            }
+        }
+        (None, None) => {
+            require!(
+                verifier_account.don_route_count == 0,
+                errors::ErrorCode::DonRoutesRequired
+            );
+            None
+        }
+        _ => return Err(errors::ErrorCode::DonRoutesRequired.into()),
+    };
+
+    match (namespace_id, &accounts.don_namespace) {
+        (None, None) => Ok(None),
+        (Some(namespace_id), Some(don_namespace)) => {
+            let don_namespace = don_namespace.load()?;
+            require!(
+                don_namespace.namespace_id == namespace_id,
+                errors::ErrorCode::DonRouteMismatch
+            );
+            Ok(Some(don_namespace))
+        }
+        _ => Err(errors::ErrorCode::DonRouteMismatch.into()),
+    }
+}
+
+/// Reads the namespace `key` is routed to from its route account, or `None` if `key` has no route
+fn read_don_route(route: &UncheckedAccount, key: &[u8; 32]) -> Result<Option<u64>> {
+    require_keys_eq!(route.key(), DonRoute::address(key).0, errors::ErrorCode::InvalidInputs);
+    if route.owner != &ID {
+        return Ok(None);
+    }
+
+    let data = route.try_borrow_data()?;
+    let route_data = data
+        .get(8..8 + std::mem::size_of::<DonRoute>())
+        .filter(|_| data[..8] == *DonRoute::DISCRIMINATOR)
+        .ok_or(errors::ErrorCode::InvalidInputs)?;
+    let don_route: &DonRoute = bytemuck::from_bytes(route_data);
+    Ok(Some(don_route.namespace_id))
+}
+
+/// Counts the verification against the user's rate limit while rate limiting is enabled
+fn consume_rate_limit(accounts: &VerifyContext, verifier_account: &Ref<VerifierAccount>) -> Result<()> {
+    if verifier_account.rate_limit_enabled == 0 {
+        return Ok(());
+    }
+
+    let rate_limit = accounts
+        .rate_limit
+        .as_ref()
+        .ok_or(errors::ErrorCode::RateLimitAccountRequired)?;
+    let mut rate_limit = rate_limit.load_mut()?;
+
+    let limit = if rate_limit.has_limit_override != 0 {
+        rate_limit.limit_override
+    } else {
+        verifier_account.default_rate_limit
+    };
+
+    require!(
+        rate_limit.try_consume(&limit, Clock::get()?.slot),
+        errors::ErrorCode::RateLimitExceeded
+    );
+    Ok(())
+}
+
+/// Counts the verification in the requester's usage counter for `feed_id` while usage metering
+/// is enabled
+fn record_usage(
+    accounts: &VerifyContext,
+    verifier_account: &Ref<VerifierAccount>,
+    feed_id: &[u8; 32],
+) -> Result<()> {
+    if verifier_account.usage_metering_enabled == 0 {
+        return Ok(());
+    }
+
+    let usage_counter = accounts
+        .usage_counter
+        .as_ref()
+        .ok_or(errors::ErrorCode::UsageCounterRequired)?;
+    let mut usage_counter = usage_counter.load_mut()?;
+
+    require!(
+        usage_counter.requester == accounts.user.key() && usage_counter.feed_id == *feed_id,
+        errors::ErrorCode::InvalidInputs
+    );
+    usage_counter.verifications += 1;
+    Ok(())
+}
+
+/// Writes `data` at `offset` in the buffer, extending its length to the end of `data`, or setting
+/// it there when `truncate` is set
+fn write_report_buffer_data(
+    report_buffer: &AccountLoader<ReportBuffer>,
+    offset: usize,
+    data: &[u8],
+    truncate: bool,
+) -> Result<()> {
+    let capacity = report_buffer.load()?.capacity as usize;
+    let end = offset
+        .checked_add(data.len())
+        .filter(|end| *end <= capacity)
+        .ok_or(errors::ErrorCode::ReportBufferTooSmall)?;
+
+    // The header and the report bytes share the account data, so only one is borrowed at a time
+    report_buffer.as_ref().try_borrow_mut_data()?
+        [ReportBuffer::DATA_OFFSET + offset..ReportBuffer::DATA_OFFSET + end]
+        .copy_from_slice(data);
+
+    let mut header = report_buffer.load_mut()?;
+    header.len = if truncate { end } else { end.max(header.len as usize) } as u32;
+    Ok(())
+}
+
+/// Writes the report's receipt when the receipt account is given, creating it on first use
+fn write_receipt(
+    accounts: &VerifyContext,
+    report: &Report,
+    report_data: &[u8],
+    don_config: &DonConfig,
+) -> Result<()> {
+    let Some(receipt) = &accounts.receipt else {
+        return Ok(());
+    };
+
+    let (expected_receipt, bump) = VerificationReceipt::address(report.feed_id, report.report_timestamp);
+    require_keys_eq!(receipt.key(), expected_receipt, errors::ErrorCode::InvalidInputs);
+
+    let space = 8 + std::mem::size_of::<VerificationReceipt>();
+    let created = receipt.owner != &ID;
+    if created {
+        let system_program = accounts
+            .system_program
+            .as_ref()
+            .ok_or(errors::ErrorCode::SystemProgramRequired)?;
+        access_controller::create_pda_account(
+            &accounts.sol_payer()?,
+            &receipt.to_account_info(),
+            &system_program.to_account_info(),
+            space,
+            &ID,
+            &[
+                RECEIPT_SEED,
+                report.feed_id,
+                &report.report_timestamp.to_le_bytes(),
+                &[bump],
+            ],
+        )?;
+    }
+
+    let mut data = receipt.try_borrow_mut_data()?;
+    let (discriminator, receipt_data) = data.split_at_mut(8);
+    if created {
+        discriminator.copy_from_slice(VerificationReceipt::DISCRIMINATOR);
+    } else {
+        require!(
+            discriminator == VerificationReceipt::DISCRIMINATOR,
+            errors::ErrorCode::InvalidInputs
+        );
+    }
+
+    let receipt_data: &mut VerificationReceipt = bytemuck::from_bytes_mut(&mut receipt_data[..space - 8]);
+    if created {
+        receipt_data.feed_id = *report.feed_id;
+        receipt_data.observations_timestamp = report.report_timestamp;
+        receipt_data.requester = accounts.user.key();
+        receipt_data.payer = accounts.sol_payer()?.key();
+    }
+    receipt_data.report_hash = keccak256(report_data).to_bytes();
+    receipt_data.don_config_id = don_config.don_config_id;
+    receipt_data.slot = Clock::get()?.slot;
+    Ok(())
+}
+
+/// Computes the fee for the report in the requester's fee asset after their discount, or `None`
+/// while fees are disabled or waived for the requester
+fn compute_fee_quote(
+    verifier_account: &VerifierAccount,
+    requester: &Pubkey,
+    feed_id: &[u8; 32],
+    fee_preference: Option<&AccountLoader<FeePreference>>,
+    fee_token_mint: Option<&InterfaceAccount<Mint>>,
+    subscriber_discount: Option<&AccountLoader<SubscriberDiscount>>,
+    report_data: &[u8],
+) -> Result<Option<FeeQuote>> {
+    let token_fee_enabled = verifier_account.fee_enabled != 0;
+    let native_fee_enabled = verifier_account.native_fee_enabled != 0;
+    if !token_fee_enabled && !native_fee_enabled {
+        return Ok(None);
+    }
+
+    let discount_bps = match subscriber_discount {
+        Some(subscriber_discount) => {
+            let subscriber_discount = subscriber_discount.load()?;
+            require!(
+                subscriber_discount.applies_to(requester, feed_id),
+                errors::ErrorCode::SubscriberDiscountMismatch
+            );
+            if subscriber_discount.waived != 0 {
+                return Ok(None);
+            }
+            subscriber_discount.discount_bps
+        }
+        None => 0,
+    };
+
+    let asset = match fee_preference {
+        Some(fee_preference) => fee_preference.load()?.asset(),
+        None if token_fee_enabled => FeeAsset::Token,
+        None => FeeAsset::Native,
+    };
+
+    let fees = Encoder::parse_report_fees(report_data)?;
+    let quote = match asset {
+        FeeAsset::Token => {
+            require!(token_fee_enabled, errors::ErrorCode::FeeAssetNotAccepted);
+            let fee_token_mint = fee_token_mint.ok_or(errors::ErrorCode::FeeAccountsRequired)?;
+            require!(
+                fee_token_mint.key() == verifier_account.fee_config.token_mint,
+                errors::ErrorCode::InvalidInputs
+            );
+            let base_fee = FeeConfig::scale_report_fee(fees.link_fee, fee_token_mint.decimals)
+                .ok_or(errors::ErrorCode::BadVerification)?;
+            let discount = FeeConfig::discount(base_fee, discount_bps);
+            FeeQuote {
+                asset,
+                fee_asset: verifier_account.fee_config.token_mint,
+                base_fee,
+                surcharge: 0,
+                discount,
+                amount: base_fee - discount,
+            }
+        }
+        FeeAsset::Native => {
+            require!(native_fee_enabled, errors::ErrorCode::FeeAssetNotAccepted);
+            let base_fee = FeeConfig::scale_report_fee(fees.native_fee, NATIVE_DECIMALS)
+                .ok_or(errors::ErrorCode::BadVerification)?;
+            let total = FeeConfig::apply_surcharge(base_fee, verifier_account.native_surcharge_bps)
+                .ok_or(errors::ErrorCode::BadVerification)?;
+            let discount = FeeConfig::discount(total, discount_bps);
+            FeeQuote {
+                asset,
+                fee_asset: system_program::ID,
+                base_fee,
+                surcharge: total - base_fee,
+                discount,
+                amount: total - discount,
+            }
+        }
+    };
+    Ok(Some(quote))
+}
+
+/// Charges the report's fee in the requester's fee asset while fees are enabled, crediting it to
+/// the reward pool of `config_digest`
+fn charge_fee(
+    accounts: &VerifyContext,
+    verifier_account: &Ref<VerifierAccount>,
+    report_data: &[u8],
+    config_digest: &[u8; 32],
+    feed_id: &[u8; 32],
+) -> Result<()> {
+    let quote = compute_fee_quote(
+        verifier_account,
+        &accounts.user.key(),
+        feed_id,
+        accounts.fee_preference.as_ref(),
+        accounts.fee_token_mint.as_deref(),
+        accounts.subscriber_discount.as_ref(),
+        report_data,
+    )?;
+    let Some(FeeQuote { asset, fee_asset, amount, .. }) = quote else {
+        return Ok(());
+    };
+    if amount == 0 {
+        return Ok(());
+    }
+
+    match asset {
+        FeeAsset::Token => transfer_token_fee(accounts, verifier_account, amount)?,
+        FeeAsset::Native => transfer_native_fee(accounts, amount)?,
+    }
+
+    let reward_pool = accounts
+        .reward_pool
+        .as_ref()
+        .ok_or(errors::ErrorCode::RewardPoolRequired)?;
+    let mut reward_pool = reward_pool.load_mut()?;
+    require!(
+        reward_pool.config_digest == *config_digest,
+        errors::ErrorCode::InvalidInputs
+    );
+    reward_pool.add_fee(asset, amount);
+
+    emit!(FeePaid {
+        requester: accounts.user.key(),
+        feed_id: *feed_id,
+        fee_asset,
+        amount,
+    });
+    Ok(())
+}
+
+/// Transfers `amount` from the requester's token account to the fee vault
+fn transfer_token_fee(
+    accounts: &VerifyContext,
+    verifier_account: &Ref<VerifierAccount>,
+    amount: u64,
+) -> Result<()> {
+    let (Some(fee_token_mint), Some(requester_token_account), Some(fee_token_vault), Some(token_program)) = (
+        &accounts.fee_token_mint,
+        &accounts.requester_token_account,
+        &accounts.fee_token_vault,
+        &accounts.token_program,
+    ) else {
+        return Err(errors::ErrorCode::FeeAccountsRequired.into());
+    };
+
+    require!(
+        fee_token_vault.key() == verifier_account.fee_config.token_vault,
+        errors::ErrorCode::InvalidInputs
+    );
+
+    transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: requester_token_account.to_account_info(),
+                mint: fee_token_mint.to_account_info(),
+                to: fee_token_vault.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+        fee_token_mint.decimals,
+    )
+}
+
+/// Transfers `amount` from the requester to the native fee vault
+fn transfer_native_fee(accounts: &VerifyContext, amount: u64) -> Result<()> {
+    let (Some(native_fee_vault), Some(system_program)) =
+        (&accounts.native_fee_vault, &accounts.system_program)
+    else {
+        return Err(errors::ErrorCode::FeeAccountsRequired.into());
+    };
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: accounts.sol_payer()?,
+                to: native_fee_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Transfers `amount` from the fee vault to the payee's token account
+fn pay_token_reward<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, ClaimRewardsContext<'info>>,
+    payee_account: &'info AccountInfo<'info>,
+    payee: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (Some(fee_token_mint), Some(fee_token_vault), Some(token_program)) = (
+        &accounts.fee_token_mint,
+        &accounts.fee_token_vault,
+        &accounts.token_program,
+    ) else {
+        return Err(errors::ErrorCode::FeeAccountsRequired.into());
+    };
+
+    let fee_config = accounts.verifier_account.load()?.fee_config;
+    require!(
+        fee_token_mint.key() == fee_config.token_mint && fee_token_vault.key() == fee_config.token_vault,
+        errors::ErrorCode::InvalidInputs
+    );
+
+    let payee_token_account = InterfaceAccount::<TokenAccount>::try_from(payee_account)?;
+    require!(
+        payee_token_account.owner == *payee && payee_token_account.mint == fee_config.token_mint,
+        errors::ErrorCode::InvalidInputs
+    );
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: fee_token_vault.to_account_info(),
+                mint: fee_token_mint.to_account_info(),
+                to: payee_account.clone(),
+                authority: accounts.verifier_account.to_account_info(),
+            },
+            &[&[b"verifier", &[ctx.bumps.verifier_account]]],
+        ),
+        amount,
+        fee_token_mint.decimals,
+    )
+}
+
+/// Transfers `amount` from the native fee vault to the payee
+fn pay_native_reward<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, ClaimRewardsContext<'info>>,
+    payee_account: &'info AccountInfo<'info>,
+    payee: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (Some(native_fee_vault), Some(system_program), Some(native_fee_vault_bump)) = (
+        &accounts.native_fee_vault,
+        &accounts.system_program,
+        ctx.bumps.native_fee_vault,
+    ) else {
+        return Err(errors::ErrorCode::FeeAccountsRequired.into());
+    };
+
+    require!(payee_account.key() == *payee, errors::ErrorCode::InvalidInputs);
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: native_fee_vault.to_account_info(),
+                to: payee_account.clone(),
+            },
+            &[&[NATIVE_FEE_VAULT_SEED, &[native_fee_vault_bump]]],
+        ),
+        amount,
+    )
+}
+
+fn don_config_details(don_config_index: usize, don_config: &DonConfig) -> DonConfigDetails {
+    DonConfigDetails {
+        don_config_index: don_config_index as u16,
+        don_config_id: don_config.don_config_id,
+        f: don_config.f,
+        is_active: don_config.is_active != 0,
+        activation_time: don_config.activation_time,
+        signers: don_config.signers.iter().map(|s| s.key).collect(),
+    }
+}
+
+/// Recovers the signer addresses from the signatures over `hash`, rejecting malformed or
+/// duplicate signatures
+fn recover_signers(
+    hash: &[u8; 32],
+    rs: &[[u8; 32]],
+    ss: &[[u8; 32]],
+    raw_vs: &[u8; 32],
+) -> Result<Vec<[u8; 20]>> {
     // Validate signature lengths
     require!(
         rs.len() == ss.len(),
@@ -271,18 +1498,11 @@ fn verify_report(
         errors::ErrorCode::NoSigners
     );
 
-    let signed_payload_hash = keccak256(&[
-        &keccak256(report_data).to_bytes()[..],
-        &report_context[0][..],
-        &report_context[1][..],
-        &report_context[2][..]
-    ].concat());
-
     // Recover signer addresses from signatures
     let mut signers = Vec::with_capacity(rs.len());
     for i in 0..rs.len() {
         let addr = ecrecover(
-            &signed_payload_hash.to_bytes(),
+            hash,
             &rs[i],
             &ss[i],
             raw_vs[i],
@@ -300,31 +1520,19 @@ fn verify_report(
         errors::ErrorCode::BadVerification
     );
 
-    // Parse report details from report_data
-    let report = Encoder::parse_report_details_from_report(report_data)?;
-
-    // Find the active DON configuration based on the report timestamp
-    let active_don_config = verifier_account
-        .don_configs
-        .iter()
-        .rev()
-        .find(|config| config.activation_time <= report.report_timestamp)
-        .ok_or(errors::ErrorCode::BadVerification)?;
-
-    // Ensure the active DON is indeed active
-    require!(
-        active_don_config.is_active != 0,
-        errors::ErrorCode::ConfigDeactivated
-    );
+    Ok(signers)
+}
 
+/// Checks that more than `f` signers signed and that each of them is registered in `don_config`
+fn check_signers(signers: &[[u8; 20]], don_config: &DonConfig) -> Result<()> {
     // Verify that the number of signers exceeds the threshold 'f'
     require!(
-        signers.len() > active_don_config.f as usize,
+        signers.len() > don_config.f as usize,
         errors::ErrorCode::BadVerification
     );
 
     // Get registered signers
-    let registered_signers: Vec<&[u8; 20]> = active_don_config
+    let registered_signers: Vec<&[u8; 20]> = don_config
         .signers
         .iter()
         .map(|s| &s.key)
@@ -337,10 +1545,155 @@ fn verify_report(
         }
     }
 
-    emit!(ReportVerified {
-        feed_id: *report.feed_id,
-        requester: ctx.accounts.user.key(),
+    Ok(())
+}
+
+/// Validates and appends a new DON config, emitting `ConfigSet`
+fn add_don_config(
+    verifier_account: &mut VerifierAccount,
+    signers: Vec<[u8; 20]>,
+    f: u8,
+    activation_time: u32,
+    expected_don_config_id: Option<[u8; 24]>,
+) -> Result<()> {
+    let (don_config, sorted_signers) = new_don_config(
+        &verifier_account.don_configs,
+        verifier_account.don_configs.capacity(),
+        signers,
+        f,
+        activation_time,
+        expected_don_config_id,
+    )?;
+    verifier_account.don_configs.push(don_config);
+
+    emit!(ConfigSet {
+        don_config_id: hex_encode(don_config.don_config_id),
+        signers: sorted_signers,
+        f,
+        don_config_index: (verifier_account.don_configs.len() - 1) as u16,
     });
 
-    Ok(report_data.to_vec())
-}
\ No newline at end of file
+    Ok(())
+}
+
+fn add_namespace_don_config(
+    don_namespace: &mut DonNamespace,
+    signers: Vec<[u8; 20]>,
+    f: u8,
+    activation_time: u32,
+    expected_don_config_id: Option<[u8; 24]>,
+) -> Result<()> {
+    let (don_config, sorted_signers) = new_don_config(
+        &don_namespace.don_configs,
+        don_namespace.don_configs.capacity(),
+        signers,
+        f,
+        activation_time,
+        expected_don_config_id,
+    )?;
+    don_namespace.don_configs.push(don_config);
+
+    emit!(DonNamespaceConfigSet {
+        namespace_id: don_namespace.namespace_id,
+        don_config_id: hex_encode(don_config.don_config_id),
+        signers: sorted_signers,
+        f,
+        don_config_index: (don_namespace.don_configs.len() - 1) as u16,
+    });
+
+    Ok(())
+}
+
+/// Validates a new DON config to follow `don_configs`, which hold up to `capacity` configs, and
+/// returns it with its sorted signers
+fn new_don_config(
+    don_configs: &[DonConfig],
+    capacity: usize,
+    signers: Vec<[u8; 20]>,
+    f: u8,
+    activation_time: u32,
+    expected_don_config_id: Option<[u8; 24]>,
+) -> Result<(DonConfig, Vec<[u8; 20]>)> {
+    require!(f > 0, errors::ErrorCode::FaultToleranceMustBePositive);
+
+    require!(
+        signers.len() > 3 * f as usize,
+        errors::ErrorCode::InsufficientSigners
+    );
+
+    require!(
+        signers.len() <= MAX_NUMBER_OF_ORACLES as usize,
+        errors::ErrorCode::ExcessSigners
+    );
+
+    // Check that activationTime is not in the future.
+    require!(
+        activation_time <= Clock::get()?.unix_timestamp as u32,
+        errors::ErrorCode::BadActivationTime
+    );
+
+    // Check we haven't reached the max number of configs
+    require!(
+        don_configs.len() < capacity,
+        errors::ErrorCode::MaxNumberOfConfigsReached
+    );
+
+    // Sort signers to ensure donConfigId is deterministic.
+    let mut sorted_signers = signers;
+    sorted_signers.sort_unstable();
+
+    // Check for duplicate addresses in signers.
+    require!(
+        !SliceUtil::has_duplicates_sorted(&sorted_signers),
+        errors::ErrorCode::NonUniqueSignatures
+    );
+
+    let don_config_id = Encoder::compute_don_config_id(&Encoder::encode_don_config_id(&sorted_signers, f));
+
+    // Guard against setting a config other than the one the caller computed
+    if let Some(expected_don_config_id) = expected_don_config_id {
+        require!(
+            expected_don_config_id == don_config_id,
+            errors::ErrorCode::DonConfigIdMismatch
+        );
+    }
+
+    // Check if there are any existing configs
+    if let Some(last_don_config) = don_configs.last() {
+        // Check the config we're setting isn't already set as the current active config as this will increase search costs unnecessarily when verifying historic reports
+        require!(
+            last_don_config.don_config_id != don_config_id,
+            errors::ErrorCode::DonConfigAlreadyExists
+        );
+
+        // Check that activation time is after the last config
+        require!(
+            last_don_config.activation_time < activation_time,
+            errors::ErrorCode::BadActivationTime
+        );
+    }
+
+    // Register the signers for this DON
+    let mut signers_array = SigningKeys::default();
+    for signer in sorted_signers.iter() {
+        require!(
+            !is_zero_address(signer),
+            errors::ErrorCode::ZeroAddress
+        );
+
+        signers_array.push(SigningKey {
+            key: *signer,
+        });
+    }
+
+    let don_config = DonConfig {
+        don_config_id,
+        f,
+        is_active: 1,
+        activation_time,
+        _padding: 0,
+        signers: signers_array,
+    };
+
+    Ok((don_config, sorted_signers))
+}
@@ -0,0 +1,38 @@
+use crate::state::DonConfig;
+use anchor_lang::prelude::*;
+use arrayvec::arrayvec;
+
+pub const DON_NAMESPACE_SEED: &[u8] = b"don_namespace";
+pub const DON_ROUTE_SEED: &[u8] = b"don_route";
+pub const MAX_NUMBER_OF_NAMESPACE_DON_CONFIGS: usize = 8;
+
+#[zero_copy]
+pub struct NamespaceDonConfigs {
+    pub len: u16,
+    pub padding: [u8; 6],
+    pub xs: [DonConfig; MAX_NUMBER_OF_NAMESPACE_DON_CONFIGS],
+}
+arrayvec!(NamespaceDonConfigs, DonConfig, u16);
+
+/// The config history of a DON verifying alongside the one of the verifier account, derived from
+/// `[DON_NAMESPACE_SEED, namespace_id]`
+#[account(zero_copy)]
+pub struct DonNamespace {
+    pub namespace_id: u64,
+    pub don_configs: NamespaceDonConfigs,
+}
+
+/// Routes reports with a config digest or feed id to a DON namespace, derived from
+/// `[DON_ROUTE_SEED, key]`
+#[account(zero_copy)]
+pub struct DonRoute {
+    // The config digest or feed id of the routed reports
+    pub key: [u8; 32],
+    pub namespace_id: u64,
+}
+
+impl DonRoute {
+    pub fn address(key: &[u8; 32]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[DON_ROUTE_SEED, key], &crate::ID)
+    }
+}
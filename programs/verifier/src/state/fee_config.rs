@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+pub const NATIVE_FEE_VAULT_SEED: &[u8] = b"native_fee_vault";
+
+// Report fees are denominated with 18 decimals
+pub const REPORT_FEE_DECIMALS: u8 = 18;
+pub const NATIVE_DECIMALS: u8 = 9;
+pub const BASIS_POINTS: u64 = 10_000;
+
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct FeeConfig {
+    // The SPL token mint fees are paid in
+    pub token_mint: Pubkey,
+    // The verifier's token account collecting fees, derived from `[FEE_VAULT_SEED, token_mint]`
+    pub token_vault: Pubkey,
+}
+
+impl FeeConfig {
+    /// Converts a report fee to base units of a mint with `decimals`, rounding down.
+    /// Returns `None` if the amount does not fit in a token amount.
+    pub fn scale_report_fee(fee: u128, decimals: u8) -> Option<u64> {
+        let scaled = if decimals <= REPORT_FEE_DECIMALS {
+            fee / 10u128.checked_pow((REPORT_FEE_DECIMALS - decimals) as u32)?
+        } else {
+            fee.checked_mul(10u128.checked_pow((decimals - REPORT_FEE_DECIMALS) as u32)?)?
+        };
+        u64::try_from(scaled).ok()
+    }
+
+    /// Adds a surcharge of `surcharge_bps` basis points to `amount`, rounding down.
+    /// Returns `None` on overflow.
+    pub fn apply_surcharge(amount: u64, surcharge_bps: u16) -> Option<u64> {
+        let total = amount as u128 * (BASIS_POINTS + surcharge_bps as u64) as u128 / BASIS_POINTS as u128;
+        u64::try_from(total).ok()
+    }
+
+    /// Returns the discount of `discount_bps` basis points on `amount`, rounding down
+    pub fn discount(amount: u64, discount_bps: u16) -> u64 {
+        (amount as u128 * discount_bps as u128 / BASIS_POINTS as u128) as u64
+    }
+}
@@ -0,0 +1,37 @@
+use super::FeeConfig;
+
+#[test]
+fn test_scale_report_fee() {
+    // 0.0252345313111642 LINK
+    let fee = 25_234_531_311_164_200;
+
+    assert_eq!(FeeConfig::scale_report_fee(fee, 18), Some(25_234_531_311_164_200));
+    assert_eq!(FeeConfig::scale_report_fee(fee, 9), Some(25_234_531));
+    assert_eq!(FeeConfig::scale_report_fee(fee, 0), Some(0));
+    assert_eq!(FeeConfig::scale_report_fee(1, 20), Some(100));
+}
+
+#[test]
+fn test_apply_surcharge() {
+    assert_eq!(FeeConfig::apply_surcharge(1_000, 0), Some(1_000));
+    assert_eq!(FeeConfig::apply_surcharge(1_000, 250), Some(1_025));
+    assert_eq!(FeeConfig::apply_surcharge(1_000, 10_000), Some(2_000));
+    assert_eq!(FeeConfig::apply_surcharge(3, 5_000), Some(4));
+    assert_eq!(FeeConfig::apply_surcharge(u64::MAX, 1), None);
+}
+
+#[test]
+fn test_discount() {
+    assert_eq!(FeeConfig::discount(1_000, 0), 0);
+    assert_eq!(FeeConfig::discount(1_000, 2_500), 250);
+    assert_eq!(FeeConfig::discount(1_000, 10_000), 1_000);
+    assert_eq!(FeeConfig::discount(3, 5_000), 1);
+    assert_eq!(FeeConfig::discount(u64::MAX, 10_000), u64::MAX);
+}
+
+#[test]
+fn test_scale_report_fee_overflow() {
+    assert_eq!(FeeConfig::scale_report_fee(u128::MAX, 18), None);
+    assert_eq!(FeeConfig::scale_report_fee(u64::MAX as u128, 19), None);
+    assert_eq!(FeeConfig::scale_report_fee(1, 60), None);
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+pub const FEE_PREFERENCE_SEED: &[u8] = b"fee_preference";
+
+/// The asset a requester pays verification fees in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeAsset {
+    /// The configured SPL token, charged the report's `link_fee`
+    Token,
+    /// Native SOL, charged the report's `native_fee` plus the surcharge
+    Native,
+}
+
+/// A requester's choice of fee asset, derived from `[FEE_PREFERENCE_SEED, requester]`
+#[account(zero_copy)]
+pub struct FeePreference {
+    pub requester: Pubkey,
+    // `FeeAsset`, token by default
+    pub asset: u8,
+    // Add padding to ensure 8-byte alignment
+    pub padding: [u8; 7],
+}
+
+impl FeePreference {
+    pub fn asset(&self) -> FeeAsset {
+        match self.asset {
+            0 => FeeAsset::Token,
+            _ => FeeAsset::Native,
+        }
+    }
+}
@@ -1,3 +1,28 @@
+mod don_namespace;
+mod fee_config;
+mod fee_preference;
+mod report_buffer;
+mod reward_pool;
+mod subscriber_discount;
+mod usage_counter;
+mod verification_receipt;
+mod user_rate_limit;
 mod verifier_account;
 
-pub use verifier_account::*;
\ No newline at end of file
+pub use don_namespace::*;
+pub use fee_config::*;
+pub use fee_preference::*;
+pub use report_buffer::*;
+pub use reward_pool::*;
+pub use subscriber_discount::*;
+pub use usage_counter::*;
+pub use user_rate_limit::*;
+pub use verifier_account::*;
+pub use verification_receipt::*;
+
+#[cfg(test)]
+mod fee_config_test;
+#[cfg(test)]
+mod reward_pool_test;
+#[cfg(test)]
+mod user_rate_limit_test;
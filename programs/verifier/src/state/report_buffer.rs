@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+pub const REPORT_BUFFER_SEED: &[u8] = b"report_buffer";
+
+/// A report staged across transactions, derived from
+/// `[REPORT_BUFFER_SEED, authority, buffer_id as little endian bytes]`. The report bytes follow
+/// this header up to the end of the account.
+#[account(zero_copy)]
+pub struct ReportBuffer {
+    // The only address that may write to or close the buffer
+    pub authority: Pubkey,
+    pub buffer_id: u64,
+    // Number of bytes written, from the start of the data
+    pub len: u32,
+    // Number of bytes the buffer can hold
+    pub capacity: u32,
+}
+
+impl ReportBuffer {
+    /// Offset of the report bytes in the account data, after the discriminator and header
+    pub const DATA_OFFSET: usize = 8 + std::mem::size_of::<ReportBuffer>();
+}
@@ -0,0 +1,77 @@
+use crate::common::MAX_NUMBER_OF_ORACLES;
+use crate::state::FeeAsset;
+use anchor_lang::prelude::*;
+use arrayvec::arrayvec;
+
+pub const REWARD_POOL_SEED: &[u8] = b"reward_pool";
+
+// Recipient weights must add up to this, as in the EVM RewardManager
+pub const TOTAL_REWARD_WEIGHT: u64 = 1_000_000_000_000_000_000;
+
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct RewardRecipient {
+    // Receives the rewards
+    pub payee: Pubkey,
+    // The DON signer the payee is paid for
+    pub signer: [u8; 20],
+    // Add padding to ensure 8-byte alignment
+    pub padding: [u8; 4],
+    // Share of the pool's fees, out of `TOTAL_REWARD_WEIGHT`
+    pub weight: u64,
+    // The pool's token fees when the recipient was last paid
+    pub token_fees_claimed_to: u64,
+    // The pool's native fees when the recipient was last paid
+    pub native_fees_claimed_to: u64,
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct RewardRecipients {
+    pub xs: [RewardRecipient; MAX_NUMBER_OF_ORACLES as usize],
+    pub len: u64,
+}
+arrayvec!(RewardRecipients, RewardRecipient, u64);
+
+/// Fees collected for reports of a config digest, derived from `[REWARD_POOL_SEED, config_digest]`
+#[account(zero_copy)]
+pub struct RewardPool {
+    pub config_digest: [u8; 32],
+    // Fees collected over the pool's lifetime
+    pub total_token_fees: u64,
+    pub total_native_fees: u64,
+    pub recipients: RewardRecipients,
+}
+
+impl RewardPool {
+    pub fn add_fee(&mut self, asset: FeeAsset, amount: u64) {
+        match asset {
+            FeeAsset::Token => self.total_token_fees += amount,
+            FeeAsset::Native => self.total_native_fees += amount,
+        }
+    }
+
+    /// Whether every recipient has been paid for all fees collected so far
+    pub fn is_settled(&self) -> bool {
+        self.recipients.iter().all(|recipient| {
+            recipient.token_fees_claimed_to == self.total_token_fees
+                && recipient.native_fees_claimed_to == self.total_native_fees
+        })
+    }
+
+    /// Marks the share of the recipient at `index` in fees collected since their last claim as
+    /// paid, returning the amount to pay them
+    pub fn claim(&mut self, index: usize, asset: FeeAsset) -> u64 {
+        let total_fees = match asset {
+            FeeAsset::Token => self.total_token_fees,
+            FeeAsset::Native => self.total_native_fees,
+        };
+        let recipient = &mut self.recipients[index];
+        let claimed_to = match asset {
+            FeeAsset::Token => &mut recipient.token_fees_claimed_to,
+            FeeAsset::Native => &mut recipient.native_fees_claimed_to,
+        };
+        let unclaimed = total_fees - std::mem::replace(claimed_to, total_fees);
+        (unclaimed as u128 * recipient.weight as u128 / TOTAL_REWARD_WEIGHT as u128) as u64
+    }
+}
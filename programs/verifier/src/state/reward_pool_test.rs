@@ -0,0 +1,64 @@
+use super::{FeeAsset, RewardPool, RewardRecipient, RewardRecipients, TOTAL_REWARD_WEIGHT};
+use anchor_lang::prelude::Pubkey;
+
+fn new_reward_pool(weights: &[u64]) -> RewardPool {
+    let mut recipients = RewardRecipients::default();
+    for weight in weights {
+        recipients.push(RewardRecipient {
+            payee: Pubkey::new_unique(),
+            weight: *weight,
+            ..Default::default()
+        });
+    }
+    RewardPool {
+        config_digest: [1; 32],
+        total_token_fees: 0,
+        total_native_fees: 0,
+        recipients,
+    }
+}
+
+#[test]
+fn test_claim_pro_rata() {
+    let mut pool = new_reward_pool(&[TOTAL_REWARD_WEIGHT / 4, TOTAL_REWARD_WEIGHT / 4 * 3]);
+
+    pool.add_fee(FeeAsset::Token, 1_000);
+    assert_eq!(pool.claim(0, FeeAsset::Token), 250);
+
+    // Only fees collected since the last claim are paid
+    pool.add_fee(FeeAsset::Token, 1_000);
+    assert_eq!(pool.claim(0, FeeAsset::Token), 250);
+    assert_eq!(pool.claim(0, FeeAsset::Token), 0);
+    assert_eq!(pool.claim(1, FeeAsset::Token), 1_500);
+
+    // Assets are accounted separately
+    assert_eq!(pool.claim(1, FeeAsset::Native), 0);
+    pool.add_fee(FeeAsset::Native, 400);
+    assert_eq!(pool.claim(1, FeeAsset::Native), 300);
+    assert_eq!(pool.claim(1, FeeAsset::Token), 0);
+}
+
+#[test]
+fn test_claim_rounds_down() {
+    let mut pool = new_reward_pool(&[TOTAL_REWARD_WEIGHT / 3, TOTAL_REWARD_WEIGHT - TOTAL_REWARD_WEIGHT / 3]);
+
+    pool.add_fee(FeeAsset::Native, 100);
+    assert_eq!(pool.claim(0, FeeAsset::Native), 33);
+    assert_eq!(pool.claim(1, FeeAsset::Native), 66);
+}
+
+#[test]
+fn test_is_settled() {
+    let mut pool = new_reward_pool(&[TOTAL_REWARD_WEIGHT / 2, TOTAL_REWARD_WEIGHT / 2]);
+    assert!(pool.is_settled());
+
+    pool.add_fee(FeeAsset::Token, 100);
+    pool.add_fee(FeeAsset::Native, 100);
+    pool.claim(0, FeeAsset::Token);
+    pool.claim(0, FeeAsset::Native);
+    pool.claim(1, FeeAsset::Token);
+    assert!(!pool.is_settled());
+
+    pool.claim(1, FeeAsset::Native);
+    assert!(pool.is_settled());
+}
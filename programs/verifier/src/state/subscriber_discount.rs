@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+pub const SUBSCRIBER_DISCOUNT_SEED: &[u8] = b"subscriber_discount";
+// The feed id of a subscriber's discount for all feeds
+pub const GLOBAL_DISCOUNT_FEED_ID: [u8; 32] = [0; 32];
+
+/// An owner-negotiated fee discount of a subscriber for a feed, or for all feeds with
+/// `GLOBAL_DISCOUNT_FEED_ID`, derived from `[SUBSCRIBER_DISCOUNT_SEED, subscriber, feed_id]`
+#[account(zero_copy)]
+pub struct SubscriberDiscount {
+    pub subscriber: Pubkey,
+    pub feed_id: [u8; 32],
+    // Discount on the fee in basis points
+    pub discount_bps: u16,
+    // Whether the subscriber pays no fees at all
+    pub waived: u8,
+    // Add padding to ensure 8-byte alignment
+    pub padding: [u8; 5],
+}
+
+impl SubscriberDiscount {
+    /// Whether the discount applies to verifications of `feed_id` by `subscriber`
+    pub fn applies_to(&self, subscriber: &Pubkey, feed_id: &[u8; 32]) -> bool {
+        self.subscriber == *subscriber
+            && (self.feed_id == *feed_id || self.feed_id == GLOBAL_DISCOUNT_FEED_ID)
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+pub const USAGE_COUNTER_SEED: &[u8] = b"usage";
+
+/// Verifications of a feed by a requester, derived from `[USAGE_COUNTER_SEED, requester, feed_id]`
+#[account(zero_copy)]
+pub struct UsageCounter {
+    pub requester: Pubkey,
+    pub feed_id: [u8; 32],
+    // Verifications since the last snapshot
+    pub verifications: u64,
+    // Verifications in the last snapshotted billing period
+    pub last_period_verifications: u64,
+    // The billing period of the last snapshot
+    pub last_billing_period: u64,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+pub const RATE_LIMIT_SEED: &[u8] = b"rate_limit";
+
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    // Verifications allowed per window, 0 for no limit
+    pub max_verifications: u64,
+    // Length of a window in slots
+    pub window_slots: u64,
+}
+
+/// Verification count of a user, derived from `[RATE_LIMIT_SEED, user]`
+#[account(zero_copy)]
+pub struct UserRateLimit {
+    pub user: Pubkey,
+    // The slot the current window started at
+    pub window_start_slot: u64,
+    // Verifications within the current window
+    pub verifications: u64,
+    // Overrides the default rate limit when set
+    pub limit_override: RateLimit,
+    pub has_limit_override: u8,
+    // Add padding to ensure 8-byte alignment
+    pub padding: [u8; 7],
+}
+
+impl UserRateLimit {
+    /// Counts a verification at `slot` against `limit`, starting a new window when the current
+    /// one has passed. Returns `false` if the limit is already reached.
+    pub fn try_consume(&mut self, limit: &RateLimit, slot: u64) -> bool {
+        if slot >= self.window_start_slot.saturating_add(limit.window_slots) {
+            self.window_start_slot = slot;
+            self.verifications = 0;
+        }
+        if limit.max_verifications != 0 && self.verifications >= limit.max_verifications {
+            return false;
+        }
+        self.verifications += 1;
+        true
+    }
+}
@@ -0,0 +1,39 @@
+use super::{RateLimit, UserRateLimit};
+use anchor_lang::prelude::Pubkey;
+
+fn new_user_rate_limit() -> UserRateLimit {
+    UserRateLimit {
+        user: Pubkey::new_unique(),
+        window_start_slot: 0,
+        verifications: 0,
+        limit_override: RateLimit::default(),
+        has_limit_override: 0,
+        padding: [0; 7],
+    }
+}
+
+#[test]
+fn test_try_consume_within_window() {
+    let limit = RateLimit { max_verifications: 2, window_slots: 10 };
+    let mut rate_limit = new_user_rate_limit();
+
+    assert!(rate_limit.try_consume(&limit, 100));
+    assert!(rate_limit.try_consume(&limit, 105));
+    assert!(!rate_limit.try_consume(&limit, 109));
+    assert_eq!(rate_limit.verifications, 2);
+
+    // A new window starts once the current one has passed
+    assert!(rate_limit.try_consume(&limit, 110));
+    assert_eq!(rate_limit.window_start_slot, 110);
+    assert_eq!(rate_limit.verifications, 1);
+}
+
+#[test]
+fn test_try_consume_without_limit() {
+    let limit = RateLimit { max_verifications: 0, window_slots: 10 };
+    let mut rate_limit = new_user_rate_limit();
+
+    for slot in 0..100 {
+        assert!(rate_limit.try_consume(&limit, slot));
+    }
+}
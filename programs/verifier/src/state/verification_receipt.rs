@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+pub const RECEIPT_SEED: &[u8] = b"receipt";
+
+/// Proof that a report was verified, derived from
+/// `[RECEIPT_SEED, feed_id, observations_timestamp as little endian bytes]`
+#[account(zero_copy)]
+pub struct VerificationReceipt {
+    pub feed_id: [u8; 32],
+    // keccak256 of the report data
+    pub report_hash: [u8; 32],
+    // The id of the DON config the report was verified against
+    pub don_config_id: [u8; 24],
+    pub observations_timestamp: u32,
+    // Add padding to ensure 8-byte alignment
+    pub padding: [u8; 4],
+    // The user whose verification created the receipt and who may close it
+    pub requester: Pubkey,
+    // The slot the report was last verified in
+    pub slot: u64,
+    // The account that paid the receipt's rent, which is returned to it on close
+    pub payer: Pubkey,
+}
+
+impl VerificationReceipt {
+    pub fn address(feed_id: &[u8; 32], observations_timestamp: u32) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[RECEIPT_SEED, feed_id, &observations_timestamp.to_le_bytes()],
+            &crate::ID,
+        )
+    }
+}
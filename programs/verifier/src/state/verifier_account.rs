@@ -1,9 +1,11 @@
 use std::borrow::Borrow;
 use crate::common::MAX_NUMBER_OF_ORACLES;
+use crate::state::{FeeConfig, RateLimit};
 use anchor_lang::prelude::*;
 use arrayvec::arrayvec;
 
 pub const MAX_NUMBER_OF_DON_CONFIGS: usize = 256; // NOTE: Must be ^2
+pub const MAX_NUMBER_OF_SCHEMA_VERSIONS: usize = 15;
 #[zero_copy]
 pub struct VerifierAccountConfig {
     // The contract admin account
@@ -58,6 +60,35 @@ pub struct DonConfigs {
 }
 arrayvec!(DonConfigs, DonConfig, u16);
 
+#[zero_copy]
+#[derive(Default)]
+pub struct SchemaVersions {
+    pub xs: [u16; MAX_NUMBER_OF_SCHEMA_VERSIONS],
+    pub len: u16,
+}
+arrayvec!(SchemaVersions, u16, u16);
+
+impl SchemaVersions {
+    /// Whether reports of `schema_version` are accepted. Every version is accepted while the list is empty.
+    pub fn accepts(&self, schema_version: u16) -> bool {
+        self.is_empty() || self.contains(&schema_version)
+    }
+}
+
+/// Lookups over a DON config history, ordered by activation time. Implemented for slices so the
+/// verifier account's history and those of DON namespaces share them.
+pub trait DonConfigHistory {
+    /// Returns the index of the config that applies at `timestamp`, i.e. the most recently set
+    /// config whose activation time is not after it.
+    fn find_index_for_timestamp(&self, timestamp: u32) -> Option<usize>;
+}
+
+impl DonConfigHistory for [DonConfig] {
+    fn find_index_for_timestamp(&self, timestamp: u32) -> Option<usize> {
+        self.iter().rposition(|config| config.activation_time <= timestamp)
+    }
+}
+
 #[account(zero_copy)]
 pub struct VerifierAccount {
     // Versioning for migrating state
@@ -67,7 +98,38 @@ pub struct VerifierAccount {
     // Account configuration
     pub verifier_account_config: VerifierAccountConfig,
     // The list of DON configurations to lookup when verifying a report
-    pub don_configs: DonConfigs
+    pub don_configs: DonConfigs,
+    // Whether the active DON may set its successor config via `rotate_config`
+    pub config_rotation_enabled: u8,
+    // Add padding to ensure 8-byte alignment
+    pub config_rotation_padding: [u8; 3],
+    // Signed over by rotations and incremented by each, so their signatures cannot be replayed
+    pub config_rotation_nonce: u32,
+    // Whether `verify` counts verifications in the user's rate limit account
+    pub rate_limit_enabled: u8,
+    // Add padding to ensure 8-byte alignment
+    pub rate_limit_padding: [u8; 7],
+    // Applies to users without their own rate limit
+    pub default_rate_limit: RateLimit,
+    // Whether `verify` counts verifications in the requester's usage counter for the feed
+    pub usage_metering_enabled: u8,
+    // Add padding to ensure 8-byte alignment
+    pub usage_metering_padding: [u8; 7],
+    // Whether `verify` charges the report's fee in the configured token
+    pub fee_enabled: u8,
+    // Add padding to ensure 8-byte alignment
+    pub fee_padding: [u8; 7],
+    pub fee_config: FeeConfig,
+    // Whether requesters may pay fees in native SOL
+    pub native_fee_enabled: u8,
+    // Add padding to ensure 8-byte alignment
+    pub native_fee_padding: [u8; 5],
+    // Added to native fees, in basis points
+    pub native_surcharge_bps: u16,
+    // Report schema versions `verify` accepts, any version while empty
+    pub accepted_schema_versions: SchemaVersions,
+    // Number of DON routes. While non-zero `verify` must be given the routes of the report.
+    pub don_route_count: u64,
 }
 
 impl VerifierAccount {
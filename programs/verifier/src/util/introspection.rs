@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+pub struct Introspection {}
+
+impl Introspection {
+    /// Returns the program that invoked this one through CPI, read from the instructions sysvar.
+    /// Only a caller at the top level of the transaction can be identified, so `None` is returned
+    /// for direct calls and for deeper CPI chains.
+    pub fn calling_program(instructions_sysvar: &AccountInfo) -> Result<Option<Pubkey>> {
+        if get_stack_height() != TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+            return Ok(None);
+        }
+
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        let instruction = load_instruction_at_checked(current_index as usize, instructions_sysvar)?;
+        Ok(Some(instruction.program_id))
+    }
+}
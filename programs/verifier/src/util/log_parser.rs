@@ -4,13 +4,23 @@ use anchor_lang::__private::base64;
 pub struct LogParser {}
 
 impl LogParser {
+    /// Decodes the first event of type `E` in `logs`
     pub fn parse_logs<E: AnchorDeserialize + Discriminator>(
         logs: Vec<String>,
     ) -> Option<E> {
+        logs.iter().find_map(|log| Self::parse_log(log))
+    }
+
+    /// Decodes every event of type `E` in `logs`, in the order they were emitted
+    pub fn parse_all_logs<E: AnchorDeserialize + Discriminator>(
+        logs: Vec<String>,
+    ) -> Vec<E> {
+        logs.iter().filter_map(|log| Self::parse_log(log)).collect()
+    }
 
-        let data_log = logs.iter().find(|log| log.starts_with("Program data: "))?;
+    fn parse_log<E: AnchorDeserialize + Discriminator>(log: &str) -> Option<E> {
 
-        let data = data_log.strip_prefix("Program data: ")?;
+        let data = log.strip_prefix("Program data: ")?;
 
         const DISCRIMINATOR_SIZE: usize = 8;
 
@@ -17,3 +17,37 @@ fn test_log_parser() {
 
     assert_eq!(logs.access_controller, Pubkey::from_str("1111111ogCyDbaRMvkdsHB3qfdyFYaG1WtRUAfdh").unwrap(), "Unexpected access controller");
 }
+
+fn program_data_log(event: &impl anchor_lang::Event) -> String {
+    format!("Program data: {}", anchor_lang::__private::base64::encode(event.data()))
+}
+
+#[test]
+fn test_log_parser_decodes_access_controller_events() {
+    let access_controller = Pubkey::new_unique();
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+
+    let program_logs = vec![
+        "Program log: Instruction: BatchAddAccess".to_string(),
+        program_data_log(&access_controller::OwnershipTransferRequested {
+            access_controller,
+            from: first,
+            to: second,
+        }),
+        program_data_log(&access_controller::AccessAdded { access_controller, address: first }),
+        program_data_log(&access_controller::AccessAdded { access_controller, address: second }),
+    ];
+
+    // Other events before the requested one are skipped
+    let parsed: Option<access_controller::AccessAdded> = LogParser::parse_logs(program_logs.clone());
+    let parsed = parsed.expect("Logs should be present");
+    assert_eq!(parsed.access_controller, access_controller);
+    assert_eq!(parsed.address, first);
+
+    let parsed: Vec<access_controller::AccessAdded> = LogParser::parse_all_logs(program_logs);
+    assert_eq!(
+        parsed.iter().map(|event| event.address).collect::<Vec<_>>(),
+        vec![first, second]
+    );
+}
@@ -1,9 +1,11 @@
 mod compression;
+mod introspection;
 mod log_parser;
 mod slice_util;
 
 pub use slice_util::*;
 pub use compression::*;
+pub use introspection::*;
 pub use log_parser::*;
 
 #[cfg(test)]
@@ -1 +1,13 @@
 pub mod test_setup;
+
+use solana_program_test::ProgramTestContext;
+use solana_sdk::pubkey::Pubkey;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+
+/// Returns the lamports held by `address`, zero when the account does not exist
+pub async fn get_lamports(environment_context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    EnvironmentContextOperations::get_account(environment_context, address)
+        .await
+        .unwrap()
+        .map_or(0, |account| account.lamports)
+}
@@ -72,6 +72,11 @@ impl VerifierTestSetupBuilder {
         self
     }
 
+    pub fn add_program(mut self, name: &'static str, program_id: Pubkey) -> Self {
+        self.base_builder = self.base_builder.add_program(name, program_id);
+        self
+    }
+
     pub fn add_zero_copy_account(
         mut self,
         program_id: Pubkey,
@@ -0,0 +1,243 @@
+use crate::common::get_lamports;
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_admin_can_add_and_remove_access() {
+    let admin = Keypair::new();
+    let address = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(admin.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &user, admin.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_access(&mut environment_context, &admin, address)
+        .await;
+    Assert::transaction_ok(&result);
+    assert!(access_controller_client.has_access(&mut environment_context, &address).await);
+
+    let result = access_controller_client
+        .remove_access(&mut environment_context, &admin, address)
+        .await;
+    Assert::transaction_ok(&result);
+    assert!(!access_controller_client.has_access(&mut environment_context, &address).await);
+
+    let result = access_controller_client
+        .batch_add_access(&mut environment_context, &admin, vec![address])
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .grant_access(&mut environment_context, &admin, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .revoke_access(&mut environment_context, &admin, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let now = EnvironmentContextOperations::get_clock(&mut environment_context)
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &admin, address, now + 3600)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let feed_id = [7u8; 32];
+    let result = access_controller_client
+        .initialize_feed_access_list(&mut environment_context, &admin, feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_feed_access(&mut environment_context, &admin, feed_id, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .remove_feed_access(&mut environment_context, &admin, feed_id, address)
+        .await;
+    Assert::transaction_ok(&result);
+}
+
+#[tokio::test]
+async fn test_revoke_access_returns_rent_to_owner() {
+    let admin = Keypair::new();
+    let address = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(admin.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &user, admin.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The owner pays for the grant
+    let result = access_controller_client
+        .grant_access(&mut environment_context, &user, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let grant_rent = get_lamports(&mut environment_context, access_controller_client.access_grant_address(&address)).await;
+    let owner_balance = get_lamports(&mut environment_context, user.pubkey()).await;
+    let admin_balance = get_lamports(&mut environment_context, admin.pubkey()).await;
+
+    // An admin revoking the grant does not receive its rent
+    let result = access_controller_client
+        .revoke_access(&mut environment_context, &admin, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    assert_eq!(get_lamports(&mut environment_context, user.pubkey()).await, owner_balance + grant_rent);
+    assert!(get_lamports(&mut environment_context, admin.pubkey()).await < admin_balance);
+}
+
+#[tokio::test]
+async fn test_admin_cannot_transfer_ownership() {
+    let admin = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(admin.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &user, admin.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .transfer_ownership(&mut environment_context, &admin, admin.pubkey())
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_admin_cannot_manage_admins() {
+    let admin = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(admin.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &user, admin.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &admin, Keypair::new().pubkey())
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+
+    let result = access_controller_client
+        .remove_admin(&mut environment_context, &admin, admin.pubkey())
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_removed_admin_cannot_add_access() {
+    let admin = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(admin.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &user, admin.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .remove_admin(&mut environment_context, &user, admin.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_access(&mut environment_context, &admin, Keypair::new().pubkey())
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_non_admin_cannot_add_access() {
+    let stranger = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(stranger.pubkey())
+        .build()
+        .await;
+
+    let result = access_controller_client
+        .unwrap()
+        .add_access(&mut environment_context, &stranger, stranger.pubkey())
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
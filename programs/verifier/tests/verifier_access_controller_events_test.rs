@@ -0,0 +1,331 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use access_controller::{
+    AccessAdded, AccessRemoved, AdminAdded, AdminRemoved, FeedAccessAdded, FeedAccessRemoved,
+    OwnershipTransferRequested, OwnershipTransferred, ProgramAccessAdded, ProgramAccessRemoved,
+    SubscriptionExtended, SubscriptionRemoved,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use verifier::util::LogParser;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_access_events() {
+    let address = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+    let access_controller = access_controller_account_address.unwrap();
+
+    let result = access_controller_client
+        .add_access(&mut environment_context, &user, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<AccessAdded> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.address, address);
+
+    // Adding again changes nothing, so nothing is emitted
+    let result = access_controller_client
+        .add_access(&mut environment_context, &user, address)
+        .await;
+    Assert::transaction_ok(&result);
+    let event: Option<AccessAdded> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert!(event.is_none());
+
+    let result = access_controller_client
+        .remove_access(&mut environment_context, &user, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<AccessRemoved> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.address, address);
+}
+
+#[tokio::test]
+async fn test_batch_access_events() {
+    let addresses = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .batch_add_access(&mut environment_context, &user, addresses.clone())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let events: Vec<AccessAdded> = LogParser::parse_all_logs(result.unwrap().metadata.unwrap().log_messages);
+    let mut added: Vec<_> = events.iter().map(|event| event.address).collect();
+    added.sort_unstable();
+    let mut expected = addresses.clone();
+    expected.sort_unstable();
+    assert_eq!(added, expected);
+
+    let result = access_controller_client
+        .batch_remove_access(&mut environment_context, &user, addresses)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let events: Vec<AccessRemoved> = LogParser::parse_all_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert_eq!(events.len(), 2);
+}
+
+#[tokio::test]
+async fn test_ownership_transfer_events() {
+    let new_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(new_owner.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+    let access_controller = access_controller_account_address.unwrap();
+
+    let result = access_controller_client
+        .transfer_ownership(&mut environment_context, &user, new_owner.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<OwnershipTransferRequested> =
+        LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.from, user.pubkey());
+    assert_eq!(event.to, new_owner.pubkey());
+
+    let result = access_controller_client
+        .accept_ownership(&mut environment_context, &new_owner)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<OwnershipTransferred> =
+        LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.from, user.pubkey());
+    assert_eq!(event.to, new_owner.pubkey());
+}
+
+#[tokio::test]
+async fn test_subscription_events() {
+    let address = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+    let access_controller = access_controller_account_address.unwrap();
+
+    let now = EnvironmentContextOperations::get_clock(&mut environment_context)
+        .await
+        .unwrap()
+        .unix_timestamp;
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, address, now + 60)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<SubscriptionExtended> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.address, address);
+    assert_eq!(event.valid_until, now + 60);
+
+    // A shorter subscription keeps the current expiry, which is what gets emitted
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, address, now + 30)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<SubscriptionExtended> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert_eq!(event.expect("Logs should be present").valid_until, now + 60);
+
+    EnvironmentContextOperations::set_unix_timestamp(&mut environment_context, now + 61)
+        .await
+        .unwrap();
+
+    let result = access_controller_client
+        .purge_expired_subscriptions(&mut environment_context, &user)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<SubscriptionRemoved> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.address, address);
+}
+
+#[tokio::test]
+async fn test_feed_access_events() {
+    let address = Keypair::new().pubkey();
+    let feed_id = [7u8; 32];
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+    let access_controller = access_controller_account_address.unwrap();
+
+    let result = access_controller_client
+        .initialize_feed_access_list(&mut environment_context, &user, feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_feed_access(&mut environment_context, &user, feed_id, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<FeedAccessAdded> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.feed_id, feed_id);
+    assert_eq!(event.address, address);
+
+    let result = access_controller_client
+        .remove_feed_access(&mut environment_context, &user, feed_id, address)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<FeedAccessRemoved> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.feed_id, feed_id);
+    assert_eq!(event.address, address);
+
+    // Removing again changes nothing, so nothing is emitted
+    let result = access_controller_client
+        .remove_feed_access(&mut environment_context, &user, feed_id, address)
+        .await;
+    Assert::transaction_ok(&result);
+    let event: Option<FeedAccessRemoved> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert!(event.is_none());
+}
+
+#[tokio::test]
+async fn test_admin_events() {
+    let admin = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+    let access_controller = access_controller_account_address.unwrap();
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &user, admin)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<AdminAdded> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.admin, admin);
+
+    let result = access_controller_client
+        .remove_admin(&mut environment_context, &user, admin)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<AdminRemoved> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.admin, admin);
+}
+
+#[tokio::test]
+async fn test_program_access_events() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+    let access_controller = access_controller_account_address.unwrap();
+
+    let result = access_controller_client
+        .add_program_access(&mut environment_context, &user, verifier::ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<ProgramAccessAdded> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.program, verifier::ID);
+
+    let result = access_controller_client
+        .remove_program_access(&mut environment_context, &user, verifier::ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<ProgramAccessRemoved> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.access_controller, access_controller);
+    assert_eq!(event.program, verifier::ID);
+}
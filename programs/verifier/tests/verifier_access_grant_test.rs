@@ -0,0 +1,267 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use access_controller::AccessListMode;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::util::Compressor;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_verify_with_access_grant() {
+    let granted_user = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(granted_user.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .grant_access(&mut environment_context, &user, granted_user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Not on the access list, but granted
+    assert!(!access_controller_client.has_access(&mut environment_context, &granted_user.pubkey()).await);
+
+    let access_grant = access_controller_client.access_grant_address(&granted_user.pubkey());
+    let result = verifier_client
+        .verify_with_access_grant(
+            &mut environment_context,
+            &granted_user,
+            Compressor::compress(&report),
+            None,
+            Some(access_grant),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &granted_user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    // Revoking closes the grant
+    let result = access_controller_client
+        .revoke_access(&mut environment_context, &user, granted_user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let account = EnvironmentContextOperations::get_account(&mut environment_context, access_grant)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn test_verify_with_another_users_access_grant_fails() {
+    let granted_user = Keypair::new();
+    let other_user = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(other_user.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .grant_access(&mut environment_context, &user, granted_user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_access_grant(
+            &mut environment_context,
+            &other_user,
+            Compressor::compress(&report),
+            None,
+            Some(access_controller_client.access_grant_address(&granted_user.pubkey())),
+        )
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+}
+
+#[tokio::test]
+async fn test_grant_access_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = access_controller_client
+        .unwrap()
+        .grant_access(&mut environment_context, &non_owner, non_owner.pubkey())
+        .await;
+
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_migrate_access_list() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    for _ in 0..3 {
+        let result = access_controller_client
+            .add_access(&mut environment_context, &user, Keypair::new().pubkey())
+            .await;
+        Assert::transaction_ok(&result);
+    }
+
+    let access_list: Vec<_> = access_controller_client
+        .read_access_controller_account(&mut environment_context)
+        .await
+        .unwrap()
+        .access_list
+        .to_vec();
+
+    // Migrate the first two entries
+    let result = access_controller_client
+        .migrate_access_list(&mut environment_context, &user, &access_list[..2])
+        .await;
+    Assert::transaction_ok(&result);
+
+    let remaining: Vec<_> = access_controller_client
+        .read_access_controller_account(&mut environment_context)
+        .await
+        .unwrap()
+        .access_list
+        .to_vec();
+    assert_eq!(remaining, access_list[2..]);
+
+    for migrated in &access_list[..2] {
+        let account = EnvironmentContextOperations::get_account(
+            &mut environment_context,
+            access_controller_client.access_grant_address(migrated),
+        )
+        .await
+        .unwrap();
+        assert!(account.is_some());
+    }
+
+    // Grant addresses must match the access list entries
+    let result = access_controller_client
+        .migrate_access_list(&mut environment_context, &user, &[Keypair::new().pubkey()])
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+}
+
+#[tokio::test]
+async fn test_migrate_access_list_with_prefunded_access_grant() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    // Anyone can send lamports to a grant address before it is created
+    let access_grant = access_controller_client.access_grant_address(&user.pubkey());
+    let result = EnvironmentContextOperations::transfer_lamports(&mut environment_context, &user, access_grant, 1_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .migrate_access_list(&mut environment_context, &user, &[user.pubkey()])
+        .await;
+    Assert::transaction_ok(&result);
+
+    let account = EnvironmentContextOperations::get_account(&mut environment_context, access_grant)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, access_controller::ID);
+}
+
+#[tokio::test]
+async fn test_migrate_access_list_in_deny_mode() {
+    let denied = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .remove_access(&mut environment_context, &user, user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+    let result = access_controller_client
+        .set_access_list_mode(&mut environment_context, &user, AccessListMode::Deny)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_access(&mut environment_context, &user, denied)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Migrating would turn denied addresses into grants
+    let result = access_controller_client
+        .migrate_access_list(&mut environment_context, &user, &[denied])
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+}
@@ -0,0 +1,182 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use access_controller::{BatchAddAccessResult, BatchRemoveAccessResult, MAX_ALLOW_LIST};
+use anchor_lang::prelude::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+
+pub mod common;
+
+fn new_addresses(count: usize) -> Vec<Pubkey> {
+    (0..count).map(|_| Keypair::new().pubkey()).collect()
+}
+
+#[tokio::test]
+async fn test_batch_add_access() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let addresses = new_addresses(10);
+
+    // `user` is already on the list and one address is repeated
+    let mut batch = addresses.clone();
+    batch.push(user.pubkey());
+    batch.push(addresses[0]);
+
+    let result = access_controller_client
+        .batch_add_access(&mut environment_context, &user, batch)
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        EnvironmentContextOperations::deserialize_return_data::<BatchAddAccessResult>(&result),
+        BatchAddAccessResult { added: 10, skipped: 2, rejected: 0 }
+    );
+
+    let access_list = access_controller_client
+        .read_access_controller_account(&mut environment_context)
+        .await
+        .unwrap()
+        .access_list
+        .to_vec();
+    assert_eq!(access_list.len(), 11);
+    assert!(access_list.windows(2).all(|pair| pair[0] < pair[1]));
+    for address in &addresses {
+        assert!(access_controller_client.has_access(&mut environment_context, address).await);
+    }
+}
+
+#[tokio::test]
+async fn test_batch_add_access_rejects_when_full() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    // `user` takes one slot, fill all but three of the rest
+    let mut len = 1;
+    while len < MAX_ALLOW_LIST - 3 {
+        let count = (MAX_ALLOW_LIST - 3 - len).min(20);
+        let result = access_controller_client
+            .batch_add_access(&mut environment_context, &user, new_addresses(count))
+            .await;
+        Assert::transaction_ok(&result);
+        len += count;
+    }
+
+    let result = access_controller_client
+        .batch_add_access(&mut environment_context, &user, new_addresses(10))
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        EnvironmentContextOperations::deserialize_return_data::<BatchAddAccessResult>(&result),
+        BatchAddAccessResult { added: 3, skipped: 0, rejected: 7 }
+    );
+
+    let access_list = access_controller_client
+        .read_access_controller_account(&mut environment_context)
+        .await
+        .unwrap()
+        .access_list
+        .to_vec();
+    assert_eq!(access_list.len(), MAX_ALLOW_LIST);
+    assert!(access_list.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[tokio::test]
+async fn test_batch_remove_access() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let addresses = new_addresses(5);
+    let result = access_controller_client
+        .batch_add_access(&mut environment_context, &user, addresses.clone())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, addresses[0], i64::MAX)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // One unknown address and one repeated
+    let mut batch = addresses[..3].to_vec();
+    batch.push(Keypair::new().pubkey());
+    batch.push(addresses[0]);
+
+    let result = access_controller_client
+        .batch_remove_access(&mut environment_context, &user, batch)
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        EnvironmentContextOperations::deserialize_return_data::<BatchRemoveAccessResult>(&result),
+        BatchRemoveAccessResult { removed: 3, skipped: 2 }
+    );
+
+    for address in &addresses[..3] {
+        assert!(!access_controller_client.has_access(&mut environment_context, address).await);
+    }
+    for address in &addresses[3..] {
+        assert!(access_controller_client.has_access(&mut environment_context, address).await);
+    }
+    assert!(access_controller_client.has_access(&mut environment_context, &user.pubkey()).await);
+
+    // Removing access also ends the subscription
+    assert_eq!(
+        access_controller_client
+            .subscription_valid_until(&mut environment_context, &addresses[0])
+            .await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_batch_add_access_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = access_controller_client
+        .unwrap()
+        .batch_add_access(&mut environment_context, &non_owner, vec![non_owner.pubkey()])
+        .await;
+
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
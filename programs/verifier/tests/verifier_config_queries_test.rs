@@ -0,0 +1,187 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::domain::{DonConfigDetails, VerifierAccountConfigDetails};
+use verifier::errors::ErrorCode;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_get_config_count() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let result = verifier_client
+        .get_config_count(&mut environment_context, &user)
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(EnvironmentContextOperations::deserialize_return_data::<u16>(&result), 0);
+
+    let (_, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .get_config_count(&mut environment_context, &user)
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(EnvironmentContextOperations::deserialize_return_data::<u16>(&result), 1);
+}
+
+#[tokio::test]
+async fn test_get_config_by_index() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (_, signers_a) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let (_, signers_b) = generate_report_with_signers::<V3Report>(10, 6, None, None);
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers_a, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers_b.clone(), 3, 1_700_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_active(&mut environment_context, &user, 1, false)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .get_config(&mut environment_context, &user, 1)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    let mut sorted_signers = signers_b;
+    sorted_signers.sort_unstable();
+
+    let config: DonConfigDetails = EnvironmentContextOperations::deserialize_return_data(&result);
+    assert_eq!(config.don_config_index, 1);
+    assert_eq!(config.don_config_id, verifier_account.don_configs[1].don_config_id);
+    assert_eq!(config.f, 3);
+    assert!(!config.is_active);
+    assert_eq!(config.activation_time, 1_700_000_000);
+    assert_eq!(config.signers, sorted_signers);
+
+    // Out of range index
+    let result = verifier_client
+        .get_config(&mut environment_context, &user, 2)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonConfigDoesNotExist);
+}
+
+#[tokio::test]
+async fn test_get_config_for_timestamp() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (_, signers_a) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let (_, signers_b) = generate_report_with_signers::<V3Report>(10, 6, None, None);
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers_a, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers_b, 3, 1_700_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Before any config is activated
+    let result = verifier_client
+        .get_config_for_timestamp(&mut environment_context, &user, 1_500_000_000)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonConfigDoesNotExist);
+
+    // Between the two activation times, the first config applies
+    let result = verifier_client
+        .get_config_for_timestamp(&mut environment_context, &user, 1_650_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+    let config: DonConfigDetails = EnvironmentContextOperations::deserialize_return_data(&result);
+    assert_eq!(config.don_config_index, 0);
+    assert_eq!(config.f, 5);
+
+    // An exact activation time selects that config
+    let result = verifier_client
+        .get_config_for_timestamp(&mut environment_context, &user, 1_700_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+    let config: DonConfigDetails = EnvironmentContextOperations::deserialize_return_data(&result);
+    assert_eq!(config.don_config_index, 1);
+    assert_eq!(config.f, 3);
+}
+
+#[tokio::test]
+async fn test_get_account_config() {
+    let proposed_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let result = verifier_client
+        .transfer_ownership(&mut environment_context, &user, proposed_owner.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .get_account_config(&mut environment_context, &user)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let config: VerifierAccountConfigDetails = EnvironmentContextOperations::deserialize_return_data(&result);
+    assert_eq!(config.owner, user.pubkey());
+    assert_eq!(config.proposed_owner, proposed_owner.pubkey());
+    assert_eq!(config.access_controller, access_controller_account_address.unwrap());
+}
@@ -0,0 +1,175 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use access_controller::AccessListMode;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::util::Compressor;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_verify_in_deny_mode() {
+    let anyone = Keypair::new();
+    let denied = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(anyone.pubkey())
+        .add_user(denied.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // `user` was allowed. The list must be emptied before it can deny instead.
+    let result = access_controller_client
+        .set_access_list_mode(&mut environment_context, &user, AccessListMode::Deny)
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+
+    let result = access_controller_client
+        .batch_remove_access(&mut environment_context, &user, vec![user.pubkey()])
+        .await;
+    Assert::transaction_ok(&result);
+    let result = access_controller_client
+        .set_access_list_mode(&mut environment_context, &user, AccessListMode::Deny)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_access(&mut environment_context, &user, denied.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &anyone, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &denied, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    // Switching back would allow the denied address
+    let result = access_controller_client
+        .set_access_list_mode(&mut environment_context, &user, AccessListMode::Allow)
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+
+    let result = access_controller_client
+        .remove_access(&mut environment_context, &user, denied.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+    let result = access_controller_client
+        .set_access_list_mode(&mut environment_context, &user, AccessListMode::Allow)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The allow list is empty
+    let result = verifier_client
+        .verify(&mut environment_context, &anyone, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &denied, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_deny_mode_overrides_access_grant() {
+    let denied = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(denied.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .grant_access(&mut environment_context, &user, denied.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+    let result = access_controller_client
+        .remove_access(&mut environment_context, &user, user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+    let result = access_controller_client
+        .set_access_list_mode(&mut environment_context, &user, AccessListMode::Deny)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = access_controller_client
+        .add_access(&mut environment_context, &user, denied.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_access_grant(
+            &mut environment_context,
+            &denied,
+            Compressor::compress(&report),
+            None,
+            Some(access_controller_client.access_grant_address(&denied.pubkey())),
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_set_access_list_mode_with_admin() {
+    let admin = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(admin.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .add_admin(&mut environment_context, &user, admin.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Only the owner may switch modes
+    let result = access_controller_client
+        .set_access_list_mode(&mut environment_context, &admin, AccessListMode::Deny)
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
@@ -0,0 +1,429 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::report::{generate_report_with_signers, V2Report, V3Report};
+use verifier::domain::RewardRecipientConfig;
+use verifier::errors::ErrorCode;
+use verifier::events::{DonNamespaceConfigSet, DonRouteSet};
+use verifier::evm::Encoder;
+use verifier::state::TOTAL_REWARD_WEIGHT;
+use verifier::util::{Compressor, LogParser};
+
+pub mod common;
+
+const NAMESPACE_ID: u64 = 1;
+
+#[tokio::test]
+async fn test_verify_with_don_namespace_routed_by_feed() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    // The verifier account's DON signs the v2 feed, the namespace's DON the v3 feed
+    let (report, signers) = generate_report_with_signers::<V2Report>(16, 6, None, None);
+    let (namespace_report, namespace_signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let feed_id: [u8; 32] = Encoder::parse_signed_report(&namespace_report).unwrap().report_data[..32]
+        .try_into()
+        .unwrap();
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_don_namespace(&mut environment_context, &user, NAMESPACE_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_namespace_config_with_activation_time(
+            &mut environment_context,
+            &user,
+            NAMESPACE_ID,
+            namespace_signers,
+            5,
+            1_600_000_000,
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<DonNamespaceConfigSet> =
+        LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.namespace_id, NAMESPACE_ID);
+    assert_eq!(event.don_config_index, 0);
+
+    let result = verifier_client
+        .initialize_don_route(&mut environment_context, &user, feed_id, NAMESPACE_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<DonRouteSet> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.key, feed_id);
+    assert_eq!(event.namespace_id, NAMESPACE_ID);
+
+    let routes = || VerifyOptionalAccounts {
+        don_routes: true,
+        ..Default::default()
+    };
+    let namespace_accounts = || VerifyOptionalAccounts {
+        don_namespace: Some(verifier_client.don_namespace_address(NAMESPACE_ID)),
+        ..routes()
+    };
+
+    // While a route exists, the routes of every report are required
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&namespace_report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonRoutesRequired);
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonRoutesRequired);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&namespace_report),
+            None,
+            namespace_accounts(),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    // A routed report cannot fall back to the verifier account's configs
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&namespace_report),
+            None,
+            routes(),
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonRouteMismatch);
+
+    // Reports without a route still verify against the verifier account's configs, but not the
+    // namespace's
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            routes(),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            namespace_accounts(),
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonRouteMismatch);
+
+    let result = verifier_client
+        .set_namespace_config_active(&mut environment_context, &user, NAMESPACE_ID, 0, false)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&namespace_report),
+            None,
+            namespace_accounts(),
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::ConfigDeactivated);
+
+    // Without routes, reports no longer need theirs
+    let result = verifier_client
+        .close_don_route(&mut environment_context, &user, feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+    assert_eq!(verifier_account.don_route_count, 0);
+}
+
+#[tokio::test]
+async fn test_verify_with_don_namespace_routed_by_config_digest() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let config_digest = Encoder::parse_signed_report(&report).unwrap().report_context[0];
+
+    let result = verifier_client
+        .initialize_don_namespace(&mut environment_context, &user, NAMESPACE_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_namespace_config_with_activation_time(
+            &mut environment_context,
+            &user,
+            NAMESPACE_ID,
+            signers,
+            5,
+            1_600_000_000,
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_don_route(&mut environment_context, &user, config_digest, NAMESPACE_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let optional_accounts = || VerifyOptionalAccounts {
+        don_namespace: Some(verifier_client.don_namespace_address(NAMESPACE_ID)),
+        don_routes: true,
+        ..Default::default()
+    };
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            optional_accounts(),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The namespace is only used together with the routes to it
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                don_routes: false,
+                ..optional_accounts()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonRoutesRequired);
+
+    let result = verifier_client
+        .close_don_route(&mut environment_context, &user, config_digest)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let namespace = verifier_client
+        .read_don_namespace(&mut environment_context, NAMESPACE_ID)
+        .await
+        .unwrap();
+    assert_eq!(namespace.namespace_id, NAMESPACE_ID);
+    assert_eq!(namespace.don_configs.len(), 1);
+}
+
+#[tokio::test]
+async fn test_set_namespace_config_checked() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let result = verifier_client
+        .initialize_don_namespace(&mut environment_context, &user, NAMESPACE_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let (_, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_namespace_config_with_activation_time_checked(
+            &mut environment_context,
+            &user,
+            NAMESPACE_ID,
+            (signers.clone(), 5),
+            1_600_000_000,
+            [1u8; 24],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonConfigIdMismatch);
+
+    let mut sorted_signers = signers.clone();
+    sorted_signers.sort_unstable();
+    let don_config_id = Encoder::compute_don_config_id(&Encoder::encode_don_config_id(&sorted_signers, 5));
+
+    let result = verifier_client
+        .set_namespace_config_with_activation_time_checked(
+            &mut environment_context,
+            &user,
+            NAMESPACE_ID,
+            (signers, 5),
+            1_600_000_000,
+            don_config_id,
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let namespace = verifier_client
+        .read_don_namespace(&mut environment_context, NAMESPACE_ID)
+        .await
+        .unwrap();
+    assert_eq!(namespace.don_configs.len(), 1);
+    assert_eq!(namespace.don_configs[0].don_config_id, don_config_id);
+}
+
+#[tokio::test]
+async fn test_set_reward_recipients_of_don_namespace() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let config_digest = Encoder::parse_signed_report(&report).unwrap().report_context[0];
+
+    let result = verifier_client
+        .initialize_don_namespace(&mut environment_context, &user, NAMESPACE_ID)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .set_namespace_config_with_activation_time(
+            &mut environment_context,
+            &user,
+            NAMESPACE_ID,
+            signers.clone(),
+            5,
+            1_600_000_000,
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, config_digest)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let recipients = vec![RewardRecipientConfig {
+        payee: Keypair::new().pubkey(),
+        signer: signers[0],
+        weight: TOTAL_REWARD_WEIGHT,
+    }];
+
+    // The verifier account has no DON config to pay the namespace's signers for
+    let result = verifier_client
+        .set_reward_recipients(&mut environment_context, &user, config_digest, 0, recipients.clone())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::DonConfigDoesNotExist);
+
+    let result = verifier_client
+        .set_namespace_reward_recipients(
+            &mut environment_context,
+            &user,
+            config_digest,
+            NAMESPACE_ID,
+            0,
+            recipients,
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let reward_pool = verifier_client
+        .read_reward_pool(&mut environment_context, &config_digest)
+        .await
+        .unwrap();
+    assert_eq!(reward_pool.recipients.len(), 1);
+    assert_eq!(reward_pool.recipients[0].signer, signers[0]);
+}
+
+#[tokio::test]
+async fn test_don_namespace_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = verifier_client
+        .initialize_don_namespace(&mut environment_context, &non_owner, NAMESPACE_ID)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .initialize_don_namespace(&mut environment_context, &user, NAMESPACE_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let (_, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_namespace_config_with_activation_time(
+            &mut environment_context,
+            &non_owner,
+            NAMESPACE_ID,
+            signers,
+            5,
+            1_600_000_000,
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .initialize_don_route(&mut environment_context, &non_owner, [1u8; 32], NAMESPACE_ID)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
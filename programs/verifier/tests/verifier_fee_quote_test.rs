@@ -0,0 +1,192 @@
+use crate::common::get_lamports;
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use anchor_lang::system_program;
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use test_utils::token_operations::TokenOperations;
+use verifier::domain::FeeQuote;
+use verifier::errors::ErrorCode;
+use verifier::state::FeeAsset;
+use verifier::util::Compressor;
+
+pub mod common;
+
+const FEE_TOKEN_DECIMALS: u8 = 9;
+// The V3 dummy report's `link_fee` scaled to `FEE_TOKEN_DECIMALS`
+const V3_REPORT_FEE: u64 = 25_234_531;
+// The V3 dummy report's `native_fee` in lamports
+const V3_REPORT_NATIVE_FEE: u64 = 118_647;
+
+#[tokio::test]
+async fn test_get_fee_quote_with_fees_disabled() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, _) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .get_fee_quote(&mut environment_context, &user, user.pubkey(), Compressor::compress(&report), VerifyOptionalAccounts::default())
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(EnvironmentContextOperations::deserialize_return_data::<Option<FeeQuote>>(&result), None);
+}
+
+#[tokio::test]
+async fn test_get_fee_quote_rejects_schema_versions_not_accepted() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let result = verifier_client
+        .set_accepted_schema_versions(&mut environment_context, &user, vec![2])
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Reports `verify` would reject are not quoted
+    let (report, _) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .get_fee_quote(&mut environment_context, &user, user.pubkey(), Compressor::compress(&report), VerifyOptionalAccounts::default())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::SchemaVersionNotAccepted);
+}
+
+#[tokio::test]
+async fn test_get_fee_quote_in_token() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let mint = TokenOperations::create_mint(&mut environment_context, &user, FEE_TOKEN_DECIMALS)
+        .await
+        .unwrap();
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, mint, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // No config is set, signatures are not checked when quoting
+    let (report, _) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+
+    // The mint is required to scale the fee
+    let result = verifier_client
+        .get_fee_quote(&mut environment_context, &user, user.pubkey(), Compressor::compress(&report), VerifyOptionalAccounts::default())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::FeeAccountsRequired);
+
+    let result = verifier_client
+        .get_fee_quote(
+            &mut environment_context,
+            &user,
+            user.pubkey(),
+            Compressor::compress(&report),
+            VerifyOptionalAccounts {
+                fee_token_mint: Some(mint),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        EnvironmentContextOperations::deserialize_return_data::<Option<FeeQuote>>(&result),
+        Some(FeeQuote {
+            asset: FeeAsset::Token,
+            fee_asset: mint,
+            base_fee: V3_REPORT_FEE,
+            surcharge: 0,
+            discount: 0,
+            amount: V3_REPORT_FEE,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_get_fee_quote_in_native_with_surcharge() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let mint = TokenOperations::create_mint(&mut environment_context, &user, FEE_TOKEN_DECIMALS)
+        .await
+        .unwrap();
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, mint, true)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, true, 250)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_fee_preference(&mut environment_context, &user, FeeAsset::Native)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let (report, _) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let native_fee_vault = verifier_client.native_fee_vault_address();
+    let vault_balance = get_lamports(&mut environment_context, native_fee_vault).await;
+
+    let result = verifier_client
+        .get_fee_quote(
+            &mut environment_context,
+            &user,
+            user.pubkey(),
+            Compressor::compress(&report),
+            VerifyOptionalAccounts {
+                fee_preference: Some(verifier_client.fee_preference_address(&user.pubkey())),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    let amount = V3_REPORT_NATIVE_FEE * 10_250 / 10_000;
+    assert_eq!(
+        EnvironmentContextOperations::deserialize_return_data::<Option<FeeQuote>>(&result),
+        Some(FeeQuote {
+            asset: FeeAsset::Native,
+            fee_asset: system_program::ID,
+            base_fee: V3_REPORT_NATIVE_FEE,
+            surcharge: amount - V3_REPORT_NATIVE_FEE,
+            discount: 0,
+            amount,
+        })
+    );
+
+    // Nothing is charged
+    assert_eq!(get_lamports(&mut environment_context, native_fee_vault).await, vault_balance);
+}
@@ -0,0 +1,477 @@
+use crate::common::get_lamports;
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use test_utils::token_operations::TokenOperations;
+use verifier::errors::ErrorCode;
+use verifier::events::FeePaid;
+use verifier::state::FeeAsset;
+use verifier::util::{Compressor, LogParser};
+
+pub mod common;
+
+// LINK on Solana has 9 decimals, the V3 dummy report's `link_fee` scales to this
+const FEE_TOKEN_DECIMALS: u8 = 9;
+const V3_REPORT_FEE: u64 = 25_234_531;
+// Test reports are generated with an empty report context
+const CONFIG_DIGEST: [u8; 32] = [0; 32];
+// The V3 dummy report's `native_fee` in lamports
+const V3_REPORT_NATIVE_FEE: u64 = 118_647;
+
+/// Creates a fee token mint and a funded token account owned by `owner`
+async fn create_funded_token_account(
+    environment_context: &mut ProgramTestContext,
+    mint_authority: &Keypair,
+    owner: &Pubkey,
+    amount: u64,
+) -> (Pubkey, Pubkey) {
+    let mint = TokenOperations::create_mint(environment_context, mint_authority, FEE_TOKEN_DECIMALS)
+        .await
+        .unwrap();
+    let token_account = TokenOperations::create_token_account(environment_context, mint_authority, &mint, owner)
+        .await
+        .unwrap();
+    TokenOperations::mint_to(environment_context, mint_authority, &mint, &token_account, amount)
+        .await
+        .unwrap();
+    (mint, token_account)
+}
+
+#[tokio::test]
+async fn test_verify_charges_link_fee() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let (mint, token_account) =
+        create_funded_token_account(&mut environment_context, &user, &user.pubkey(), 10 * V3_REPORT_FEE).await;
+
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, mint, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The fee accounts are required while fees are enabled
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::FeeAccountsRequired);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                fee_token_mint: Some(mint),
+                requester_token_account: Some(token_account),
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<FeePaid> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.requester, user.pubkey());
+    assert_eq!(event.fee_asset, mint);
+    assert_eq!(event.amount, V3_REPORT_FEE);
+
+    let fee_vault = verifier_client.fee_vault_address(&mint);
+    assert_eq!(
+        TokenOperations::get_balance(&mut environment_context, &fee_vault).await.unwrap(),
+        V3_REPORT_FEE
+    );
+    assert_eq!(
+        TokenOperations::get_balance(&mut environment_context, &token_account).await.unwrap(),
+        9 * V3_REPORT_FEE
+    );
+
+    // Disabling fees stops charging
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, mint, false)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+}
+
+#[tokio::test]
+async fn test_verify_with_another_fee_token_fails() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let (mint, _) =
+        create_funded_token_account(&mut environment_context, &user, &user.pubkey(), V3_REPORT_FEE).await;
+    let (other_mint, other_token_account) =
+        create_funded_token_account(&mut environment_context, &user, &user.pubkey(), V3_REPORT_FEE).await;
+
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, mint, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The fee token cannot be switched, as reward pools hold unclaimed fees in it
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, other_mint, true)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::FeeTokenMintChanged);
+
+    // Paying in another mint is rejected
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                fee_token_mint: Some(other_mint),
+                requester_token_account: Some(other_token_account),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, anchor_lang::error::ErrorCode::AccountNotInitialized);
+}
+
+#[tokio::test]
+async fn test_verify_charges_native_fee_with_surcharge() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // 2.5% surcharge, with only native fees enabled requesters pay in SOL by default
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, true, 250)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::FeeAccountsRequired);
+
+    let native_fee_vault = verifier_client.native_fee_vault_address();
+    let vault_balance = get_lamports(&mut environment_context, native_fee_vault).await;
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                native_fee: true,
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let expected_fee = V3_REPORT_NATIVE_FEE * 10_250 / 10_000;
+    let event: Option<FeePaid> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.fee_asset, system_program::ID);
+    assert_eq!(event.amount, expected_fee);
+
+    assert_eq!(
+        get_lamports(&mut environment_context, native_fee_vault).await,
+        vault_balance + expected_fee
+    );
+}
+
+#[tokio::test]
+async fn test_verify_charges_native_fee_to_payer() {
+    let payer = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(payer.pubkey())
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, true, 0)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The user does not pay the transaction fee, so it is read-only and cannot be debited
+    let optional_accounts = || VerifyOptionalAccounts {
+        native_fee: true,
+        reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+        ..Default::default()
+    };
+    let result = verifier_client
+        .verify_with_fee_payer(
+            &mut environment_context,
+            &user,
+            &payer,
+            Compressor::compress(&report),
+            None,
+            optional_accounts(),
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::PayerRequired);
+
+    let native_fee_vault = verifier_client.native_fee_vault_address();
+    let vault_balance = get_lamports(&mut environment_context, native_fee_vault).await;
+    let user_balance = get_lamports(&mut environment_context, user.pubkey()).await;
+
+    let result = verifier_client
+        .verify_with_fee_payer(
+            &mut environment_context,
+            &user,
+            &payer,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                payer: Some(payer.pubkey()),
+                ..optional_accounts()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    assert_eq!(
+        get_lamports(&mut environment_context, native_fee_vault).await,
+        vault_balance + V3_REPORT_NATIVE_FEE
+    );
+    assert_eq!(get_lamports(&mut environment_context, user.pubkey()).await, user_balance);
+}
+
+#[tokio::test]
+async fn test_verify_with_fee_preference() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let (mint, token_account) =
+        create_funded_token_account(&mut environment_context, &user, &user.pubkey(), V3_REPORT_FEE).await;
+
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, mint, true)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, true, 0)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_fee_preference(&mut environment_context, &user, FeeAsset::Native)
+        .await;
+    Assert::transaction_ok(&result);
+    let fee_preference = verifier_client
+        .read_fee_preference(&mut environment_context, &user.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(fee_preference.requester, user.pubkey());
+    assert_eq!(fee_preference.asset(), FeeAsset::Native);
+
+    let fee_accounts = || VerifyOptionalAccounts {
+        fee_token_mint: Some(mint),
+        requester_token_account: Some(token_account),
+        fee_preference: Some(verifier_client.fee_preference_address(&user.pubkey())),
+        native_fee: true,
+        reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+        ..Default::default()
+    };
+
+    let native_fee_vault = verifier_client.native_fee_vault_address();
+    let vault_balance = get_lamports(&mut environment_context, native_fee_vault).await;
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, fee_accounts())
+        .await;
+    Assert::transaction_ok(&result);
+
+    assert_eq!(
+        get_lamports(&mut environment_context, native_fee_vault).await,
+        vault_balance + V3_REPORT_NATIVE_FEE
+    );
+    assert_eq!(
+        TokenOperations::get_balance(&mut environment_context, &token_account).await.unwrap(),
+        V3_REPORT_FEE
+    );
+
+    // Switching to the token
+    let result = verifier_client
+        .set_fee_preference(&mut environment_context, &user, FeeAsset::Token)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, fee_accounts())
+        .await;
+    Assert::transaction_ok(&result);
+
+    assert_eq!(
+        TokenOperations::get_balance(&mut environment_context, &token_account).await.unwrap(),
+        0
+    );
+    assert_eq!(
+        get_lamports(&mut environment_context, native_fee_vault).await,
+        vault_balance + V3_REPORT_NATIVE_FEE
+    );
+
+    // Native fees can be turned off while requesters still prefer them
+    let result = verifier_client
+        .set_fee_preference(&mut environment_context, &user, FeeAsset::Native)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, false, 0)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, fee_accounts())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::FeeAssetNotAccepted);
+}
+
+#[tokio::test]
+async fn test_set_native_fee_config_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &non_owner, true, 0)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_set_fee_config_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let mint = TokenOperations::create_mint(&mut environment_context, &non_owner, FEE_TOKEN_DECIMALS)
+        .await
+        .unwrap();
+
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &non_owner, mint, true)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
@@ -0,0 +1,215 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::report::{generate_report_with_signers, V2Report, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::evm::Encoder;
+use verifier::util::Compressor;
+
+pub mod common;
+
+fn feed_id(report: &[u8]) -> [u8; 32] {
+    let signed_report = Encoder::parse_signed_report(report).unwrap();
+    signed_report.report_data[..32].try_into().unwrap()
+}
+
+#[tokio::test]
+async fn test_verify_with_feed_access() {
+    let feed_user = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(feed_user.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let feed_id = feed_id(&report);
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .initialize_feed_access_list(&mut environment_context, &user, feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_feed_access(&mut environment_context, &user, feed_id, feed_user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Not on the global list, but allowed for this feed
+    assert!(!access_controller_client.has_access(&mut environment_context, &feed_user.pubkey()).await);
+
+    let result = verifier_client
+        .verify_with_feed_access_list(
+            &mut environment_context,
+            &feed_user,
+            Compressor::compress(&report),
+            None,
+            Some(access_controller_client.feed_access_list_address(&feed_id)),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Without the feed access list only the global list applies
+    let result = verifier_client
+        .verify(&mut environment_context, &feed_user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    // Removing feed access revokes it
+    let result = access_controller_client
+        .remove_feed_access(&mut environment_context, &user, feed_id, feed_user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_feed_access_list(
+            &mut environment_context,
+            &feed_user,
+            Compressor::compress(&report),
+            None,
+            Some(access_controller_client.feed_access_list_address(&feed_id)),
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_verify_with_feed_access_falls_back_to_global_list() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let feed_id = feed_id(&report);
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .initialize_feed_access_list(&mut environment_context, &user, feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // `user` is on the global list only
+    let result = verifier_client
+        .verify_with_feed_access_list(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            Some(access_controller_client.feed_access_list_address(&feed_id)),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+}
+
+#[tokio::test]
+async fn test_verify_with_feed_access_list_for_another_feed_fails() {
+    let feed_user = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(feed_user.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let (other_report, _) = generate_report_with_signers::<V2Report>(16, 6, None, None);
+    let other_feed_id = feed_id(&other_report);
+
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .initialize_feed_access_list(&mut environment_context, &user, other_feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_feed_access(&mut environment_context, &user, other_feed_id, feed_user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_feed_access_list(
+            &mut environment_context,
+            &feed_user,
+            Compressor::compress(&report),
+            None,
+            Some(access_controller_client.feed_access_list_address(&other_feed_id)),
+        )
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+}
+
+#[tokio::test]
+async fn test_add_feed_access_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let feed_id = [1u8; 32];
+
+    let result = access_controller_client
+        .initialize_feed_access_list(&mut environment_context, &non_owner, feed_id)
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+
+    let result = access_controller_client
+        .initialize_feed_access_list(&mut environment_context, &user, feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_feed_access(&mut environment_context, &non_owner, feed_id, non_owner.pubkey())
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
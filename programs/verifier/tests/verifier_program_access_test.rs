@@ -0,0 +1,194 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::util::Compressor;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_add_and_remove_program_access() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .add_program_access(&mut environment_context, &user, verifier::ID)
+        .await;
+    Assert::transaction_ok(&result);
+    assert!(access_controller_client.has_program_access(&mut environment_context, &verifier::ID).await);
+
+    let result = access_controller_client
+        .remove_program_access(&mut environment_context, &user, verifier::ID)
+        .await;
+    Assert::transaction_ok(&result);
+    assert!(!access_controller_client.has_program_access(&mut environment_context, &verifier::ID).await);
+}
+
+#[tokio::test]
+async fn test_add_program_access_requires_executable() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let result = access_controller_client
+        .unwrap()
+        .add_program_access(&mut environment_context, &user, user.pubkey())
+        .await;
+
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+}
+
+#[tokio::test]
+async fn test_add_program_access_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = access_controller_client
+        .unwrap()
+        .add_program_access(&mut environment_context, &non_owner, verifier::ID)
+        .await;
+
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_program_access_does_not_apply_to_direct_calls() {
+    let caller = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(caller.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Allowing the verifier itself must not let top-level callers through
+    let result = access_controller_client
+        .add_program_access(&mut environment_context, &user, verifier::ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &caller,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                instructions_sysvar: true,
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_allowed_program_verifies_for_unlisted_user() {
+    let caller = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_program("test_consumer", test_consumer::ID)
+        .add_user(caller.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .add_program_access(&mut environment_context, &user, test_consumer::ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The caller is not on the access list, the calling program is allowed instead
+    let result = verifier_client
+        .verify_through_program(&mut environment_context, &caller, Compressor::compress(&report), test_consumer::ID)
+        .await;
+    Assert::transaction_ok(&result);
+}
+
+#[tokio::test]
+async fn test_program_not_allowed_cannot_verify_for_unlisted_user() {
+    let caller = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_program("test_consumer", test_consumer::ID)
+        .add_user(caller.pubkey())
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_through_program(&mut environment_context, &caller, Compressor::compress(&report), test_consumer::ID)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
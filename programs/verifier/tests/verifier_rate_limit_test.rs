@@ -0,0 +1,264 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::util::Compressor;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_verify_with_default_rate_limit() {
+    let payer = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(payer.pubkey())
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_default_rate_limit(&mut environment_context, &user, true, 2, 100)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The rate limit account is required while rate limiting is enabled
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::RateLimitAccountRequired);
+
+    // Anyone may create it
+    let result = verifier_client
+        .initialize_rate_limit(&mut environment_context, &payer, user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let optional_accounts = || VerifyOptionalAccounts {
+        rate_limit: Some(verifier_client.rate_limit_address(&user.pubkey())),
+        ..Default::default()
+    };
+
+    for _ in 0..2 {
+        let result = verifier_client
+            .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, optional_accounts())
+            .await;
+        Assert::transaction_ok(&result);
+    }
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, optional_accounts())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::RateLimitExceeded);
+
+    // The next window allows verifications again
+    let slot = EnvironmentContextOperations::get_clock(&mut environment_context)
+        .await
+        .unwrap()
+        .slot;
+    environment_context.warp_to_slot(slot + 100).unwrap();
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, optional_accounts())
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Disabled again, the account is no longer required
+    let result = verifier_client
+        .set_default_rate_limit(&mut environment_context, &user, false, 2, 100)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+}
+
+#[tokio::test]
+async fn test_verify_with_user_rate_limit() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // No limit by default, one verification per window for this user
+    let result = verifier_client
+        .set_default_rate_limit(&mut environment_context, &user, true, 0, 100)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_rate_limit(&mut environment_context, &user, user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .set_user_rate_limit(&mut environment_context, &user, user.pubkey(), 1, 100)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let optional_accounts = || VerifyOptionalAccounts {
+        rate_limit: Some(verifier_client.rate_limit_address(&user.pubkey())),
+        ..Default::default()
+    };
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, optional_accounts())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, optional_accounts())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::RateLimitExceeded);
+
+    // Back on the default, which has no limit
+    let result = verifier_client
+        .clear_user_rate_limit(&mut environment_context, &user, user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(&mut environment_context, &user, Compressor::compress(&report), None, optional_accounts())
+        .await;
+    Assert::transaction_ok(&result);
+}
+
+#[tokio::test]
+async fn test_verify_with_another_users_rate_limit_fails() {
+    let other_user = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_default_rate_limit(&mut environment_context, &user, true, 1, 100)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_rate_limit(&mut environment_context, &user, other_user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                rate_limit: Some(verifier_client.rate_limit_address(&other_user.pubkey())),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, anchor_lang::error::ErrorCode::ConstraintSeeds);
+}
+
+#[tokio::test]
+async fn test_set_rate_limits_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = verifier_client
+        .set_default_rate_limit(&mut environment_context, &non_owner, true, 1, 100)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .initialize_rate_limit(&mut environment_context, &non_owner, non_owner.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_user_rate_limit(&mut environment_context, &non_owner, non_owner.pubkey(), 1, 100)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_set_rate_limits_with_empty_window() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let result = verifier_client
+        .set_default_rate_limit(&mut environment_context, &user, true, 1, 0)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+
+    // The window is only used while enabled
+    let result = verifier_client
+        .set_default_rate_limit(&mut environment_context, &user, false, 0, 0)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_rate_limit(&mut environment_context, &user, user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_user_rate_limit(&mut environment_context, &user, user.pubkey(), 1, 0)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+}
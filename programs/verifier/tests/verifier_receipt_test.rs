@@ -0,0 +1,269 @@
+use crate::common::get_lamports;
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use anchor_lang::solana_program::keccak::hash as keccak256;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::evm::Encoder;
+use verifier::util::Compressor;
+
+pub mod common;
+
+#[tokio::test]
+async fn test_verify_writes_receipt() {
+    let other_user = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(other_user.pubkey())
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let signed_report = Encoder::parse_signed_report(&report).unwrap();
+    let details = Encoder::parse_report_details_from_report(signed_report.report_data).unwrap();
+    let receipt = verifier_client.receipt_address(details.feed_id, details.report_timestamp);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                receipt: Some(receipt),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+    let receipt_data = verifier_client
+        .read_receipt(&mut environment_context, receipt)
+        .await
+        .unwrap();
+    assert_eq!(receipt_data.feed_id, *details.feed_id);
+    assert_eq!(receipt_data.observations_timestamp, details.report_timestamp);
+    assert_eq!(receipt_data.report_hash, keccak256(signed_report.report_data).to_bytes());
+    assert_eq!(receipt_data.don_config_id, verifier_account.don_configs[0].don_config_id);
+    assert_eq!(receipt_data.requester, user.pubkey());
+    assert_eq!(receipt_data.payer, user.pubkey());
+
+    // Verifying the report again keeps the receipt and whoever paid for it
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &other_user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                receipt: Some(receipt),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    let receipt_data = verifier_client
+        .read_receipt(&mut environment_context, receipt)
+        .await
+        .unwrap();
+    assert_eq!(receipt_data.requester, user.pubkey());
+
+    // Only the requester can close it
+    let result = verifier_client
+        .close_receipt(&mut environment_context, &other_user, receipt, user.pubkey())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .close_receipt(&mut environment_context, &user, receipt, user.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+    assert!(EnvironmentContextOperations::get_account(&mut environment_context, receipt)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_close_receipt_refunds_payer() {
+    let payer = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(payer.pubkey())
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let signed_report = Encoder::parse_signed_report(&report).unwrap();
+    let details = Encoder::parse_report_details_from_report(signed_report.report_data).unwrap();
+    let receipt = verifier_client.receipt_address(details.feed_id, details.report_timestamp);
+
+    let result = verifier_client
+        .verify_with_fee_payer(
+            &mut environment_context,
+            &user,
+            &payer,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                receipt: Some(receipt),
+                payer: Some(payer.pubkey()),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let receipt_data = verifier_client
+        .read_receipt(&mut environment_context, receipt)
+        .await
+        .unwrap();
+    assert_eq!(receipt_data.requester, user.pubkey());
+    assert_eq!(receipt_data.payer, payer.pubkey());
+
+    // The user closes the receipt, but the rent goes back to the payer
+    let result = verifier_client
+        .close_receipt(&mut environment_context, &user, receipt, user.pubkey())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+
+    let receipt_rent = get_lamports(&mut environment_context, receipt).await;
+    let payer_balance = get_lamports(&mut environment_context, payer.pubkey()).await;
+    let result = verifier_client
+        .close_receipt(&mut environment_context, &user, receipt, payer.pubkey())
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        get_lamports(&mut environment_context, payer.pubkey()).await,
+        payer_balance + receipt_rent
+    );
+}
+
+#[tokio::test]
+async fn test_verify_writes_prefunded_receipt() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let signed_report = Encoder::parse_signed_report(&report).unwrap();
+    let details = Encoder::parse_report_details_from_report(signed_report.report_data).unwrap();
+    let receipt = verifier_client.receipt_address(details.feed_id, details.report_timestamp);
+
+    // Anyone can fund the receipt's address before it is created
+    let result = EnvironmentContextOperations::transfer_lamports(&mut environment_context, &user, receipt, 1_000).await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                receipt: Some(receipt),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let receipt_data = verifier_client
+        .read_receipt(&mut environment_context, receipt)
+        .await
+        .unwrap();
+    assert_eq!(receipt_data.report_hash, keccak256(signed_report.report_data).to_bytes());
+    assert_eq!(receipt_data.requester, user.pubkey());
+
+    // Only the shortfall was paid on top of the existing lamports
+    let account = EnvironmentContextOperations::get_account(&mut environment_context, receipt)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = environment_context.banks_client.get_rent().await.unwrap();
+    assert_eq!(account.owner, verifier::ID);
+    assert_eq!(account.lamports, rent.minimum_balance(account.data.len()));
+}
+
+#[tokio::test]
+async fn test_verify_with_receipt_for_another_report_fails() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let signed_report = Encoder::parse_signed_report(&report).unwrap();
+    let details = Encoder::parse_report_details_from_report(signed_report.report_data).unwrap();
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                receipt: Some(verifier_client.receipt_address(details.feed_id, details.report_timestamp + 1)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+}
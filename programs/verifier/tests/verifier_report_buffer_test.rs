@@ -0,0 +1,155 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::evm::Encoder;
+use verifier::util::Compressor;
+
+pub mod common;
+
+const BUFFER_CAPACITY: u32 = 2_048;
+
+#[tokio::test]
+async fn test_verify_from_buffer() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_report_buffer(&mut environment_context, &user, 0, BUFFER_CAPACITY)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Stage the compressed report in two chunks
+    let compressed_report = Compressor::compress(&report);
+    let report_buffer = verifier_client.report_buffer_address(&user.pubkey(), 0);
+    let (first_chunk, second_chunk) = compressed_report.split_at(compressed_report.len() / 2);
+    for (offset, chunk) in [(0, first_chunk), (first_chunk.len(), second_chunk)] {
+        let result = verifier_client
+            .write_report_buffer(&mut environment_context, &user, report_buffer, offset as u32, chunk.to_vec())
+            .await;
+        Assert::transaction_ok(&result);
+    }
+    assert_eq!(
+        verifier_client
+            .read_report_buffer_data(&mut environment_context, report_buffer)
+            .await
+            .unwrap(),
+        compressed_report
+    );
+
+    let report_data = Encoder::parse_signed_report(&report).unwrap().report_data.to_vec();
+
+    // Without an output buffer the report data is returned
+    let result = verifier_client
+        .verify_from_buffer(
+            &mut environment_context,
+            &user,
+            &compressed_report,
+            report_buffer,
+            None,
+            VerifyOptionalAccounts::default(),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    let data = result
+        .expect("Transaction result should be present")
+        .metadata
+        .expect("Metadata should be present")
+        .return_data
+        .expect("Return data should be present")
+        .data;
+    assert_eq!(data, report_data);
+
+    let result = verifier_client
+        .initialize_report_buffer(&mut environment_context, &user, 1, BUFFER_CAPACITY)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let report_output = verifier_client.report_buffer_address(&user.pubkey(), 1);
+    let result = verifier_client
+        .verify_from_buffer(
+            &mut environment_context,
+            &user,
+            &compressed_report,
+            report_buffer,
+            Some(report_output),
+            VerifyOptionalAccounts::default(),
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        verifier_client
+            .read_report_buffer_data(&mut environment_context, report_output)
+            .await
+            .unwrap(),
+        report_data
+    );
+
+    for buffer in [report_buffer, report_output] {
+        let result = verifier_client
+            .close_report_buffer(&mut environment_context, &user, buffer)
+            .await;
+        Assert::transaction_ok(&result);
+        assert!(EnvironmentContextOperations::get_account(&mut environment_context, buffer)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_write_report_buffer_with_invalid_inputs() {
+    let other_user = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(other_user.pubkey())
+        .build()
+        .await;
+
+    let result = verifier_client
+        .initialize_report_buffer(&mut environment_context, &user, 0, 64)
+        .await;
+    Assert::transaction_ok(&result);
+    let report_buffer = verifier_client.report_buffer_address(&user.pubkey(), 0);
+
+    let result = verifier_client
+        .write_report_buffer(&mut environment_context, &user, report_buffer, 32, vec![1u8; 33])
+        .await;
+    Assert::transaction_error(&result, ErrorCode::ReportBufferTooSmall);
+
+    let result = verifier_client
+        .write_report_buffer(&mut environment_context, &other_user, report_buffer, 0, vec![1u8; 32])
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .close_report_buffer(&mut environment_context, &other_user, report_buffer)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
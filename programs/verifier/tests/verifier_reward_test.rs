@@ -0,0 +1,624 @@
+use crate::common::get_lamports;
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use test_utils::token_operations::TokenOperations;
+use verifier::domain::RewardRecipientConfig;
+use verifier::errors::ErrorCode;
+use verifier::events::RewardsClaimed;
+use verifier::state::{FeeAsset, TOTAL_REWARD_WEIGHT};
+use verifier::util::{Compressor, LogParser};
+
+pub mod common;
+
+// Test reports are generated with an empty report context
+const CONFIG_DIGEST: [u8; 32] = [0; 32];
+// The V3 dummy report's fees with a 9 decimal token and in lamports
+const V3_REPORT_FEE: u64 = 25_234_531;
+const V3_REPORT_NATIVE_FEE: u64 = 118_647;
+
+fn share(amount: u64, weight: u64) -> u64 {
+    (amount as u128 * weight as u128 / TOTAL_REWARD_WEIGHT as u128) as u64
+}
+
+#[tokio::test]
+async fn test_claim_token_rewards() {
+    let claimer = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(claimer.pubkey())
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers.clone(), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let mint = TokenOperations::create_mint(&mut environment_context, &user, 9).await.unwrap();
+    let token_account = TokenOperations::create_token_account(&mut environment_context, &user, &mint, &user.pubkey())
+        .await
+        .unwrap();
+    TokenOperations::mint_to(&mut environment_context, &user, &mint, &token_account, 2 * V3_REPORT_FEE)
+        .await
+        .unwrap();
+
+    let result = verifier_client
+        .set_fee_config(&mut environment_context, &user, mint, true)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Fees collected before the recipients are set are shared among them too
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                fee_token_mint: Some(mint),
+                requester_token_account: Some(token_account),
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let payees = [Keypair::new().pubkey(), Keypair::new().pubkey()];
+    let weights = [TOTAL_REWARD_WEIGHT / 4, TOTAL_REWARD_WEIGHT / 4 * 3];
+    let mut payee_token_accounts = Vec::new();
+    for payee in &payees {
+        payee_token_accounts.push(
+            TokenOperations::create_token_account(&mut environment_context, &user, &mint, payee)
+                .await
+                .unwrap(),
+        );
+    }
+
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![
+                RewardRecipientConfig { signer: signers[0], payee: payees[0], weight: weights[0] },
+                RewardRecipientConfig { signer: signers[1], payee: payees[1], weight: weights[1] },
+            ],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                fee_token_mint: Some(mint),
+                requester_token_account: Some(token_account),
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let reward_pool = verifier_client
+        .read_reward_pool(&mut environment_context, &CONFIG_DIGEST)
+        .await
+        .unwrap();
+    assert_eq!(reward_pool.total_token_fees, 2 * V3_REPORT_FEE);
+
+    // Payees must be paid into their own token accounts
+    let result = verifier_client
+        .claim_rewards(
+            &mut environment_context,
+            &claimer,
+            CONFIG_DIGEST,
+            FeeAsset::Token,
+            Some(mint),
+            &[payee_token_accounts[0], token_account],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+
+    // Anyone may claim on behalf of the recipients
+    let result = verifier_client
+        .claim_rewards(&mut environment_context, &claimer, CONFIG_DIGEST, FeeAsset::Token, Some(mint), &payee_token_accounts)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let events: Vec<RewardsClaimed> = LogParser::parse_all_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert_eq!(events.len(), 2);
+    for (index, payee_token_account) in payee_token_accounts.iter().enumerate() {
+        let expected = share(2 * V3_REPORT_FEE, weights[index]);
+        assert_eq!(events[index].payee, payees[index]);
+        assert_eq!(events[index].amount, expected);
+        assert_eq!(
+            TokenOperations::get_balance(&mut environment_context, payee_token_account).await.unwrap(),
+            expected
+        );
+    }
+
+    // Nothing is left to claim
+    let result = verifier_client
+        .claim_rewards(&mut environment_context, &claimer, CONFIG_DIGEST, FeeAsset::Token, Some(mint), &payee_token_accounts)
+        .await;
+    Assert::transaction_ok(&result);
+    let events: Vec<RewardsClaimed> = LogParser::parse_all_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn test_claim_native_rewards() {
+    let payees = [Keypair::new(), Keypair::new()];
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(payees[0].pubkey())
+        .add_user(payees[1].pubkey())
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers.clone(), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, true, 0)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let weights = [TOTAL_REWARD_WEIGHT / 2, TOTAL_REWARD_WEIGHT / 2];
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![
+                RewardRecipientConfig { signer: signers[2], payee: payees[0].pubkey(), weight: weights[0] },
+                RewardRecipientConfig { signer: signers[3], payee: payees[1].pubkey(), weight: weights[1] },
+            ],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                native_fee: true,
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let mut balances = Vec::new();
+    for payee in &payees {
+        let account = EnvironmentContextOperations::get_account(&mut environment_context, payee.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        balances.push(account.lamports);
+    }
+
+    let payee_addresses = [payees[0].pubkey(), payees[1].pubkey()];
+    let result = verifier_client
+        .claim_rewards(&mut environment_context, &user, CONFIG_DIGEST, FeeAsset::Native, None, &payee_addresses)
+        .await;
+    Assert::transaction_ok(&result);
+
+    for (index, payee) in payees.iter().enumerate() {
+        let account = EnvironmentContextOperations::get_account(&mut environment_context, payee.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(account.lamports, balances[index] + share(V3_REPORT_NATIVE_FEE, weights[index]));
+    }
+
+    // Recipients must be passed in order
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                native_fee: true,
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .claim_rewards(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            FeeAsset::Native,
+            None,
+            &[payee_addresses[1], payee_addresses[0]],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+}
+
+#[tokio::test]
+async fn test_verify_without_reward_pool_fails() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, true, 0)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                native_fee: true,
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::RewardPoolRequired);
+
+    // The pool of another config digest is rejected
+    let other_config_digest = [1; 32];
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, other_config_digest)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                native_fee: true,
+                reward_pool: Some(verifier_client.reward_pool_address(&other_config_digest)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+}
+
+#[tokio::test]
+async fn test_set_reward_recipients_validation() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let (_, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers.clone(), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let recipient = |signer: [u8; 20], weight: u64| RewardRecipientConfig {
+        signer,
+        payee: Keypair::new().pubkey(),
+        weight,
+    };
+
+    // Weights must add up to the total
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![recipient(signers[0], TOTAL_REWARD_WEIGHT / 2)],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidRewardRecipients);
+
+    // Recipients must be signers of the DON config
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![recipient([7; 20], TOTAL_REWARD_WEIGHT)],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidRewardRecipients);
+
+    // Each signer is paid once
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![
+                recipient(signers[0], TOTAL_REWARD_WEIGHT / 2),
+                recipient(signers[0], TOTAL_REWARD_WEIGHT / 2),
+            ],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidRewardRecipients);
+
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &non_owner,
+            CONFIG_DIGEST,
+            0,
+            vec![recipient(signers[0], TOTAL_REWARD_WEIGHT)],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![recipient(signers[0], TOTAL_REWARD_WEIGHT)],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Recipients with nothing left to claim can be replaced
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![recipient(signers[1], TOTAL_REWARD_WEIGHT)],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let reward_pool = verifier_client
+        .read_reward_pool(&mut environment_context, &CONFIG_DIGEST)
+        .await
+        .unwrap();
+    assert_eq!(reward_pool.recipients.len(), 1);
+    assert_eq!(reward_pool.recipients[0].signer, signers[1]);
+}
+
+#[tokio::test]
+async fn test_update_reward_recipients() {
+    let payees = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers.clone(), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_native_fee_config(&mut environment_context, &user, true, 0)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .initialize_reward_pool(&mut environment_context, &user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+
+    for payee in &payees {
+        let result =
+            EnvironmentContextOperations::transfer_lamports(&mut environment_context, &user, payee.pubkey(), 1_000_000_000)
+                .await;
+        Assert::transaction_ok(&result);
+    }
+
+    let weights = [TOTAL_REWARD_WEIGHT / 4, TOTAL_REWARD_WEIGHT / 4 * 3];
+    let result = verifier_client
+        .set_reward_recipients(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            0,
+            vec![
+                RewardRecipientConfig { signer: signers[2], payee: payees[0].pubkey(), weight: weights[0] },
+                RewardRecipientConfig { signer: signers[3], payee: payees[1].pubkey(), weight: weights[1] },
+            ],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                native_fee: true,
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let new_recipients = vec![RewardRecipientConfig {
+        signer: signers[4],
+        payee: payees[2].pubkey(),
+        weight: TOTAL_REWARD_WEIGHT,
+    }];
+
+    // Recipients cannot be replaced before they have claimed
+    let result = verifier_client
+        .set_reward_recipients(&mut environment_context, &user, CONFIG_DIGEST, 0, new_recipients.clone())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::UnclaimedRewards);
+
+    // Recipients can be claimed for separately
+    let mut balances = Vec::new();
+    for payee in &payees {
+        balances.push(get_lamports(&mut environment_context, payee.pubkey()).await);
+    }
+    let result = verifier_client
+        .claim_rewards_for(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            FeeAsset::Native,
+            None,
+            &[(1, payees[1].pubkey())],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(get_lamports(&mut environment_context, payees[0].pubkey()).await, balances[0]);
+    assert_eq!(
+        get_lamports(&mut environment_context, payees[1].pubkey()).await,
+        balances[1] + share(V3_REPORT_NATIVE_FEE, weights[1])
+    );
+
+    let result = verifier_client
+        .set_reward_recipients(&mut environment_context, &user, CONFIG_DIGEST, 0, new_recipients.clone())
+        .await;
+    Assert::transaction_error(&result, ErrorCode::UnclaimedRewards);
+
+    // Indices must be in range and match the accounts
+    let result = verifier_client
+        .claim_rewards_for(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            FeeAsset::Native,
+            None,
+            &[(2, payees[0].pubkey())],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+
+    let result = verifier_client
+        .claim_rewards_for(
+            &mut environment_context,
+            &user,
+            CONFIG_DIGEST,
+            FeeAsset::Native,
+            None,
+            &[(0, payees[0].pubkey())],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        get_lamports(&mut environment_context, payees[0].pubkey()).await,
+        balances[0] + share(V3_REPORT_NATIVE_FEE, weights[0])
+    );
+
+    let result = verifier_client
+        .set_reward_recipients(&mut environment_context, &user, CONFIG_DIGEST, 0, new_recipients)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The new recipient only shares in fees collected after the update
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                native_fee: true,
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .claim_rewards(&mut environment_context, &user, CONFIG_DIGEST, FeeAsset::Native, None, &[payees[2].pubkey()])
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        get_lamports(&mut environment_context, payees[2].pubkey()).await,
+        balances[2] + V3_REPORT_NATIVE_FEE
+    );
+}
@@ -0,0 +1,346 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::report::{get_signers, sign_hash, Signer as DonSigner};
+use verifier::errors::ErrorCode;
+use verifier::events::{ConfigRotated, ConfigSet};
+use verifier::evm::Encoder;
+use verifier::state::VerifierAccount;
+use verifier::util::LogParser;
+
+pub mod common;
+
+fn addresses(signers: &[DonSigner]) -> Vec<[u8; 20]> {
+    signers.iter().map(|s| s.signer_address).collect()
+}
+
+fn rotation_hash(
+    active_don_config_id: &[u8; 24],
+    new_signers: &[[u8; 20]],
+    new_f: u8,
+    new_index: u16,
+    rotation_nonce: u32,
+) -> [u8; 32] {
+    let mut sorted_signers = new_signers.to_vec();
+    sorted_signers.sort_unstable();
+    let new_don_config_id = Encoder::compute_don_config_id(&Encoder::encode_don_config_id(&sorted_signers, new_f));
+
+    Encoder::compute_config_rotation_hash(
+        &verifier::ID.to_bytes(),
+        active_don_config_id,
+        &new_don_config_id,
+        new_index,
+        rotation_nonce,
+    )
+}
+
+#[tokio::test]
+async fn test_rotate_config_fails_when_disabled() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let current_signers = get_signers(16);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, addresses(&current_signers), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    let new_signers = addresses(&get_signers(10));
+    let hash = rotation_hash(&verifier_account.don_configs[0].don_config_id, &new_signers, 3, 1, 0);
+
+    let result = verifier_client
+        .rotate_config(&mut environment_context, &user, new_signers, 3, sign_hash(hash, &current_signers[..6]))
+        .await;
+
+    Assert::transaction_error(&result, ErrorCode::ConfigRotationDisabled);
+}
+
+#[tokio::test]
+async fn test_set_config_rotation_enabled_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = verifier_client
+        .set_config_rotation_enabled(&mut environment_context, &non_owner, true)
+        .await;
+
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_rotate_config() {
+    let rotation_payer = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(rotation_payer.pubkey())
+        .build()
+        .await;
+
+    let current_signers = get_signers(16);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, addresses(&current_signers), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_rotation_enabled(&mut environment_context, &user, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+    let active_don_config_id = verifier_account.don_configs[0].don_config_id;
+
+    let new_signers = addresses(&get_signers(10));
+    let hash = rotation_hash(&active_don_config_id, &new_signers, 3, 1, 0);
+
+    // f + 1 signatures from the active config, submitted by someone other than the owner
+    let result = verifier_client
+        .rotate_config(&mut environment_context, &rotation_payer, new_signers.clone(), 3, sign_hash(hash, &current_signers[..6]))
+        .await;
+    Assert::transaction_ok(&result);
+
+    let log_messages = result.unwrap().metadata.unwrap().log_messages;
+    let logs: Option<ConfigSet> = LogParser::parse_logs(log_messages.clone());
+    let logs = logs.expect("Logs should be present");
+    assert_eq!(logs.don_config_index, 1);
+    assert_eq!(logs.f, 3);
+
+    let logs: Option<ConfigRotated> = LogParser::parse_logs(log_messages);
+    let logs = logs.expect("Logs should be present");
+    assert_eq!(logs.config_rotation_nonce, 0);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    let mut sorted_signers = new_signers;
+    sorted_signers.sort_unstable();
+
+    assert_eq!(verifier_account.don_configs.len(), 2);
+    let rotated_config = &verifier_account.don_configs[1];
+    assert_eq!(rotated_config.f, 3);
+    assert_eq!(rotated_config.is_active, 1);
+    assert_eq!(
+        rotated_config.signers.iter().map(|s| s.key).collect::<Vec<_>>(),
+        sorted_signers
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_config_fails_with_f_signatures() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let current_signers = get_signers(16);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, addresses(&current_signers), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_rotation_enabled(&mut environment_context, &user, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    let new_signers = addresses(&get_signers(10));
+    let hash = rotation_hash(&verifier_account.don_configs[0].don_config_id, &new_signers, 3, 1, 0);
+
+    // Only f signatures
+    let result = verifier_client
+        .rotate_config(&mut environment_context, &user, new_signers, 3, sign_hash(hash, &current_signers[..5]))
+        .await;
+
+    Assert::transaction_error(&result, ErrorCode::BadVerification);
+}
+
+#[tokio::test]
+async fn test_rotate_config_fails_with_signatures_from_unregistered_signers() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let current_signers = get_signers(16);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, addresses(&current_signers), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_rotation_enabled(&mut environment_context, &user, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    // The incoming signers cannot authorise their own rotation
+    let new_signer_keys = get_signers(10);
+    let new_signers = addresses(&new_signer_keys);
+    let hash = rotation_hash(&verifier_account.don_configs[0].don_config_id, &new_signers, 3, 1, 0);
+
+    let result = verifier_client
+        .rotate_config(&mut environment_context, &user, new_signers, 3, sign_hash(hash, &new_signer_keys[..6]))
+        .await;
+
+    Assert::transaction_error(&result, ErrorCode::BadVerification);
+}
+
+#[tokio::test]
+async fn test_rotate_config_fails_with_signatures_for_another_config() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let current_signers = get_signers(16);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, addresses(&current_signers), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_rotation_enabled(&mut environment_context, &user, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    // Signatures approve a different signer set than the one submitted
+    let approved_signers = addresses(&get_signers(10));
+    let hash = rotation_hash(&verifier_account.don_configs[0].don_config_id, &approved_signers, 3, 1, 0);
+
+    let result = verifier_client
+        .rotate_config(&mut environment_context, &user, addresses(&get_signers(10)), 3, sign_hash(hash, &current_signers[..6]))
+        .await;
+
+    Assert::transaction_error(&result, ErrorCode::BadVerification);
+}
+
+#[tokio::test]
+async fn test_rotate_config_cannot_be_replayed() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let current_signers = get_signers(16);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, addresses(&current_signers), 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_config_rotation_enabled(&mut environment_context, &user, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    let new_signers = addresses(&get_signers(10));
+    let hash = rotation_hash(&verifier_account.don_configs[0].don_config_id, &new_signers, 3, 1, 0);
+    let signatures = sign_hash(hash, &current_signers[..6]);
+
+    let result = verifier_client
+        .rotate_config(&mut environment_context, &user, new_signers.clone(), 3, signatures.clone())
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The owner removes the rotated config
+    let result = verifier_client
+        .remove_latest_config(&mut environment_context, &user)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+    assert_eq!(verifier_account.don_configs.len(), 1);
+    assert_eq!(verifier_account.config_rotation_nonce, 1);
+
+    // Replaying the public signatures does not restore it
+    let result = verifier_client
+        .rotate_config(&mut environment_context, &user, new_signers, 3, signatures)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::BadVerification);
+}
@@ -0,0 +1,121 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::events::AcceptedSchemaVersionsSet;
+use verifier::state::MAX_NUMBER_OF_SCHEMA_VERSIONS;
+use verifier::util::{Compressor, LogParser};
+
+pub mod common;
+
+#[tokio::test]
+async fn test_verify_with_accepted_schema_versions() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Only v2 reports are accepted
+    let result = verifier_client
+        .set_accepted_schema_versions(&mut environment_context, &user, vec![2])
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<AcceptedSchemaVersionsSet> =
+        LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert_eq!(event.expect("Logs should be present").schema_versions, vec![2]);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::SchemaVersionNotAccepted);
+
+    let result = verifier_client
+        .set_accepted_schema_versions(&mut environment_context, &user, vec![2, 3])
+        .await;
+    Assert::transaction_ok(&result);
+
+    let verifier_account = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+    assert_eq!(verifier_account.accepted_schema_versions.as_slice(), &[2, 3]);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Clearing the list accepts every version again
+    let result = verifier_client
+        .set_accepted_schema_versions(&mut environment_context, &user, vec![])
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+}
+
+#[tokio::test]
+async fn test_set_accepted_schema_versions_with_invalid_inputs() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let result = verifier_client
+        .set_accepted_schema_versions(&mut environment_context, &user, vec![3, 3])
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+
+    let too_many = (0..=MAX_NUMBER_OF_SCHEMA_VERSIONS as u16).collect();
+    let result = verifier_client
+        .set_accepted_schema_versions(&mut environment_context, &user, too_many)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+}
+
+#[tokio::test]
+async fn test_set_accepted_schema_versions_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = verifier_client
+        .set_accepted_schema_versions(&mut environment_context, &non_owner, vec![3])
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
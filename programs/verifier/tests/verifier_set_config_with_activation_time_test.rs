@@ -9,6 +9,7 @@ use verifier::events::ConfigSet;
 use verifier::state::VerifierAccount;
 use verifier::util::LogParser;
 use verifier::state::MAX_NUMBER_OF_DON_CONFIGS;
+use hex_literal::hex;
 
 pub mod common;
 
@@ -775,4 +776,89 @@ async fn test_setting_maximum_configs_and_above_max() {
    
     // Should fail with MaxNumberOfConfigsReached error
     Assert::transaction_error(&result, ErrorCode::MaxNumberOfConfigsReached);
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_set_config_with_matching_expected_don_config_id() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    // Same fixed signers as `test_verify_emits_correct_don_config_id`, in reverse order
+    let signers: Vec<[u8; 20]> = (1..17)
+        .rev()
+        .map(|i| {
+            let mut signer = [0u8; 20];
+            signer[19] = i as u8;
+            signer
+        })
+        .collect();
+
+    let expected_don_config_id = hex!("56a39dda91c8613fb4720b757cc603299afbcb36340a1cf7");
+
+    let result = verifier_client
+        .set_config_with_activation_time_checked(
+            &mut environment_context,
+            &user,
+            signers,
+            5,
+            1_600_000_000,
+            expected_don_config_id,
+        )
+        .await;
+
+    Assert::transaction_ok(&result);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    assert_eq!(verifier_account.don_configs.len(), 1);
+    assert_eq!(verifier_account.don_configs[0].don_config_id, expected_don_config_id);
+}
+
+#[tokio::test]
+async fn test_set_config_with_mismatched_expected_don_config_id_fails() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (_, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+
+    let result = verifier_client
+        .set_config_with_activation_time_checked(
+            &mut environment_context,
+            &user,
+            signers,
+            5,
+            1_600_000_000,
+            [1u8; 24],
+        )
+        .await;
+
+    Assert::transaction_error(&result, ErrorCode::DonConfigIdMismatch);
+
+    let verifier_account: VerifierAccount = verifier_client
+        .read_verifier_account(&mut environment_context)
+        .await
+        .unwrap();
+
+    assert!(verifier_account.don_configs.is_empty());
+}
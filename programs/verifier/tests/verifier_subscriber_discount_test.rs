@@ -0,0 +1,263 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use anchor_lang::prelude::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::{VerifierClient, VerifyOptionalAccounts};
+use test_utils::report::{generate_report_with_signers, V3Report};
+use test_utils::token_operations::TokenOperations;
+use verifier::errors::ErrorCode;
+use verifier::events::{FeePaid, FeeWaiverSet, SubscriberDiscountSet};
+use verifier::evm::Encoder;
+use verifier::state::GLOBAL_DISCOUNT_FEED_ID;
+use verifier::util::{Compressor, LogParser};
+
+pub mod common;
+
+const FEE_TOKEN_DECIMALS: u8 = 9;
+// The V3 dummy report's `link_fee` scaled to `FEE_TOKEN_DECIMALS`
+const V3_REPORT_FEE: u64 = 25_234_531;
+// Test reports are generated with an empty report context
+const CONFIG_DIGEST: [u8; 32] = [0; 32];
+
+fn feed_id(report: &[u8]) -> [u8; 32] {
+    let signed_report = Encoder::parse_signed_report(report).unwrap();
+    signed_report.report_data[..32].try_into().unwrap()
+}
+
+/// Sets a config for `report`'s signers and enables token fees, returning the mint and the
+/// user's funded token account
+async fn setup_token_fees(
+    environment_context: &mut ProgramTestContext,
+    verifier_client: &VerifierClient,
+    user: &Keypair,
+    signers: Vec<[u8; 20]>,
+) -> (Pubkey, Pubkey) {
+    let result = verifier_client
+        .set_config_with_activation_time(environment_context, user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let mint = TokenOperations::create_mint(environment_context, user, FEE_TOKEN_DECIMALS)
+        .await
+        .unwrap();
+    let token_account = TokenOperations::create_token_account(environment_context, user, &mint, &user.pubkey())
+        .await
+        .unwrap();
+    TokenOperations::mint_to(environment_context, user, &mint, &token_account, 10 * V3_REPORT_FEE)
+        .await
+        .unwrap();
+
+    let result = verifier_client
+        .initialize_reward_pool(environment_context, user, CONFIG_DIGEST)
+        .await;
+    Assert::transaction_ok(&result);
+    let result = verifier_client
+        .set_fee_config(environment_context, user, mint, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    (mint, token_account)
+}
+
+#[tokio::test]
+async fn test_verify_with_global_discount() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let (mint, token_account) =
+        setup_token_fees(&mut environment_context, &verifier_client, &user, signers).await;
+
+    let result = verifier_client
+        .initialize_subscriber_discount(&mut environment_context, &user, user.pubkey(), GLOBAL_DISCOUNT_FEED_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let subscriber_discount = verifier_client.subscriber_discount_address(&user.pubkey(), &GLOBAL_DISCOUNT_FEED_ID);
+    let result = verifier_client
+        .set_subscriber_discount(&mut environment_context, &user, subscriber_discount, 2_500)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<SubscriberDiscountSet> =
+        LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.subscriber, user.pubkey());
+    assert_eq!(event.feed_id, GLOBAL_DISCOUNT_FEED_ID);
+    assert_eq!(event.discount_bps, 2_500);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                fee_token_mint: Some(mint),
+                requester_token_account: Some(token_account),
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                subscriber_discount: Some(subscriber_discount),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let discounted_fee = V3_REPORT_FEE - V3_REPORT_FEE / 4;
+    let event: Option<FeePaid> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    assert_eq!(event.expect("Logs should be present").amount, discounted_fee);
+    assert_eq!(
+        TokenOperations::get_balance(&mut environment_context, &verifier_client.fee_vault_address(&mint))
+            .await
+            .unwrap(),
+        discounted_fee
+    );
+}
+
+#[tokio::test]
+async fn test_verify_with_fee_waiver() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let feed_id = feed_id(&report);
+    let (mint, _) = setup_token_fees(&mut environment_context, &verifier_client, &user, signers).await;
+
+    let result = verifier_client
+        .initialize_subscriber_discount(&mut environment_context, &user, user.pubkey(), feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let subscriber_discount = verifier_client.subscriber_discount_address(&user.pubkey(), &feed_id);
+    let result = verifier_client
+        .set_fee_waiver(&mut environment_context, &user, subscriber_discount, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<FeeWaiverSet> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.subscriber, user.pubkey());
+    assert_eq!(event.feed_id, feed_id);
+    assert!(event.waived);
+
+    // Waived subscribers need none of the fee accounts
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                subscriber_discount: Some(subscriber_discount),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_ok(&result);
+    assert_eq!(
+        TokenOperations::get_balance(&mut environment_context, &verifier_client.fee_vault_address(&mint))
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_verify_with_discount_for_another_feed_fails() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let (mint, token_account) =
+        setup_token_fees(&mut environment_context, &verifier_client, &user, signers).await;
+
+    let other_feed_id = [1u8; 32];
+    let result = verifier_client
+        .initialize_subscriber_discount(&mut environment_context, &user, user.pubkey(), other_feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                fee_token_mint: Some(mint),
+                requester_token_account: Some(token_account),
+                reward_pool: Some(verifier_client.reward_pool_address(&CONFIG_DIGEST)),
+                subscriber_discount: Some(verifier_client.subscriber_discount_address(&user.pubkey(), &other_feed_id)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::SubscriberDiscountMismatch);
+}
+
+#[tokio::test]
+async fn test_set_subscriber_discount_with_invalid_inputs() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    // Anyone may create the record
+    let result = verifier_client
+        .initialize_subscriber_discount(&mut environment_context, &non_owner, non_owner.pubkey(), GLOBAL_DISCOUNT_FEED_ID)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let subscriber_discount = verifier_client.subscriber_discount_address(&non_owner.pubkey(), &GLOBAL_DISCOUNT_FEED_ID);
+    let result = verifier_client
+        .set_subscriber_discount(&mut environment_context, &non_owner, subscriber_discount, 2_500)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .set_fee_waiver(&mut environment_context, &non_owner, subscriber_discount, true)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+
+    let result = verifier_client
+        .set_subscriber_discount(&mut environment_context, &user, subscriber_discount, 10_001)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+}
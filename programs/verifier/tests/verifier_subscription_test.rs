@@ -0,0 +1,290 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use access_controller::AccessController;
+use solana_program_test::tokio;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::util::Compressor;
+use std::mem::{offset_of, size_of};
+
+pub mod common;
+
+#[tokio::test]
+async fn test_verify_with_subscription_until_expiry() {
+    let subscriber = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(subscriber.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let now = EnvironmentContextOperations::get_clock(&mut environment_context)
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let valid_until = now + 3600;
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, subscriber.pubkey(), valid_until)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify(&mut environment_context, &subscriber, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The last second of the subscription is still valid
+    EnvironmentContextOperations::set_unix_timestamp(&mut environment_context, valid_until)
+        .await
+        .unwrap();
+    let result = verifier_client
+        .verify(&mut environment_context, &subscriber, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_ok(&result);
+
+    EnvironmentContextOperations::set_unix_timestamp(&mut environment_context, valid_until + 1)
+        .await
+        .unwrap();
+    let result = verifier_client
+        .verify(&mut environment_context, &subscriber, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_extend_subscription_never_shortens() {
+    let subscriber = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let now = EnvironmentContextOperations::get_clock(&mut environment_context)
+        .await
+        .unwrap()
+        .unix_timestamp;
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, subscriber.pubkey(), now + 7200)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, subscriber.pubkey(), now + 3600)
+        .await;
+    Assert::transaction_ok(&result);
+
+    assert_eq!(
+        access_controller_client
+            .subscription_valid_until(&mut environment_context, &subscriber.pubkey())
+            .await,
+        Some(now + 7200)
+    );
+
+    // Already expired grants are rejected
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, Keypair::new().pubkey(), now - 1)
+        .await;
+    Assert::transaction_error(&result, access_controller::ErrorCode::InvalidInput);
+}
+
+#[tokio::test]
+async fn test_extend_subscription_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let result = access_controller_client
+        .unwrap()
+        .extend_subscription(&mut environment_context, &non_owner, non_owner.pubkey(), i64::MAX)
+        .await;
+
+    Assert::transaction_error(&result, access_controller::ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_purge_expired_subscriptions() {
+    let cranker = Keypair::new();
+    let expiring = Keypair::new().pubkey();
+    let lasting = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(cranker.pubkey())
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let now = EnvironmentContextOperations::get_clock(&mut environment_context)
+        .await
+        .unwrap()
+        .unix_timestamp;
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, expiring, now + 60)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, lasting, now + 3600)
+        .await;
+    Assert::transaction_ok(&result);
+
+    EnvironmentContextOperations::set_unix_timestamp(&mut environment_context, now + 61)
+        .await
+        .unwrap();
+
+    // Anyone can purge
+    let result = access_controller_client
+        .purge_expired_subscriptions(&mut environment_context, &cranker)
+        .await;
+    Assert::transaction_ok(&result);
+
+    assert_eq!(
+        access_controller_client
+            .subscription_valid_until(&mut environment_context, &expiring)
+            .await,
+        None
+    );
+    assert_eq!(
+        access_controller_client
+            .subscription_valid_until(&mut environment_context, &lasting)
+            .await,
+        Some(now + 3600)
+    );
+}
+
+#[tokio::test]
+async fn test_remove_access_ends_subscription() {
+    let subscriber = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+
+    let result = access_controller_client
+        .extend_subscription(&mut environment_context, &user, subscriber, i64::MAX)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = access_controller_client
+        .remove_access(&mut environment_context, &user, subscriber)
+        .await;
+    Assert::transaction_ok(&result);
+
+    assert_eq!(
+        access_controller_client
+            .subscription_valid_until(&mut environment_context, &subscriber)
+            .await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_realloc_account_keeps_access_list() {
+    let listed = Keypair::new().pubkey();
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        access_controller_client,
+        access_controller_account_address,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+    let access_controller_client = access_controller_client.unwrap();
+    let access_controller_account = access_controller_account_address.unwrap();
+
+    let result = access_controller_client
+        .add_access(&mut environment_context, &user, listed)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // Shrink the account back to the layout from before subscriptions were added
+    let old_size = 8 + offset_of!(AccessController, subscriptions);
+    let mut account = EnvironmentContextOperations::get_account(&mut environment_context, access_controller_account)
+        .await
+        .unwrap()
+        .unwrap();
+    account.data.truncate(old_size);
+    account.lamports = Rent::default().minimum_balance(old_size);
+    environment_context.set_account(&access_controller_account, &account.into());
+
+    assert!(access_controller_client
+        .read_access_controller_account(&mut environment_context)
+        .await
+        .is_err());
+
+    let result = access_controller_client
+        .realloc_account(&mut environment_context, &user)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let size = EnvironmentContextOperations::get_account_data_size(&mut environment_context, access_controller_account)
+        .await
+        .unwrap();
+    assert_eq!(size, (8 + size_of::<AccessController>()) as u64);
+
+    // Existing entries survive and the new fields start out empty
+    let access_controller = access_controller_client
+        .read_access_controller_account(&mut environment_context)
+        .await
+        .unwrap();
+    assert_eq!(access_controller.owner, user.pubkey());
+    assert!(access_controller.access_list.binary_search(&user.pubkey()).is_ok());
+    assert!(access_controller.access_list.binary_search(&listed).is_ok());
+    assert_eq!(access_controller.subscriptions.len(), 0);
+}
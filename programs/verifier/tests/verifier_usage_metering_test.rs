@@ -0,0 +1,179 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::report::{generate_report_with_signers, V2Report, V3Report};
+use verifier::errors::ErrorCode;
+use verifier::events::UsageSnapshot;
+use verifier::evm::Encoder;
+use verifier::util::{Compressor, LogParser};
+
+pub mod common;
+
+fn feed_id(report: &[u8]) -> [u8; 32] {
+    let signed_report = Encoder::parse_signed_report(report).unwrap();
+    signed_report.report_data[..32].try_into().unwrap()
+}
+
+#[tokio::test]
+async fn test_verify_with_usage_metering() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let feed_id = feed_id(&report);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_usage_metering_enabled(&mut environment_context, &user, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The usage counter is required while metering is enabled
+    let result = verifier_client
+        .verify(&mut environment_context, &user, Compressor::compress(&report), None)
+        .await;
+    Assert::transaction_error(&result, ErrorCode::UsageCounterRequired);
+
+    let result = verifier_client
+        .initialize_usage_counter(&mut environment_context, &user, user.pubkey(), feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let usage_counter = verifier_client.usage_counter_address(&user.pubkey(), &feed_id);
+    for _ in 0..2 {
+        let result = verifier_client
+            .verify_with_optional_accounts(
+                &mut environment_context,
+                &user,
+                Compressor::compress(&report),
+                None,
+                VerifyOptionalAccounts {
+                    usage_counter: Some(usage_counter),
+                    ..Default::default()
+                },
+            )
+            .await;
+        Assert::transaction_ok(&result);
+    }
+
+    let counter = verifier_client
+        .read_usage_counter(&mut environment_context, usage_counter)
+        .await
+        .unwrap();
+    assert_eq!(counter.requester, user.pubkey());
+    assert_eq!(counter.feed_id, feed_id);
+    assert_eq!(counter.verifications, 2);
+
+    let result = verifier_client
+        .snapshot_usage(&mut environment_context, &user, 1, &[usage_counter])
+        .await;
+    Assert::transaction_ok(&result);
+
+    let event: Option<UsageSnapshot> = LogParser::parse_logs(result.unwrap().metadata.unwrap().log_messages);
+    let event = event.expect("Logs should be present");
+    assert_eq!(event.requester, user.pubkey());
+    assert_eq!(event.feed_id, feed_id);
+    assert_eq!(event.billing_period, 1);
+    assert_eq!(event.verifications, 2);
+
+    let counter = verifier_client
+        .read_usage_counter(&mut environment_context, usage_counter)
+        .await
+        .unwrap();
+    assert_eq!(counter.verifications, 0);
+    assert_eq!(counter.last_period_verifications, 2);
+    assert_eq!(counter.last_billing_period, 1);
+}
+
+#[tokio::test]
+async fn test_verify_with_usage_counter_for_another_feed_fails() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let (other_report, _) = generate_report_with_signers::<V2Report>(16, 6, None, None);
+    let other_feed_id = feed_id(&other_report);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .set_usage_metering_enabled(&mut environment_context, &user, true)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .initialize_usage_counter(&mut environment_context, &user, user.pubkey(), other_feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_with_optional_accounts(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            None,
+            VerifyOptionalAccounts {
+                usage_counter: Some(verifier_client.usage_counter_address(&user.pubkey(), &other_feed_id)),
+                ..Default::default()
+            },
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::InvalidInputs);
+}
+
+#[tokio::test]
+async fn test_snapshot_usage_with_non_owner() {
+    let non_owner = Keypair::new();
+    let VerifierTestSetup {
+        mut environment_context,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_user(non_owner.pubkey())
+        .build()
+        .await;
+
+    let feed_id = [1u8; 32];
+    let result = verifier_client
+        .initialize_usage_counter(&mut environment_context, &non_owner, non_owner.pubkey(), feed_id)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .snapshot_usage(
+            &mut environment_context,
+            &non_owner,
+            1,
+            &[verifier_client.usage_counter_address(&non_owner.pubkey(), &feed_id)],
+        )
+        .await;
+    Assert::transaction_error(&result, ErrorCode::Unauthorized);
+}
@@ -0,0 +1,186 @@
+use crate::common::test_setup::{VerifierTestSetup, VerifierTestSetupBuilder};
+use anchor_lang::prelude::AccountMeta;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AnchorSerialize, Discriminator};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use test_utils::assert::Assert;
+use test_utils::client::VerifyOptionalAccounts;
+use test_utils::environment_context_operations::EnvironmentContextOperations;
+use test_utils::report::{generate_report_with_signers, V3Report};
+use test_consumer::ReportConsumed;
+use verifier::events::ReportVerified;
+use verifier::evm::Encoder;
+use verifier::util::{Compressor, LogParser};
+
+pub mod common;
+
+/// The discriminator of the test consumer's `consume`
+fn consume_discriminator() -> [u8; 8] {
+    test_consumer::instruction::Consume::DISCRIMINATOR.try_into().unwrap()
+}
+
+#[tokio::test]
+async fn test_verify_and_forward() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_program("test_consumer", test_consumer::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, Some(1_700_000_000), None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // The consumer requires the verifier's forwarder to sign the call
+    let feed_id = Encoder::parse_signed_report(&report).unwrap().report_data[..32].to_vec();
+    let result = verifier_client
+        .verify_and_forward(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            (test_consumer::ID, consume_discriminator()),
+            VerifyOptionalAccounts::default(),
+            vec![],
+        )
+        .await;
+    Assert::transaction_ok(&result);
+
+    let logs = result.unwrap().metadata.unwrap().log_messages;
+    let event: Option<ReportVerified> = LogParser::parse_logs(logs.clone());
+    assert_eq!(event.expect("Logs should be present").feed_id, feed_id[..]);
+
+    let consumed: ReportConsumed = LogParser::parse_logs(logs).expect("Report should be consumed");
+    assert_eq!(consumed.feed_id, feed_id[..]);
+    assert_eq!(consumed.observations_timestamp, 1_700_000_000);
+}
+
+#[tokio::test]
+async fn test_verify_and_forward_does_not_forward_a_forged_report() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_program("test_consumer", test_consumer::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, Some(1_700_000_000), None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    // A forged report placed after the discriminator, where an arbitrary consumer data prefix
+    // would have put it, is dropped with the rest of the trailing data
+    let report_data = Encoder::parse_signed_report(&report).unwrap().report_data;
+    let mut forged_report = Encoder::decode_report(report_data).unwrap();
+    forged_report.feed_id = [9; 32];
+    forged_report.observations_timestamp = 1_800_000_000;
+
+    let mut instruction = verifier_client.verify_and_forward_instruction(
+        &user,
+        Compressor::compress(&report),
+        (test_consumer::ID, consume_discriminator()),
+        VerifyOptionalAccounts::default(),
+        vec![],
+    );
+    forged_report.serialize(&mut instruction.data).unwrap();
+
+    let result = EnvironmentContextOperations::send_transaction(
+        &mut environment_context,
+        &[instruction],
+        Some(&user.pubkey()),
+        &[&user],
+    )
+    .await;
+    Assert::transaction_ok(&result);
+
+    let logs = result.unwrap().metadata.unwrap().log_messages;
+    let consumed: ReportConsumed = LogParser::parse_logs(logs).expect("Report should be consumed");
+    assert_eq!(consumed.feed_id, report_data[..32]);
+    assert_eq!(consumed.observations_timestamp, 1_700_000_000);
+}
+
+#[tokio::test]
+async fn test_consumer_rejects_reports_not_forwarded_by_the_verifier() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .add_program("test_consumer", test_consumer::ID)
+        .build()
+        .await;
+
+    // The same data the verifier would forward, sent without its signature
+    let (report, _) = generate_report_with_signers::<V3Report>(16, 6, Some(1_700_000_000), None);
+    let report_data = Encoder::parse_signed_report(&report).unwrap().report_data;
+    let mut data = consume_discriminator().to_vec();
+    Encoder::decode_report(report_data).unwrap().serialize(&mut data).unwrap();
+
+    let instruction = Instruction {
+        program_id: test_consumer::ID,
+        accounts: vec![AccountMeta::new_readonly(verifier_client.forwarder_address(), false)],
+        data,
+    };
+    let result = EnvironmentContextOperations::send_transaction(
+        &mut environment_context,
+        &[instruction],
+        Some(&user.pubkey()),
+        &[&user],
+    )
+    .await;
+    Assert::transaction_error(&result, anchor_lang::error::ErrorCode::AccountNotSigner);
+}
+
+#[tokio::test]
+async fn test_verify_and_forward_to_non_executable_account_fails() {
+    let VerifierTestSetup {
+        mut environment_context,
+        user,
+        verifier_client,
+        ..
+    } = VerifierTestSetupBuilder::new()
+        .program_name("verifier")
+        .program_id(verifier::ID)
+        .access_controller(access_controller::ID)
+        .build()
+        .await;
+
+    let (report, signers) = generate_report_with_signers::<V3Report>(16, 6, None, None);
+    let result = verifier_client
+        .set_config_with_activation_time(&mut environment_context, &user, signers, 5, 1_600_000_000)
+        .await;
+    Assert::transaction_ok(&result);
+
+    let result = verifier_client
+        .verify_and_forward(
+            &mut environment_context,
+            &user,
+            Compressor::compress(&report),
+            (user.pubkey(), consume_discriminator()),
+            VerifyOptionalAccounts::default(),
+            vec![],
+        )
+        .await;
+    Assert::transaction_error(&result, anchor_lang::error::ErrorCode::ConstraintExecutable);
+}
@@ -7,7 +7,7 @@ use solana_program::pubkey::Pubkey;
 use solana_program_test::tokio;
 use solana_sdk::signer::keypair::Keypair;
 use solana_sdk::signer::Signer;
-use test_utils::report::{generate_report_with_signers, get_signers, DummyReport, V3Report};
+use test_utils::report::{generate_report_with_signers, get_signers, DummyReport, V2Report, V3Report};
 use verifier::state::{VerifierAccount, MAX_NUMBER_OF_DON_CONFIGS};
 use std::mem::size_of;
 use anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram;
@@ -253,7 +253,7 @@ async fn test_verify_fails_with_invalid_access_controller() {
     assert_ne!(verifier_account.verifier_account_config.access_controller, Pubkey::default());
     assert_ne!(verifier_account.verifier_account_config.access_controller, dummy_access_controller);
 
-    let (report, signers) = generate_report_with_signers::<DummyReport>(16, 6, None, None);
+    let (report, signers) = generate_report_with_signers::<V2Report>(16, 6, None, None);
     let compressed_report = Compressor::compress(&report);
 
     let result = verifier_client
@@ -589,7 +589,7 @@ async fn test_can_verify_older_reports_with_older_configs() {
 }
 
 #[tokio::test]
-async fn test_can_verify_non_standard_reports() {
+async fn test_verify_rejects_non_standard_reports() {
     let VerifierTestSetup {
         mut environment_context,
         user,
@@ -602,7 +602,7 @@ async fn test_can_verify_non_standard_reports() {
         .build()
         .await;
 
-    // Generate report with signers
+    // A report whose feed id carries schema version 0, which has no known layout
     let (report, signers) = generate_report_with_signers::<DummyReport>(16, 6, None, None);
     let compressed_report = Compressor::compress(&report);
 
@@ -628,7 +628,7 @@ async fn test_can_verify_non_standard_reports() {
         )
         .await;
 
-    Assert::transaction_ok(&result);
+    Assert::transaction_error(&result, ErrorCode::UnknownSchemaVersion);
 }
 #[tokio::test]
 async fn test_rolling_out_configuration() {
@@ -924,7 +924,7 @@ async fn test_verify_with_disabled_access_controller() {
     assert_ne!(access_controller_account_address.unwrap(), Pubkey::default());
     assert_ne!(verifier_account.verifier_account_config.access_controller, Pubkey::default());
 
-    let (report, signers) = generate_report_with_signers::<DummyReport>(16, 6, None, None);
+    let (report, signers) = generate_report_with_signers::<V2Report>(16, 6, None, None);
     let compressed_report = Compressor::compress(&report);
 
     // Set up the configuration
@@ -1,14 +1,37 @@
-use access_controller::accounts::{AddAccess, Initialize, RemoveAccess};
+use access_controller::accounts::{
+    AcceptOwnership, AddAccess, BatchUpdateAccess, ExtendSubscription, GrantAccess, Initialize, InitializeFeedAccessList,
+    MigrateAccessList, PurgeExpiredSubscriptions, ReallocAccount, RemoveAccess, RevokeAccess,
+    SetAccessListMode, TransferOwnership, UpdateAdmins, UpdateFeedAccess, UpdateProgramAccess,
+};
+use access_controller::instruction::AcceptOwnership as AcceptOwnershipParams;
 use access_controller::instruction::AddAccess as AddAccessParams;
+use access_controller::instruction::AddAdmin as AddAdminParams;
+use access_controller::instruction::AddFeedAccess as AddFeedAccessParams;
+use access_controller::instruction::AddProgramAccess as AddProgramAccessParams;
+use access_controller::instruction::BatchAddAccess as BatchAddAccessParams;
+use access_controller::instruction::BatchRemoveAccess as BatchRemoveAccessParams;
+use access_controller::instruction::ExtendSubscription as ExtendSubscriptionParams;
+use access_controller::instruction::GrantAccess as GrantAccessParams;
 use access_controller::instruction::Initialize as InitializeParams;
+use access_controller::instruction::InitializeFeedAccessList as InitializeFeedAccessListParams;
+use access_controller::instruction::MigrateAccessList as MigrateAccessListParams;
+use access_controller::instruction::PurgeExpiredSubscriptions as PurgeExpiredSubscriptionsParams;
+use access_controller::instruction::ReallocAccount as ReallocAccountParams;
 use access_controller::instruction::RemoveAccess as RemoveAccessParams;
+use access_controller::instruction::RemoveAdmin as RemoveAdminParams;
+use access_controller::instruction::RemoveFeedAccess as RemoveFeedAccessParams;
+use access_controller::instruction::RemoveProgramAccess as RemoveProgramAccessParams;
+use access_controller::instruction::RevokeAccess as RevokeAccessParams;
+use access_controller::instruction::SetAccessListMode as SetAccessListModeParams;
+use access_controller::instruction::TransferOwnership as TransferOwnershipParams;
 use anchor_lang::prelude::{ProgramError, Pubkey};
-use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
 use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
 use solana_program_test::{BanksClientError, BanksTransactionResultWithMetadata, ProgramTestContext};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
-use access_controller::AccessController;
+use access_controller::{AccessController, AccessListMode, ACCESS_GRANT_SEED, FEED_ACCESS_LIST_SEED};
+use solana_program::system_program;
 use crate::environment_context_operations::EnvironmentContextOperations;
 
 pub struct AccessControllerClient {
@@ -89,6 +112,456 @@ impl AccessControllerClient {
         ).await
     }
 
+    pub async fn batch_add_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        addresses: Vec<Pubkey>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_batch_update_access(context, owner, BatchAddAccessParams { addresses }.data())
+            .await
+    }
+
+    pub async fn batch_remove_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        addresses: Vec<Pubkey>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_batch_update_access(context, owner, BatchRemoveAccessParams { addresses }.data())
+            .await
+    }
+
+    async fn send_batch_update_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let batch_context = BatchUpdateAccess {
+            state: self.data_account,
+            owner: owner.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: batch_context.to_account_metas(None),
+            data,
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn set_access_list_mode(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        mode: AccessListMode,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetAccessListModeParams { mode };
+
+        let set_mode_context = SetAccessListMode {
+            state: self.data_account,
+            owner: owner.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: set_mode_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn add_admin(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        admin: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_update_admins(context, owner, admin, AddAdminParams {}.data())
+            .await
+    }
+
+    pub async fn remove_admin(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        admin: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_update_admins(context, owner, admin, RemoveAdminParams {}.data())
+            .await
+    }
+
+    async fn send_update_admins(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        admin: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let update_context = UpdateAdmins {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            admin,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: update_context.to_account_metas(None),
+            data,
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn add_program_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        program: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_update_program_access(context, owner, program, AddProgramAccessParams {}.data())
+            .await
+    }
+
+    pub async fn remove_program_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        program: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_update_program_access(context, owner, program, RemoveProgramAccessParams {}.data())
+            .await
+    }
+
+    pub async fn has_program_access(&self, context: &mut ProgramTestContext, program: &Pubkey) -> bool {
+        let access_controller = self.read_access_controller_account(context).await.unwrap();
+        access_controller.program_access_list.binary_search(program).is_ok()
+    }
+
+    async fn send_update_program_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        program: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let update_context = UpdateProgramAccess {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            program,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: update_context.to_account_metas(None),
+            data,
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn extend_subscription(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        user: Pubkey,
+        valid_until: i64,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = ExtendSubscriptionParams { valid_until };
+
+        let extend_subscription_context = ExtendSubscription {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            address: user,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: extend_subscription_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn purge_expired_subscriptions(
+        &self,
+        context: &mut ProgramTestContext,
+        payer: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = PurgeExpiredSubscriptionsParams {};
+
+        let purge_context = PurgeExpiredSubscriptions {
+            state: self.data_account,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: purge_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&payer.pubkey()), &[payer]).await
+    }
+
+    pub async fn subscription_valid_until(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Pubkey,
+    ) -> Option<i64> {
+        let access_controller = self.read_access_controller_account(context)
+            .await
+            .expect("Failed to read access controller account");
+
+        access_controller.subscriptions.find(user)
+            .ok()
+            .map(|index| access_controller.subscriptions[index].valid_until)
+    }
+
+    pub async fn realloc_account(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = ReallocAccountParams {};
+
+        let realloc_context = ReallocAccount {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: realloc_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub fn feed_access_list_address(&self, feed_id: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(
+            &[FEED_ACCESS_LIST_SEED, self.data_account.as_ref(), feed_id],
+            &self.program_id,
+        )
+        .0
+    }
+
+    pub async fn initialize_feed_access_list(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        feed_id: [u8; 32],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = InitializeFeedAccessListParams { feed_id };
+
+        let initialize_context = InitializeFeedAccessList {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            feed_access_list: self.feed_access_list_address(&feed_id),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: initialize_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn add_feed_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        feed_id: [u8; 32],
+        user: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = AddFeedAccessParams {};
+
+        let add_feed_access_context = UpdateFeedAccess {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            feed_access_list: self.feed_access_list_address(&feed_id),
+            address: user,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: add_feed_access_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn remove_feed_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        feed_id: [u8; 32],
+        user: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = RemoveFeedAccessParams {};
+
+        let remove_feed_access_context = UpdateFeedAccess {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            feed_access_list: self.feed_access_list_address(&feed_id),
+            address: user,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: remove_feed_access_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub fn access_grant_address(&self, user: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[ACCESS_GRANT_SEED, self.data_account.as_ref(), user.as_ref()],
+            &self.program_id,
+        )
+        .0
+    }
+
+    pub async fn grant_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        user: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = GrantAccessParams {};
+
+        let grant_access_context = GrantAccess {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            address: user,
+            access_grant: self.access_grant_address(&user),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: grant_access_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn revoke_access(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        user: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = RevokeAccessParams {};
+
+        let state_owner = self.read_access_controller_account(context)
+            .await
+            .expect("Failed to read access controller account")
+            .owner;
+
+        let revoke_access_context = RevokeAccess {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            address: user,
+            access_grant: self.access_grant_address(&user),
+            state_owner,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: revoke_access_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    /// Migrates the given leading entries of the access list into access grants
+    pub async fn migrate_access_list(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        users: &[Pubkey],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = MigrateAccessListParams {};
+
+        let migrate_context = MigrateAccessList {
+            state: self.data_account,
+            owner: owner.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let mut accounts = migrate_context.to_account_metas(None);
+        accounts.extend(
+            users
+                .iter()
+                .map(|user| AccountMeta::new(self.access_grant_address(user), false)),
+        );
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn transfer_ownership(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        proposed_owner: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = TransferOwnershipParams { proposed_owner };
+
+        let transfer_ownership_context = TransferOwnership {
+            state: self.data_account,
+            authority: owner.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: transfer_ownership_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&owner.pubkey()), &[owner]).await
+    }
+
+    pub async fn accept_ownership(
+        &self,
+        context: &mut ProgramTestContext,
+        proposed_owner: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = AcceptOwnershipParams {};
+
+        let accept_ownership_context = AcceptOwnership {
+            state: self.data_account,
+            authority: proposed_owner.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: accept_ownership_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(context, &[instruction], Some(&proposed_owner.pubkey()), &[proposed_owner]).await
+    }
+
     pub async fn initialize(&self,
                             context: &mut ProgramTestContext,
                             user: &Keypair) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
@@ -1,32 +1,113 @@
 use crate::environment_context_operations::EnvironmentContextOperations;
 use anchor_lang::prelude::{ProgramError, Pubkey};
-use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
 use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
-use solana_program::system_program;
+use solana_program::{system_program, sysvar};
 use solana_program_test::{
     BanksClientError, BanksTransactionResultWithMetadata, ProgramTestContext,
 };
+use anchor_spl::token::spl_token;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use verifier::accounts::InitializeAccountDataContext;
+use verifier::common::FORWARDER_SEED;
 use verifier::accounts::{
-    AcceptOwnershipContext, InitializeContext, ReallocContext, SetAccessControllerContext,
-    TransferOwnershipContext, UpdateConfigContext, VerifyContext,
+    AcceptOwnershipContext, ClaimRewardsContext, CloseDonRouteContext, CloseReceiptContext, CloseReportBufferContext,
+    GetFeeQuoteContext, InitializeContext, InitializeDonNamespaceContext, InitializeDonRouteContext,
+    InitializeRateLimitContext, InitializeUsageCounterContext,
+    InitializeFeePreferenceContext, InitializeReportBufferContext, InitializeRewardPoolContext,
+    InitializeSubscriberDiscountContext, ReadVerifierAccountContext,
+    SetFeeConfigContext, SetFeePreferenceContext, SetNativeFeeConfigContext,
+    SetRewardRecipientsContext, SetSubscriberDiscountContext, ReallocContext, RotateConfigContext, SetAccessControllerContext,
+    SetUserRateLimitContext, TransferOwnershipContext, UpdateConfigContext, UpdateDonNamespaceContext, VerifyAndForwardContext,
+    VerifyContext, VerifyFromBufferContext, WriteReportBufferContext,
 };
+use verifier::domain::{RewardRecipientConfig, SignedReport};
+use verifier::evm::Encoder;
 use verifier::instruction::AcceptOwnership as AcceptOwnershipParams;
+use verifier::instruction::ClaimRewards as ClaimRewardsParams;
+use verifier::instruction::ClearAccessController as ClearAccessControllerParams;
+use verifier::instruction::ClearUserRateLimit as ClearUserRateLimitParams;
+use verifier::instruction::CloseDonRoute as CloseDonRouteParams;
+use verifier::instruction::CloseReceipt as CloseReceiptParams;
+use verifier::instruction::CloseReportBuffer as CloseReportBufferParams;
+use verifier::instruction::GetAccountConfig as GetAccountConfigParams;
+use verifier::instruction::GetConfig as GetConfigParams;
+use verifier::instruction::GetConfigCount as GetConfigCountParams;
+use verifier::instruction::GetConfigForTimestamp as GetConfigForTimestampParams;
+use verifier::instruction::GetFeeQuote as GetFeeQuoteParams;
 use verifier::instruction::Initialize as InitializeParams;
 use verifier::instruction::InitializeAccountData;
+use verifier::instruction::InitializeDonNamespace as InitializeDonNamespaceParams;
+use verifier::instruction::InitializeDonRoute as InitializeDonRouteParams;
+use verifier::instruction::InitializeFeePreference as InitializeFeePreferenceParams;
+use verifier::instruction::InitializeRateLimit as InitializeRateLimitParams;
+use verifier::instruction::InitializeReportBuffer as InitializeReportBufferParams;
+use verifier::instruction::InitializeRewardPool as InitializeRewardPoolParams;
+use verifier::instruction::InitializeSubscriberDiscount as InitializeSubscriberDiscountParams;
+use verifier::instruction::InitializeUsageCounter as InitializeUsageCounterParams;
 use verifier::instruction::ReallocAccount as ReallocParams;
 use verifier::instruction::RemoveLatestConfig as RemoveLatestConfigParams;
+use verifier::instruction::RotateConfig as RotateConfigParams;
+use verifier::instruction::SetAcceptedSchemaVersions as SetAcceptedSchemaVersionsParams;
 use verifier::instruction::SetAccessController as SetAccessControllerParams;
 use verifier::instruction::SetConfig as SetConfigParams;
 use verifier::instruction::SetConfigActive as SetConfigActiveParams;
+use verifier::instruction::SetConfigChecked as SetConfigCheckedParams;
+use verifier::instruction::SetConfigRotationEnabled as SetConfigRotationEnabledParams;
 use verifier::instruction::SetConfigWithActivationTime as SetConfigWithActivationTimeParams;
+use verifier::instruction::SetConfigWithActivationTimeChecked as SetConfigWithActivationTimeCheckedParams;
+use verifier::instruction::SetDefaultRateLimit as SetDefaultRateLimitParams;
+use verifier::instruction::SetFeeConfig as SetFeeConfigParams;
+use verifier::instruction::SetFeePreference as SetFeePreferenceParams;
+use verifier::instruction::SetFeeWaiver as SetFeeWaiverParams;
+use verifier::instruction::SetNamespaceConfigActive as SetNamespaceConfigActiveParams;
+use verifier::instruction::SetNamespaceConfigWithActivationTime as SetNamespaceConfigWithActivationTimeParams;
+use verifier::instruction::SetNamespaceConfigWithActivationTimeChecked as SetNamespaceConfigWithActivationTimeCheckedParams;
+use verifier::instruction::SetNativeFeeConfig as SetNativeFeeConfigParams;
+use verifier::instruction::SetRewardRecipients as SetRewardRecipientsParams;
+use verifier::instruction::SetSubscriberDiscount as SetSubscriberDiscountParams;
+use verifier::instruction::SetUsageMeteringEnabled as SetUsageMeteringEnabledParams;
+use verifier::instruction::SetUserRateLimit as SetUserRateLimitParams;
+use verifier::instruction::SnapshotUsage as SnapshotUsageParams;
 use verifier::instruction::TransferOwnership as TransferOwnershipParams;
 use verifier::instruction::Verify as VerifyParams;
-use verifier::state::VerifierAccount;
+use verifier::instruction::VerifyAndForward as VerifyAndForwardParams;
+use verifier::instruction::VerifyFromBuffer as VerifyFromBufferParams;
+use verifier::instruction::WriteReportBuffer as WriteReportBufferParams;
+use verifier::state::{
+    DonNamespace, FeeAsset, FeePreference, ReportBuffer, RewardPool, UsageCounter, VerificationReceipt,
+    VerifierAccount,
+    DON_NAMESPACE_SEED, DON_ROUTE_SEED, FEE_PREFERENCE_SEED, FEE_VAULT_SEED, NATIVE_FEE_VAULT_SEED, RATE_LIMIT_SEED, RECEIPT_SEED,
+    REPORT_BUFFER_SEED, REWARD_POOL_SEED, SUBSCRIBER_DISCOUNT_SEED, USAGE_COUNTER_SEED,
+};
 use verifier::util::Compressor;
 
+/// Optional accounts of `verify`, all omitted by default
+#[derive(Default)]
+pub struct VerifyOptionalAccounts {
+    pub feed_access_list: Option<Pubkey>,
+    pub access_grant: Option<Pubkey>,
+    pub instructions_sysvar: bool,
+    pub rate_limit: Option<Pubkey>,
+    pub usage_counter: Option<Pubkey>,
+    // The fee vault and token program are derived from the mint
+    pub fee_token_mint: Option<Pubkey>,
+    pub requester_token_account: Option<Pubkey>,
+    pub fee_preference: Option<Pubkey>,
+    // Passes the native fee vault and the system program
+    pub native_fee: bool,
+    pub reward_pool: Option<Pubkey>,
+    pub subscriber_discount: Option<Pubkey>,
+    // Also passes the system program
+    pub receipt: Option<Pubkey>,
+    // The namespace the report is routed to
+    pub don_namespace: Option<Pubkey>,
+    // Passes the routes of the report's config digest and feed id
+    pub don_routes: bool,
+    pub payer: Option<Pubkey>,
+}
+
 // Verifier struct using ContractOperations
 // This is a client wrapper to abstract interacting with the Verifier program
 pub struct VerifierClient {
@@ -118,7 +199,7 @@ impl VerifierClient {
         let initialize_context = InitializeAccountDataContext {
             verifier_account: self.data_account,
             owner: user.pubkey(),
-            access_controller: self.access_controller_data_account,
+            access_controller: self.access_controller_data_account.unwrap_or(self.program_id),
             system_program: system_program::ID,
             program: self.program_id,
             program_data: self.get_program_data_address(),
@@ -193,7 +274,8 @@ impl VerifierClient {
         let set_access_controller_context = SetAccessControllerContext {
             verifier_account: self.data_account,
             owner: user.pubkey(),
-            access_controller: access_controller_data_account,
+            // The program ID disables the access controller
+            access_controller: access_controller_data_account.unwrap_or(self.program_id),
         };
 
         let instruction = Instruction {
@@ -211,6 +293,33 @@ impl VerifierClient {
         .await
     }
 
+    pub async fn clear_access_controller(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = ClearAccessControllerParams {};
+
+        let owner_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: owner_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
     pub async fn verify(
         &self,
         context: &mut ProgramTestContext,
@@ -218,19 +327,135 @@ impl VerifierClient {
         signed_report: Vec<u8>,
         override_config_account: Option<Pubkey>,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        let permissioned_context = VerifyContext {
-            verifier_account: self.data_account,
-            user: user.pubkey(),
-            access_controller: self.access_controller_data_account.unwrap(),
-            config_account: override_config_account
-                .unwrap_or(self.compute_report_config_pda(&signed_report)),
-        };
+        self.verify_with_feed_access_list(
+            context,
+            user,
+            signed_report,
+            override_config_account,
+            None,
+        )
+        .await
+    }
+
+    pub async fn verify_with_feed_access_list(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signed_report: Vec<u8>,
+        override_config_account: Option<Pubkey>,
+        feed_access_list: Option<Pubkey>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.verify_with_optional_accounts(
+            context,
+            user,
+            signed_report,
+            override_config_account,
+            VerifyOptionalAccounts {
+                feed_access_list,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    pub async fn verify_with_access_grant(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signed_report: Vec<u8>,
+        override_config_account: Option<Pubkey>,
+        access_grant: Option<Pubkey>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.verify_with_optional_accounts(
+            context,
+            user,
+            signed_report,
+            override_config_account,
+            VerifyOptionalAccounts {
+                access_grant,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    pub async fn verify_with_optional_accounts(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signed_report: Vec<u8>,
+        override_config_account: Option<Pubkey>,
+        optional_accounts: VerifyOptionalAccounts,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.verify_with_fee_payer(
+            context,
+            user,
+            user,
+            signed_report,
+            override_config_account,
+            optional_accounts,
+        )
+        .await
+    }
+
+    /// Verifies with `fee_payer` paying the transaction fee, leaving the user read-only unless it
+    /// is the fee payer
+    pub async fn verify_with_fee_payer(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        fee_payer: &Keypair,
+        signed_report: Vec<u8>,
+        override_config_account: Option<Pubkey>,
+        optional_accounts: VerifyOptionalAccounts,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let accounts = self
+            .verify_context(user, &signed_report, override_config_account, optional_accounts)
+            .to_account_metas(None);
 
         let data = VerifyParams { signed_report };
 
         let instruction = Instruction {
             program_id: self.program_id,
-            accounts: permissioned_context.to_account_metas(None),
+            accounts,
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&fee_payer.pubkey()),
+            &[fee_payer, user],
+        )
+        .await
+    }
+
+    /// Verifies the report through `calling_program`, which takes the data of `verify` and calls
+    /// the verifier with the accounts of `verify` following its own
+    pub async fn verify_through_program(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signed_report: Vec<u8>,
+        calling_program: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let verify_context = self.verify_context(
+            user,
+            &signed_report,
+            None,
+            VerifyOptionalAccounts {
+                instructions_sysvar: true,
+                ..Default::default()
+            },
+        );
+        let mut accounts = vec![AccountMeta::new_readonly(self.program_id, false)];
+        accounts.extend(verify_context.to_account_metas(None));
+
+        let data = VerifyParams { signed_report };
+
+        let instruction = Instruction {
+            program_id: calling_program,
+            accounts,
             data: data.data(),
         };
 
@@ -243,29 +468,86 @@ impl VerifierClient {
         .await
     }
 
-    pub async fn set_config_with_activation_time(
+    /// Verifies the report and forwards it to the `consumer_program` instruction with
+    /// `consumer_discriminator`, passing `passthrough_accounts` on to the consumer
+    pub async fn verify_and_forward(
         &self,
         context: &mut ProgramTestContext,
         user: &Keypair,
-        signers: Vec<[u8; 20]>,
-        f: u8,
-        activation_time: u32,
+        signed_report: Vec<u8>,
+        consumer: (Pubkey, [u8; 8]),
+        optional_accounts: VerifyOptionalAccounts,
+        passthrough_accounts: Vec<AccountMeta>,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        let data = SetConfigWithActivationTimeParams {
-            signers,
-            f,
-            activation_time,
+        let instruction =
+            self.verify_and_forward_instruction(user, signed_report, consumer, optional_accounts, passthrough_accounts);
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    /// The `verify_and_forward` instruction sent by [`Self::verify_and_forward`]. `consumer` is the
+    /// consumer program and the discriminator of its instruction to forward the report to.
+    pub fn verify_and_forward_instruction(
+        &self,
+        user: &Keypair,
+        signed_report: Vec<u8>,
+        consumer: (Pubkey, [u8; 8]),
+        optional_accounts: VerifyOptionalAccounts,
+        passthrough_accounts: Vec<AccountMeta>,
+    ) -> Instruction {
+        let (consumer_program, consumer_discriminator) = consumer;
+        let forward_context = VerifyAndForwardContext {
+            verify: self.verify_context(user, &signed_report, None, optional_accounts),
+            consumer_program,
+            forwarder: self.forwarder_address(),
         };
+        let mut accounts = forward_context.to_account_metas(None);
+        accounts.extend(passthrough_accounts);
 
-        let owner_context = UpdateConfigContext {
-            verifier_account: self.data_account,
-            owner: user.pubkey(),
+        let data = VerifyAndForwardParams {
+            signed_report,
+            consumer_discriminator,
         };
 
-        let instruction = Instruction {
+        Instruction {
             program_id: self.program_id,
-            accounts: owner_context.to_account_metas(None),
+            accounts,
             data: data.data(),
+        }
+    }
+
+    pub fn forwarder_address(&self) -> Pubkey {
+        Pubkey::find_program_address(&[FORWARDER_SEED], &self.program_id).0
+    }
+
+    /// Verifies the signed report staged in `report_buffer`, writing the report data to
+    /// `report_output` when given. The signed report is only used to derive the report config
+    /// account.
+    pub async fn verify_from_buffer(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signed_report: &[u8],
+        report_buffer: Pubkey,
+        report_output: Option<Pubkey>,
+        optional_accounts: VerifyOptionalAccounts,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let buffer_context = VerifyFromBufferContext {
+            verify: self.verify_context(user, signed_report, None, optional_accounts),
+            report_buffer,
+            report_output,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: buffer_context.to_account_metas(None),
+            data: VerifyFromBufferParams {}.data(),
         };
 
         EnvironmentContextOperations::send_transaction(
@@ -277,55 +559,211 @@ impl VerifierClient {
         .await
     }
 
-    pub async fn set_config(
+    pub fn report_buffer_address(&self, authority: &Pubkey, buffer_id: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[REPORT_BUFFER_SEED, authority.as_ref(), &buffer_id.to_le_bytes()],
+            &self.program_id,
+        )
+        .0
+    }
+
+    pub async fn initialize_report_buffer(
         &self,
         context: &mut ProgramTestContext,
-        user: &Keypair,
-        signers: Vec<[u8; 20]>,
-        f: u8,
+        authority: &Keypair,
+        buffer_id: u64,
+        capacity: u32,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        let data = SetConfigParams { signers, f };
+        let data = InitializeReportBufferParams { buffer_id, capacity };
 
-        let owner_context = UpdateConfigContext {
-            verifier_account: self.data_account,
-            owner: user.pubkey(),
+        let initialize_context = InitializeReportBufferContext {
+            report_buffer: self.report_buffer_address(&authority.pubkey(), buffer_id),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
         };
 
         let instruction = Instruction {
             program_id: self.program_id,
-            accounts: owner_context.to_account_metas(None),
+            accounts: initialize_context.to_account_metas(None),
             data: data.data(),
         };
 
         EnvironmentContextOperations::send_transaction(
             context,
             &[instruction],
-            Some(&user.pubkey()),
-            &[user],
+            Some(&authority.pubkey()),
+            &[authority],
         )
         .await
     }
 
-    pub async fn set_config_active(
+    pub async fn write_report_buffer(
+        &self,
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        report_buffer: Pubkey,
+        offset: u32,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = WriteReportBufferParams { offset, data };
+
+        let write_context = WriteReportBufferContext {
+            report_buffer,
+            authority: authority.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: write_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+        )
+        .await
+    }
+
+    pub async fn close_report_buffer(
+        &self,
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        report_buffer: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let close_context = CloseReportBufferContext {
+            report_buffer,
+            authority: authority.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: close_context.to_account_metas(None),
+            data: CloseReportBufferParams {}.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+        )
+        .await
+    }
+
+    /// Returns the bytes written to a report buffer
+    pub async fn read_report_buffer_data(
+        &self,
+        context: &mut ProgramTestContext,
+        report_buffer: Pubkey,
+    ) -> Result<Vec<u8>, ProgramError> {
+        let account = EnvironmentContextOperations::get_account(context, report_buffer)
+            .await
+            .unwrap()
+            .ok_or(ProgramError::UninitializedAccount)?;
+
+        let header: ReportBuffer = EnvironmentContextOperations::deserialize_account_data(&account.data)?;
+        let data = &account.data[ReportBuffer::DATA_OFFSET..];
+        Ok(data[..header.len as usize].to_vec())
+    }
+
+    fn verify_context(
+        &self,
+        user: &Keypair,
+        signed_report: &[u8],
+        override_config_account: Option<Pubkey>,
+        optional_accounts: VerifyOptionalAccounts,
+    ) -> VerifyContext {
+        let VerifyOptionalAccounts {
+            feed_access_list,
+            access_grant,
+            instructions_sysvar,
+            rate_limit,
+            usage_counter,
+            fee_token_mint,
+            requester_token_account,
+            fee_preference,
+            native_fee,
+            reward_pool,
+            subscriber_discount,
+            receipt,
+            don_namespace,
+            don_routes,
+            payer,
+        } = optional_accounts;
+
+        let (config_digest_route, feed_route) = if don_routes {
+            let report = Compressor::decompress(signed_report);
+            let SignedReport {
+                report_context,
+                report_data,
+                ..
+            } = Encoder::parse_signed_report(&report).unwrap();
+            let feed_id: &[u8; 32] = report_data[..32].try_into().unwrap();
+            (
+                Some(self.don_route_address(&report_context[0])),
+                Some(self.don_route_address(feed_id)),
+            )
+        } else {
+            (None, None)
+        };
+
+        VerifyContext {
+            verifier_account: self.data_account,
+            user: user.pubkey(),
+            access_controller: self.access_controller_data_account.unwrap(),
+            config_account: override_config_account
+                .unwrap_or(self.compute_report_config_pda(signed_report)),
+            feed_access_list,
+            access_grant,
+            instructions_sysvar: instructions_sysvar.then_some(sysvar::instructions::ID),
+            rate_limit,
+            usage_counter,
+            fee_token_mint,
+            requester_token_account,
+            fee_token_vault: fee_token_mint.map(|mint| self.fee_vault_address(&mint)),
+            token_program: fee_token_mint.map(|_| spl_token::ID),
+            fee_preference,
+            native_fee_vault: native_fee.then(|| self.native_fee_vault_address()),
+            system_program: (native_fee || receipt.is_some()).then_some(system_program::ID),
+            reward_pool,
+            subscriber_discount,
+            receipt,
+            don_namespace,
+            config_digest_route,
+            feed_route,
+            payer,
+        }
+    }
+
+    pub fn rate_limit_address(&self, user: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[RATE_LIMIT_SEED, user.as_ref()], &self.program_id).0
+    }
+
+    pub async fn set_default_rate_limit(
         &self,
         context: &mut ProgramTestContext,
         user: &Keypair,
-        don_config_index: u64,
-        is_active: bool,
+        enabled: bool,
+        max_verifications: u64,
+        window_slots: u64,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        let data = SetConfigActiveParams {
-            don_config_index,
-            is_active: u8::from(is_active),
+        let data = SetDefaultRateLimitParams {
+            enabled,
+            max_verifications,
+            window_slots,
         };
 
-        let owner_context = UpdateConfigContext {
+        let update_context = UpdateConfigContext {
             verifier_account: self.data_account,
             owner: user.pubkey(),
         };
 
         let instruction = Instruction {
             program_id: self.program_id,
-            accounts: owner_context.to_account_metas(None),
+            accounts: update_context.to_account_metas(None),
             data: data.data(),
         };
 
@@ -338,69 +776,1153 @@ impl VerifierClient {
         .await
     }
 
-    pub async fn remove_latest_config(
+    pub async fn initialize_rate_limit(
         &self,
         context: &mut ProgramTestContext,
-        user: &Keypair,
+        payer: &Keypair,
+        user: Pubkey,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        let data = RemoveLatestConfigParams {};
+        let data = InitializeRateLimitParams {};
 
-        let owner_context = UpdateConfigContext {
-            verifier_account: self.data_account,
-            owner: user.pubkey(),
+        let initialize_context = InitializeRateLimitContext {
+            rate_limit: self.rate_limit_address(&user),
+            user,
+            payer: payer.pubkey(),
+            system_program: system_program::ID,
         };
 
         let instruction = Instruction {
             program_id: self.program_id,
-            accounts: owner_context.to_account_metas(None),
+            accounts: initialize_context.to_account_metas(None),
             data: data.data(),
         };
 
         EnvironmentContextOperations::send_transaction(
             context,
             &[instruction],
-            Some(&user.pubkey()),
-            &[user],
+            Some(&payer.pubkey()),
+            &[payer],
         )
         .await
     }
 
-    pub async fn transfer_ownership(
+    pub async fn set_user_rate_limit(
         &self,
         context: &mut ProgramTestContext,
-        user: &Keypair,
-        proposed_owner: Pubkey,
+        owner: &Keypair,
+        user: Pubkey,
+        max_verifications: u64,
+        window_slots: u64,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        let data = TransferOwnershipParams { proposed_owner };
+        let data = SetUserRateLimitParams {
+            max_verifications,
+            window_slots,
+        };
+        self.send_user_rate_limit_instruction(context, owner, user, data.data())
+            .await
+    }
 
-        let transfer_ownership_context = TransferOwnershipContext {
+    pub async fn clear_user_rate_limit(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        user: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_user_rate_limit_instruction(context, owner, user, ClearUserRateLimitParams {}.data())
+            .await
+    }
+
+    async fn send_user_rate_limit_instruction(
+        &self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        user: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let set_context = SetUserRateLimitContext {
             verifier_account: self.data_account,
-            owner: user.pubkey(),
+            rate_limit: self.rate_limit_address(&user),
+            user,
+            owner: owner.pubkey(),
         };
 
         let instruction = Instruction {
             program_id: self.program_id,
-            accounts: transfer_ownership_context.to_account_metas(None),
-            data: data.data(),
+            accounts: set_context.to_account_metas(None),
+            data,
         };
 
         EnvironmentContextOperations::send_transaction(
             context,
             &[instruction],
-            Some(&user.pubkey()),
-            &[user],
+            Some(&owner.pubkey()),
+            &[owner],
         )
         .await
     }
 
-    pub async fn accept_ownership(
+    pub fn usage_counter_address(&self, requester: &Pubkey, feed_id: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(
+            &[USAGE_COUNTER_SEED, requester.as_ref(), feed_id],
+            &self.program_id,
+        )
+        .0
+    }
+
+    pub async fn set_usage_metering_enabled(
         &self,
         context: &mut ProgramTestContext,
         user: &Keypair,
+        enabled: bool,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        let data = AcceptOwnershipParams {};
+        let data = SetUsageMeteringEnabledParams { enabled };
 
-        let accept_ownership_context = AcceptOwnershipContext {
+        let update_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: update_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn initialize_usage_counter(
+        &self,
+        context: &mut ProgramTestContext,
+        payer: &Keypair,
+        requester: Pubkey,
+        feed_id: [u8; 32],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = InitializeUsageCounterParams { feed_id };
+
+        let initialize_context = InitializeUsageCounterContext {
+            usage_counter: self.usage_counter_address(&requester, &feed_id),
+            requester,
+            payer: payer.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: initialize_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+        )
+        .await
+    }
+
+    pub async fn snapshot_usage(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        billing_period: u64,
+        usage_counters: &[Pubkey],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SnapshotUsageParams { billing_period };
+
+        let update_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let mut accounts = update_context.to_account_metas(None);
+        accounts.extend(
+            usage_counters
+                .iter()
+                .map(|usage_counter| AccountMeta::new(*usage_counter, false)),
+        );
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn read_usage_counter(
+        &self,
+        context: &mut ProgramTestContext,
+        usage_counter: Pubkey,
+    ) -> Result<UsageCounter, ProgramError> {
+        let account = EnvironmentContextOperations::get_account(context, usage_counter)
+            .await
+            .unwrap()
+            .ok_or(ProgramError::UninitializedAccount)?;
+
+        EnvironmentContextOperations::deserialize_account_data(&account.data)
+    }
+
+    pub fn fee_vault_address(&self, token_mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[FEE_VAULT_SEED, token_mint.as_ref()], &self.program_id).0
+    }
+
+    pub async fn set_fee_config(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        fee_token_mint: Pubkey,
+        enabled: bool,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetFeeConfigParams { enabled };
+
+        let fee_config_context = SetFeeConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+            fee_token_mint,
+            fee_token_vault: self.fee_vault_address(&fee_token_mint),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: fee_config_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub fn native_fee_vault_address(&self) -> Pubkey {
+        Pubkey::find_program_address(&[NATIVE_FEE_VAULT_SEED], &self.program_id).0
+    }
+
+    pub async fn set_native_fee_config(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        enabled: bool,
+        surcharge_bps: u16,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetNativeFeeConfigParams {
+            enabled,
+            surcharge_bps,
+        };
+
+        let fee_config_context = SetNativeFeeConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+            native_fee_vault: self.native_fee_vault_address(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: fee_config_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub fn fee_preference_address(&self, requester: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[FEE_PREFERENCE_SEED, requester.as_ref()], &self.program_id).0
+    }
+
+    pub async fn initialize_fee_preference(
+        &self,
+        context: &mut ProgramTestContext,
+        requester: &Keypair,
+        asset: FeeAsset,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = InitializeFeePreferenceParams { asset };
+
+        let initialize_context = InitializeFeePreferenceContext {
+            fee_preference: self.fee_preference_address(&requester.pubkey()),
+            requester: requester.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: initialize_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&requester.pubkey()),
+            &[requester],
+        )
+        .await
+    }
+
+    pub async fn set_fee_preference(
+        &self,
+        context: &mut ProgramTestContext,
+        requester: &Keypair,
+        asset: FeeAsset,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetFeePreferenceParams { asset };
+
+        let set_context = SetFeePreferenceContext {
+            fee_preference: self.fee_preference_address(&requester.pubkey()),
+            requester: requester.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: set_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&requester.pubkey()),
+            &[requester],
+        )
+        .await
+    }
+
+    pub fn receipt_address(&self, feed_id: &[u8; 32], observations_timestamp: u32) -> Pubkey {
+        Pubkey::find_program_address(
+            &[RECEIPT_SEED, feed_id, &observations_timestamp.to_le_bytes()],
+            &self.program_id,
+        )
+        .0
+    }
+
+    pub async fn close_receipt(
+        &self,
+        context: &mut ProgramTestContext,
+        requester: &Keypair,
+        receipt: Pubkey,
+        payer: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let close_context = CloseReceiptContext {
+            receipt,
+            requester: requester.pubkey(),
+            payer,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: close_context.to_account_metas(None),
+            data: CloseReceiptParams {}.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&requester.pubkey()),
+            &[requester],
+        )
+        .await
+    }
+
+    pub async fn read_receipt(
+        &self,
+        context: &mut ProgramTestContext,
+        receipt: Pubkey,
+    ) -> Result<VerificationReceipt, ProgramError> {
+        let account = EnvironmentContextOperations::get_account(context, receipt)
+            .await
+            .unwrap()
+            .ok_or(ProgramError::UninitializedAccount)?;
+
+        EnvironmentContextOperations::deserialize_account_data(&account.data)
+    }
+
+    pub fn subscriber_discount_address(&self, subscriber: &Pubkey, feed_id: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(&[SUBSCRIBER_DISCOUNT_SEED, subscriber.as_ref(), feed_id], &self.program_id).0
+    }
+
+    pub async fn initialize_subscriber_discount(
+        &self,
+        context: &mut ProgramTestContext,
+        payer: &Keypair,
+        subscriber: Pubkey,
+        feed_id: [u8; 32],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = InitializeSubscriberDiscountParams { feed_id };
+
+        let initialize_context = InitializeSubscriberDiscountContext {
+            subscriber_discount: self.subscriber_discount_address(&subscriber, &feed_id),
+            subscriber,
+            payer: payer.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: initialize_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+        )
+        .await
+    }
+
+    pub async fn set_subscriber_discount(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        subscriber_discount: Pubkey,
+        discount_bps: u16,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetSubscriberDiscountParams { discount_bps };
+        self.send_subscriber_discount_instruction(context, user, subscriber_discount, data.data())
+            .await
+    }
+
+    pub async fn set_fee_waiver(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        subscriber_discount: Pubkey,
+        waived: bool,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetFeeWaiverParams { waived };
+        self.send_subscriber_discount_instruction(context, user, subscriber_discount, data.data())
+            .await
+    }
+
+    async fn send_subscriber_discount_instruction(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        subscriber_discount: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let set_context = SetSubscriberDiscountContext {
+            verifier_account: self.data_account,
+            subscriber_discount,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: set_context.to_account_metas(None),
+            data,
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn read_fee_preference(
+        &self,
+        context: &mut ProgramTestContext,
+        requester: &Pubkey,
+    ) -> Result<FeePreference, ProgramError> {
+        let account = EnvironmentContextOperations::get_account(context, self.fee_preference_address(requester))
+            .await
+            .unwrap()
+            .ok_or(ProgramError::UninitializedAccount)?;
+
+        EnvironmentContextOperations::deserialize_account_data(&account.data)
+    }
+
+    pub fn reward_pool_address(&self, config_digest: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(&[REWARD_POOL_SEED, config_digest], &self.program_id).0
+    }
+
+    pub async fn initialize_reward_pool(
+        &self,
+        context: &mut ProgramTestContext,
+        payer: &Keypair,
+        config_digest: [u8; 32],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = InitializeRewardPoolParams { config_digest };
+
+        let initialize_context = InitializeRewardPoolContext {
+            reward_pool: self.reward_pool_address(&config_digest),
+            payer: payer.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: initialize_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+        )
+        .await
+    }
+
+    pub async fn set_reward_recipients(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        config_digest: [u8; 32],
+        don_config_index: u64,
+        recipients: Vec<RewardRecipientConfig>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_set_reward_recipients(context, user, config_digest, None, don_config_index, recipients)
+            .await
+    }
+
+    /// Sets the recipients of a pool of the namespace's DON, paid for signers of its DON config at
+    /// `don_config_index`
+    pub async fn set_namespace_reward_recipients(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        config_digest: [u8; 32],
+        namespace_id: u64,
+        don_config_index: u64,
+        recipients: Vec<RewardRecipientConfig>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_set_reward_recipients(
+            context,
+            user,
+            config_digest,
+            Some(self.don_namespace_address(namespace_id)),
+            don_config_index,
+            recipients,
+        )
+        .await
+    }
+
+    async fn send_set_reward_recipients(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        config_digest: [u8; 32],
+        don_namespace: Option<Pubkey>,
+        don_config_index: u64,
+        recipients: Vec<RewardRecipientConfig>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetRewardRecipientsParams {
+            don_config_index,
+            recipients,
+        };
+
+        let set_context = SetRewardRecipientsContext {
+            verifier_account: self.data_account,
+            reward_pool: self.reward_pool_address(&config_digest),
+            owner: user.pubkey(),
+            don_namespace,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: set_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    /// Claims rewards in `asset` for every recipient, paying `payee_accounts` in recipient order.
+    /// `fee_token_mint` is required for token rewards.
+    pub async fn claim_rewards(
+        &self,
+        context: &mut ProgramTestContext,
+        payer: &Keypair,
+        config_digest: [u8; 32],
+        asset: FeeAsset,
+        fee_token_mint: Option<Pubkey>,
+        payee_accounts: &[Pubkey],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let recipients: Vec<(u8, Pubkey)> = payee_accounts
+            .iter()
+            .enumerate()
+            .map(|(index, payee_account)| (index as u8, *payee_account))
+            .collect();
+        self.claim_rewards_for(context, payer, config_digest, asset, fee_token_mint, &recipients)
+            .await
+    }
+
+    /// Claims rewards in `asset` for the given recipients, each an index into the pool's
+    /// recipients and the account to pay them into
+    pub async fn claim_rewards_for(
+        &self,
+        context: &mut ProgramTestContext,
+        payer: &Keypair,
+        config_digest: [u8; 32],
+        asset: FeeAsset,
+        fee_token_mint: Option<Pubkey>,
+        recipients: &[(u8, Pubkey)],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = ClaimRewardsParams {
+            asset,
+            recipient_indices: recipients.iter().map(|(index, _)| *index).collect(),
+        };
+
+        let native = asset == FeeAsset::Native;
+        let claim_context = ClaimRewardsContext {
+            verifier_account: self.data_account,
+            reward_pool: self.reward_pool_address(&config_digest),
+            fee_token_mint,
+            fee_token_vault: fee_token_mint.map(|mint| self.fee_vault_address(&mint)),
+            token_program: fee_token_mint.map(|_| spl_token::ID),
+            native_fee_vault: native.then(|| self.native_fee_vault_address()),
+            system_program: native.then_some(system_program::ID),
+        };
+
+        let mut accounts = claim_context.to_account_metas(None);
+        accounts.extend(
+            recipients
+                .iter()
+                .map(|(_, payee_account)| AccountMeta::new(*payee_account, false)),
+        );
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+        )
+        .await
+    }
+
+    pub async fn read_reward_pool(
+        &self,
+        context: &mut ProgramTestContext,
+        config_digest: &[u8; 32],
+    ) -> Result<RewardPool, ProgramError> {
+        let account = EnvironmentContextOperations::get_account(context, self.reward_pool_address(config_digest))
+            .await
+            .unwrap()
+            .ok_or(ProgramError::UninitializedAccount)?;
+
+        EnvironmentContextOperations::deserialize_account_data(&account.data)
+    }
+
+    pub async fn set_config_with_activation_time(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: u32,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetConfigWithActivationTimeParams {
+            signers,
+            f,
+            activation_time,
+        };
+        self.send_update_config_instruction(context, user, data.data())
+            .await
+    }
+
+    pub async fn set_config_with_activation_time_checked(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: u32,
+        expected_don_config_id: [u8; 24],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetConfigWithActivationTimeCheckedParams {
+            signers,
+            f,
+            activation_time,
+            expected_don_config_id,
+        };
+        self.send_update_config_instruction(context, user, data.data())
+            .await
+    }
+
+    pub async fn set_config(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetConfigParams { signers, f };
+        self.send_update_config_instruction(context, user, data.data())
+            .await
+    }
+
+    pub async fn set_config_checked(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        expected_don_config_id: [u8; 24],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetConfigCheckedParams {
+            signers,
+            f,
+            expected_don_config_id,
+        };
+        self.send_update_config_instruction(context, user, data.data())
+            .await
+    }
+
+    async fn send_update_config_instruction(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let owner_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: owner_context.to_account_metas(None),
+            data,
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn set_config_active(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        don_config_index: u64,
+        is_active: bool,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetConfigActiveParams {
+            don_config_index,
+            is_active: u8::from(is_active),
+        };
+
+        let owner_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: owner_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn remove_latest_config(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = RemoveLatestConfigParams {};
+
+        let owner_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: owner_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn set_config_rotation_enabled(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        enabled: bool,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetConfigRotationEnabledParams { enabled };
+
+        let owner_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: owner_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn set_accepted_schema_versions(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        schema_versions: Vec<u16>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetAcceptedSchemaVersionsParams { schema_versions };
+
+        let owner_context = UpdateConfigContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: owner_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub fn don_namespace_address(&self, namespace_id: u64) -> Pubkey {
+        Pubkey::find_program_address(&[DON_NAMESPACE_SEED, &namespace_id.to_le_bytes()], &self.program_id).0
+    }
+
+    pub fn don_route_address(&self, key: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(&[DON_ROUTE_SEED, key], &self.program_id).0
+    }
+
+    pub async fn initialize_don_namespace(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        namespace_id: u64,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = InitializeDonNamespaceParams { namespace_id };
+
+        let initialize_context = InitializeDonNamespaceContext {
+            verifier_account: self.data_account,
+            don_namespace: self.don_namespace_address(namespace_id),
+            owner: user.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: initialize_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn set_namespace_config_with_activation_time(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        namespace_id: u64,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        activation_time: u32,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetNamespaceConfigWithActivationTimeParams {
+            signers,
+            f,
+            activation_time,
+        };
+        self.send_don_namespace_instruction(context, user, namespace_id, data.data())
+            .await
+    }
+
+    /// Sets the namespace's config of `signers` and `f`, checked against `expected_don_config_id`
+    pub async fn set_namespace_config_with_activation_time_checked(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        namespace_id: u64,
+        config: (Vec<[u8; 20]>, u8),
+        activation_time: u32,
+        expected_don_config_id: [u8; 24],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let (signers, f) = config;
+        let data = SetNamespaceConfigWithActivationTimeCheckedParams {
+            signers,
+            f,
+            activation_time,
+            expected_don_config_id,
+        };
+        self.send_don_namespace_instruction(context, user, namespace_id, data.data())
+            .await
+    }
+
+    pub async fn set_namespace_config_active(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        namespace_id: u64,
+        don_config_index: u64,
+        is_active: bool,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = SetNamespaceConfigActiveParams {
+            don_config_index,
+            is_active: u8::from(is_active),
+        };
+        self.send_don_namespace_instruction(context, user, namespace_id, data.data())
+            .await
+    }
+
+    async fn send_don_namespace_instruction(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        namespace_id: u64,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let update_context = UpdateDonNamespaceContext {
+            verifier_account: self.data_account,
+            don_namespace: self.don_namespace_address(namespace_id),
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: update_context.to_account_metas(None),
+            data,
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn initialize_don_route(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        key: [u8; 32],
+        namespace_id: u64,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = InitializeDonRouteParams { key };
+
+        let initialize_context = InitializeDonRouteContext {
+            verifier_account: self.data_account,
+            don_route: self.don_route_address(&key),
+            don_namespace: self.don_namespace_address(namespace_id),
+            owner: user.pubkey(),
+            system_program: system_program::ID,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: initialize_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn close_don_route(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        key: [u8; 32],
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let close_context = CloseDonRouteContext {
+            verifier_account: self.data_account,
+            don_route: self.don_route_address(&key),
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: close_context.to_account_metas(None),
+            data: CloseDonRouteParams {}.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn read_don_namespace(
+        &self,
+        context: &mut ProgramTestContext,
+        namespace_id: u64,
+    ) -> Result<DonNamespace, ProgramError> {
+        let account = EnvironmentContextOperations::get_account(context, self.don_namespace_address(namespace_id))
+            .await
+            .unwrap()
+            .ok_or(ProgramError::UninitializedAccount)?;
+
+        EnvironmentContextOperations::deserialize_account_data(&account.data)
+    }
+
+    pub async fn rotate_config(
+        &self,
+        context: &mut ProgramTestContext,
+        payer: &Keypair,
+        signers: Vec<[u8; 20]>,
+        f: u8,
+        signatures: (Vec<[u8; 32]>, Vec<[u8; 32]>, Vec<u8>),
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let (rs, ss, vs) = signatures;
+
+        // Recovery ids are packed into a single word as in a signed report
+        let mut raw_vs = [0u8; 32];
+        raw_vs[..vs.len()].copy_from_slice(&vs);
+
+        let data = RotateConfigParams {
+            signers,
+            f,
+            rs,
+            ss,
+            raw_vs,
+        };
+
+        let rotate_config_context = RotateConfigContext {
+            verifier_account: self.data_account,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: rotate_config_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+        )
+        .await
+    }
+
+    pub async fn transfer_ownership(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        proposed_owner: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = TransferOwnershipParams { proposed_owner };
+
+        let transfer_ownership_context = TransferOwnershipContext {
+            verifier_account: self.data_account,
+            owner: user.pubkey(),
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: transfer_ownership_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    pub async fn accept_ownership(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = AcceptOwnershipParams {};
+
+        let accept_ownership_context = AcceptOwnershipContext {
             verifier_account: self.data_account,
             owner: user.pubkey(),
         };
@@ -420,6 +1942,106 @@ impl VerifierClient {
         .await
     }
 
+    pub async fn get_config_count(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_read_instruction(context, user, GetConfigCountParams {}.data())
+            .await
+    }
+
+    pub async fn get_config(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        don_config_index: u64,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = GetConfigParams { don_config_index };
+
+        self.send_read_instruction(context, user, data.data()).await
+    }
+
+    pub async fn get_config_for_timestamp(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        timestamp: u32,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = GetConfigForTimestampParams { timestamp };
+
+        self.send_read_instruction(context, user, data.data()).await
+    }
+
+    pub async fn get_account_config(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        self.send_read_instruction(context, user, GetAccountConfigParams {}.data())
+            .await
+    }
+
+    /// Quotes the fee of `signed_report` for `requester`, passing the fee preference, fee token
+    /// mint and subscriber discount of `optional_accounts`
+    pub async fn get_fee_quote(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        requester: Pubkey,
+        signed_report: Vec<u8>,
+        optional_accounts: VerifyOptionalAccounts,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let data = GetFeeQuoteParams { signed_report };
+
+        let quote_context = GetFeeQuoteContext {
+            verifier_account: self.data_account,
+            requester,
+            fee_preference: optional_accounts.fee_preference,
+            fee_token_mint: optional_accounts.fee_token_mint,
+            subscriber_discount: optional_accounts.subscriber_discount,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: quote_context.to_account_metas(None),
+            data: data.data(),
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
+    async fn send_read_instruction(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let read_context = ReadVerifierAccountContext {
+            verifier_account: self.data_account,
+        };
+
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: read_context.to_account_metas(None),
+            data,
+        };
+
+        EnvironmentContextOperations::send_transaction(
+            context,
+            &[instruction],
+            Some(&user.pubkey()),
+            &[user],
+        )
+        .await
+    }
+
     pub async fn read_verifier_account(
         &self,
         context: &mut ProgramTestContext,
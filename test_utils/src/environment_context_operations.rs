@@ -1,11 +1,13 @@
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::solana_program::instruction::Instruction;
-use anchor_lang::AccountDeserialize;
+use anchor_lang::{AccountDeserialize, AnchorDeserialize};
 use solana_program_test::{BanksClientError, BanksTransactionResultWithMetadata, ProgramTestContext};
 use solana_sdk::account::Account;
-use solana_sdk::signature::Keypair;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_program::system_instruction;
 use solana_sdk::transaction::Transaction;
 use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::clock::Clock;
 
 pub struct EnvironmentContextOperations {}
 
@@ -27,6 +29,15 @@ impl EnvironmentContextOperations {
         environment_context.banks_client.process_transaction_with_metadata(tx).await
     }
 
+    pub async fn transfer_lamports(environment_context: &mut ProgramTestContext,
+                                   from: &Keypair,
+                                   to: Pubkey,
+                                   lamports: u64) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let instruction = system_instruction::transfer(&from.pubkey(), &to, lamports);
+
+        Self::send_transaction(environment_context, &[instruction], Some(&from.pubkey()), &[from]).await
+    }
+
     pub async fn get_account(environment_context: &mut ProgramTestContext, account_pubkey: Pubkey) -> Result<Option<Account>, BanksClientError> {
         let account = environment_context.banks_client.get_account(account_pubkey).await?;
 
@@ -40,6 +51,37 @@ impl EnvironmentContextOperations {
     }
 
 
+    /// Deserializes the instruction return data of a processed transaction
+    pub fn deserialize_return_data<T: AnchorDeserialize>(
+        result: &Result<BanksTransactionResultWithMetadata, BanksClientError>,
+    ) -> T {
+        let data = &result
+            .as_ref()
+            .expect("Transaction result should be present")
+            .metadata
+            .as_ref()
+            .expect("Metadata should be present")
+            .return_data
+            .as_ref()
+            .expect("Return data should be present")
+            .data;
+
+        T::try_from_slice(data).expect("Return data should deserialize")
+    }
+
+    pub async fn get_clock(environment_context: &mut ProgramTestContext) -> Result<Clock, BanksClientError> {
+        environment_context.banks_client.get_sysvar::<Clock>().await
+    }
+
+    /// Overrides the clock sysvar so subsequent transactions observe `unix_timestamp`
+    pub async fn set_unix_timestamp(environment_context: &mut ProgramTestContext, unix_timestamp: i64) -> Result<(), BanksClientError> {
+        let mut clock = Self::get_clock(environment_context).await?;
+        clock.unix_timestamp = unix_timestamp;
+        environment_context.set_sysvar(&clock);
+
+        Ok(())
+    }
+
     pub async fn get_account_data_size(
         environment_context: &mut ProgramTestContext,
         account_pubkey: Pubkey,
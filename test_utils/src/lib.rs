@@ -3,4 +3,5 @@ pub mod environment_context_operations;
 pub mod assert;
 pub mod base_test_environment;
 pub mod client;
-pub mod report;
\ No newline at end of file
+pub mod report;
+pub mod token_operations;
\ No newline at end of file
@@ -15,6 +15,7 @@ pub trait Report {
     fn dummy(observation_timestamp: Option<u32>) -> Self;
 }
 
+/// A non-standard report, whose zero feed id gives it schema version 0
 #[derive(Debug, Clone)]
 pub struct DummyReport {
     feed_id: [u8; 32],           // bytes32
@@ -52,6 +53,58 @@ impl Report for DummyReport {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct V2Report {
+    feed_id: [u8; 32],           // bytes32
+    valid_from_timestamp: u32,   // uint32
+    observations_timestamp: u32, // uint32
+    native_fee: String,          // uint192
+    link_fee: String,            // uint192
+    expires_at: u32,             // uint32
+    benchmark_price: String,     // int192
+}
+
+impl Report for V2Report {
+    fn encode(&self) -> Vec<u8> {
+        let tokens = vec![
+            Token::FixedBytes(self.feed_id.to_vec()),
+            Token::Uint(self.valid_from_timestamp.into()),
+            Token::Uint(self.observations_timestamp.into()),
+            Token::Uint(ethabi::ethereum_types::U256::from_dec_str(&self.native_fee).unwrap()),
+            Token::Uint(ethabi::ethereum_types::U256::from_dec_str(&self.link_fee).unwrap()),
+            Token::Uint(self.expires_at.into()),
+            Token::Int(ethabi::ethereum_types::U256::from_dec_str(&self.benchmark_price).unwrap()),
+        ];
+
+        ethabi::encode(&tokens)
+    }
+
+    fn dummy(observation_timestamp: Option<u32>) -> Self {
+        let current_ts: u32 = observation_timestamp.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Error: Timestamp in the past")
+                .as_secs() as u32
+        });
+
+        const FEED_ID_V2: [u8; 32] = [
+            0x00, 0x02, 0x8c, 0x91, 0x5d, 0x6a, 0xf0, 0xfd, 0x66, 0xbb, 0xa2, 0xd0, 0xfc, 0x93, 0x40,
+            0x22, 0x6e, 0x3b, 0xaf, 0x50, 0xc8, 0x6a, 0x8a, 0x5c, 0x92, 0xbd, 0xa2, 0x6c, 0x7c, 0x93,
+            0x8b, 0x52,
+        ];
+
+        V2Report {
+            feed_id: FEED_ID_V2,
+            valid_from_timestamp: 1_727_467_477,
+            observations_timestamp: current_ts,
+            native_fee: "118647852657900".to_string(),
+            link_fee: "25234531311164200".to_string(),
+            expires_at: 0,
+            benchmark_price: "655442225238888900".to_string(),
+        }
+    }
+}
+
 // representing int192 as string for simplicity
 #[derive(Debug, Clone)]
 pub struct V3Report {
@@ -117,10 +170,6 @@ fn generate_signer_signatures(
     report_context: [[u8; 32]; 3],
     signers: &[Signer],
 ) -> (Vec<[u8; 32]>, Vec<[u8; 32]>, Vec<u8>) {
-    let mut rs = Vec::with_capacity(signers.len());
-    let mut ss = Vec::with_capacity(signers.len());
-    let mut vs = Vec::with_capacity(signers.len());
-
     // Compute hash = keccak256(keccak256(report) + reportContext)
     let hash_report = keccak256(report);
 
@@ -135,6 +184,18 @@ fn generate_signer_signatures(
     // Compute the final hash
     let hash = keccak256(&data_to_hash);
 
+    sign_hash(hash, signers)
+}
+
+/// Signs `hash` with each signer, returning the `rs`, `ss` and recovery ids in signer order.
+pub fn sign_hash(
+    hash: [u8; 32],
+    signers: &[Signer],
+) -> (Vec<[u8; 32]>, Vec<[u8; 32]>, Vec<u8>) {
+    let mut rs = Vec::with_capacity(signers.len());
+    let mut ss = Vec::with_capacity(signers.len());
+    let mut vs = Vec::with_capacity(signers.len());
+
     // Create a Secp256k1 context for signing
     let secp = Secp256k1::new();
 
@@ -0,0 +1,132 @@
+use crate::environment_context_operations::EnvironmentContextOperations;
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token::spl_token;
+use solana_program::program_pack::Pack;
+use solana_program::system_instruction;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// SPL token helpers for tests. The token program is deployed by default in the test environment.
+pub struct TokenOperations {}
+
+impl TokenOperations {
+    /// Creates a mint with `payer` as the mint authority
+    pub async fn create_mint(
+        environment_context: &mut ProgramTestContext,
+        payer: &Keypair,
+        decimals: u8,
+    ) -> Result<Pubkey, BanksClientError> {
+        let mint = Keypair::new();
+        let rent = environment_context.banks_client.get_rent().await?;
+
+        let instructions = [
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &payer.pubkey(),
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ];
+
+        EnvironmentContextOperations::send_transaction(
+            environment_context,
+            &instructions,
+            Some(&payer.pubkey()),
+            &[payer, &mint],
+        )
+        .await?
+        .result?;
+
+        Ok(mint.pubkey())
+    }
+
+    /// Creates a token account of `mint` owned by `owner`
+    pub async fn create_token_account(
+        environment_context: &mut ProgramTestContext,
+        payer: &Keypair,
+        mint: &Pubkey,
+        owner: &Pubkey,
+    ) -> Result<Pubkey, BanksClientError> {
+        let token_account = Keypair::new();
+        let rent = environment_context.banks_client.get_rent().await?;
+
+        let instructions = [
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &token_account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account3(
+                &spl_token::ID,
+                &token_account.pubkey(),
+                mint,
+                owner,
+            )
+            .unwrap(),
+        ];
+
+        EnvironmentContextOperations::send_transaction(
+            environment_context,
+            &instructions,
+            Some(&payer.pubkey()),
+            &[payer, &token_account],
+        )
+        .await?
+        .result?;
+
+        Ok(token_account.pubkey())
+    }
+
+    /// Mints `amount` to `token_account`, signed by the mint authority
+    pub async fn mint_to(
+        environment_context: &mut ProgramTestContext,
+        mint_authority: &Keypair,
+        mint: &Pubkey,
+        token_account: &Pubkey,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            mint,
+            token_account,
+            &mint_authority.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+
+        EnvironmentContextOperations::send_transaction(
+            environment_context,
+            &[instruction],
+            Some(&mint_authority.pubkey()),
+            &[mint_authority],
+        )
+        .await?
+        .result?;
+
+        Ok(())
+    }
+
+    pub async fn get_balance(
+        environment_context: &mut ProgramTestContext,
+        token_account: &Pubkey,
+    ) -> Result<u64, BanksClientError> {
+        let account = EnvironmentContextOperations::get_account(environment_context, *token_account)
+            .await?
+            .expect("Token account should exist");
+
+        Ok(spl_token::state::Account::unpack(&account.data).unwrap().amount)
+    }
+}